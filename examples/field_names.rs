@@ -1,6 +1,8 @@
 use std::{collections::HashMap, env};
 
-use baserow_rs::{api::client::BaserowClient, Baserow, BaserowTableOperations, ConfigBuilder};
+use baserow_rs::{
+    api::client::BaserowClient, Baserow, BaserowTableOperations, ConfigBuilder, TableId,
+};
 use serde_json::Value;
 
 #[tokio::main]
@@ -11,10 +13,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let configuration = ConfigBuilder::new()
         .base_url(endpoint.as_str())
         .api_key(api_key.as_str())
-        .build();
+        .try_build()?;
 
     let baserow = Baserow::with_configuration(configuration);
-    let table = baserow.table_by_id(176);
+    let table = baserow.table_by_id(TableId(176));
 
     // Approach 1: Using user_field_names parameter
     let mut record = HashMap::new();
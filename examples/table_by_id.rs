@@ -2,7 +2,7 @@ use std::{collections::HashMap, env};
 
 use baserow_rs::{
     api::client::BaserowClient, filter::Filter, Baserow, BaserowTableOperations, ConfigBuilder,
-    OrderDirection,
+    OrderDirection, TableId,
 };
 use serde_json::Value;
 
@@ -14,13 +14,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let configuration = ConfigBuilder::new()
         .base_url(endpoint.as_str())
         .api_key(api_key.as_str())
-        .build();
+        .try_build()?;
 
     let baserow = Baserow::with_configuration(configuration);
 
     // retrieve a table by id
     let rows = baserow
-        .table_by_id(176)
+        .table_by_id(TableId(176))
         .query()
         .filter_by("field_1529", Filter::Equal, "testaaaaaaaaaa")
         .order_by("field_1529", OrderDirection::Asc)
@@ -1,4 +1,4 @@
-use baserow_rs::{api::client::BaserowClient, BaserowTableOperations, ConfigBuilder};
+use baserow_rs::{api::client::BaserowClient, BaserowTableOperations, ConfigBuilder, TableId};
 use serde_json::Value;
 use std::collections::HashMap;
 use tracing::{debug, error, info, Level};
@@ -32,14 +32,15 @@ async fn main() {
     let config = ConfigBuilder::new()
         .base_url("https://api.baserow.io")
         .api_key("your-api-key")
-        .build();
+        .try_build()
+        .expect("invalid configuration");
 
     // Initialize the client
     let baserow = baserow_rs::Baserow::with_configuration(config);
     debug!("Client initialized successfully");
 
     // Get a table reference
-    let table = baserow.table_by_id(1234);
+    let table = baserow.table_by_id(TableId(1234));
     debug!(table_id = 1234, "Retrieved table reference");
 
     // Create a record with tracing enabled
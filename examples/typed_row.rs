@@ -1,4 +1,7 @@
-use baserow_rs::{api::client::BaserowClient, Baserow, BaserowTableOperations, ConfigBuilder};
+use baserow_rs::{
+    api::client::BaserowClient, Baserow, BaserowTableOperations, ConfigBuilder, RowId, TableId,
+    TypedTableOperations,
+};
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
@@ -14,16 +17,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let config = ConfigBuilder::new()
         .base_url("https://api.baserow.io")
         .api_key("your-api-key")
-        .build();
+        .try_build()?;
 
     let baserow = Baserow::with_configuration(config);
 
     // First auto_map the table to ensure field mappings are available
-    let table = baserow.table_by_id(1234).auto_map().await?;
+    let table = baserow.table_by_id(TableId(1234)).auto_map().await?;
 
     // Get a row and deserialize it into our User struct
-    match table.get_one::<User>(1, None).await {
-        Ok(user) => println!("Found user: {:?}", user),
+    match table.get_one::<User>(RowId(1), None).await {
+        Ok(user) => println!(
+            "Found user {}: {} ({}) - Age: {:?}",
+            user.id, user.name, user.email, user.age
+        ),
         Err(e) => eprintln!("Error: {}", e),
     }
 
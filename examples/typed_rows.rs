@@ -1,7 +1,4 @@
-use baserow_rs::{
-    api::client::BaserowClient, filter::Filter, Baserow, BaserowTableOperations, ConfigBuilder,
-    OrderDirection,
-};
+use baserow_rs::prelude::*;
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
@@ -17,12 +14,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let config = ConfigBuilder::new()
         .base_url("https://api.baserow.io")
         .api_key("your-api-key")
-        .build();
+        .try_build()?;
 
     let baserow = Baserow::with_configuration(config);
 
     // First auto_map the table to ensure field mappings are available
-    let table = baserow.table_by_id(1234).auto_map().await?;
+    let table = baserow.table_by_id(TableId(1234)).auto_map().await?;
 
     // Get rows and deserialize them into User structs with filtering and pagination
     let response = table
@@ -1,6 +1,8 @@
 use std::{collections::HashMap, env};
 
-use baserow_rs::{api::client::BaserowClient, Baserow, BaserowTableOperations, ConfigBuilder};
+use baserow_rs::{
+    api::client::BaserowClient, Baserow, BaserowTableOperations, ConfigBuilder, RowId, TableId,
+};
 use serde_json::Value;
 
 #[tokio::main]
@@ -11,7 +13,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let configuration = ConfigBuilder::new()
         .base_url(endpoint.as_str())
         .api_key(api_key.as_str())
-        .build();
+        .try_build()?;
 
     let baserow = Baserow::with_configuration(configuration);
 
@@ -19,17 +21,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     record.insert("field_1529".to_string(), Value::String("test".to_string()));
 
     // retrieve a table by id
-    let row = baserow.table_by_id(176).create_one(record, None).await?;
+    let row = baserow
+        .table_by_id(TableId(176))
+        .create_one(record, None)
+        .await?;
 
     println!("Row created: {:#?}", row);
 
-    let row_id = row.get("id").unwrap().as_u64().unwrap();
+    let row_id = RowId(row.get("id").unwrap().as_u64().unwrap());
 
     let mut updated_record: HashMap<String, Value> = HashMap::new();
     updated_record.insert("field_1529".to_string(), Value::String("test2".to_string()));
 
     let updated_record = baserow
-        .table_by_id(176)
+        .table_by_id(TableId(176))
         .update(row_id, updated_record, None)
         .await?;
 
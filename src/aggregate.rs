@@ -0,0 +1,373 @@
+//! Aggregations over the values of a field
+//!
+//! [`RowRequestBuilder::aggregate`] computes a sum, average, minimum,
+//! maximum or count over a field of the rows matching a query, e.g. for
+//! reports, without handing all rows to the caller.
+//!
+//! Queries reading a grid view without further filters are aggregated by
+//! Baserow. Otherwise, only the aggregated field of the matching rows is
+//! fetched, a page of up to 200 rows at a time, and aggregated by the client.
+//!
+//! # Example
+//! ```no_run
+//! use baserow_rs::{aggregate::Aggregation, prelude::*};
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let config = ConfigBuilder::new()
+//!         .base_url("https://api.baserow.io")
+//!         .api_key("your-api-key")
+//!         .try_build()
+//!         .expect("invalid configuration");
+//!     let baserow = Baserow::with_configuration(config);
+//!
+//!     let revenue = baserow
+//!         .table_by_id(TableId(1234))
+//!         .auto_map()
+//!         .await
+//!         .unwrap()
+//!         .query()
+//!         .filter_by("Status", Filter::Equal, "Paid")
+//!         .aggregate("Amount", Aggregation::Sum)
+//!         .await
+//!         .unwrap();
+//!     println!("Revenue: {:?}", revenue);
+//! }
+//! ```
+
+use std::collections::HashSet;
+
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::{debug, instrument};
+
+use crate::{
+    api::{
+        client::{BaserowClient, RequestTracing},
+        table_operations::{row_value, BaserowTableOperations, RowRequestBuilder},
+        view::{ViewSource, ViewType},
+    },
+    error::BaserowError,
+};
+
+/// The number of rows fetched per request when aggregating on the client
+const PAGE_SIZE: i32 = 200;
+
+/// How the values of a field are aggregated
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Aggregation {
+    /// The number of rows with a value in the field
+    Count,
+    /// The number of distinct values of the field
+    UniqueCount,
+    /// The sum of the numbers in the field
+    Sum,
+    /// The average of the numbers in the field
+    Average,
+    /// The smallest number in the field
+    Min,
+    /// The largest number in the field
+    Max,
+}
+
+impl Aggregation {
+    /// Returns the aggregation type as named by the API of Baserow
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Aggregation::Count => "not_empty_count",
+            Aggregation::UniqueCount => "unique_count",
+            Aggregation::Sum => "sum",
+            Aggregation::Average => "average",
+            Aggregation::Min => "min",
+            Aggregation::Max => "max",
+        }
+    }
+}
+
+/// The response of the field aggregation endpoint of grid views
+#[derive(Deserialize)]
+struct AggregationResponse {
+    value: Value,
+}
+
+/// Aggregates values on the client
+#[derive(Default)]
+struct Accumulator {
+    count: u64,
+    unique: HashSet<String>,
+    sum: f64,
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+impl Accumulator {
+    fn add(
+        &mut self,
+        aggregation: Aggregation,
+        field: &str,
+        value: &Value,
+    ) -> Result<(), BaserowError> {
+        if is_empty(value) {
+            return Ok(());
+        }
+        self.count += 1;
+        match aggregation {
+            Aggregation::Count => {}
+            Aggregation::UniqueCount => {
+                self.unique.insert(value.to_string());
+            }
+            Aggregation::Sum | Aggregation::Average | Aggregation::Min | Aggregation::Max => {
+                let number = number(value).ok_or_else(|| {
+                    BaserowError::InvalidRequest(format!(
+                        "Cannot aggregate the value {} of field '{}' as number",
+                        value, field
+                    ))
+                })?;
+                self.sum += number;
+                self.min = Some(self.min.map_or(number, |min| min.min(number)));
+                self.max = Some(self.max.map_or(number, |max| max.max(number)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the result, or `None` if there were no numbers to aggregate
+    fn finish(self, aggregation: Aggregation) -> Option<f64> {
+        match aggregation {
+            Aggregation::Count => Some(self.count as f64),
+            Aggregation::UniqueCount => Some(self.unique.len() as f64),
+            Aggregation::Sum => Some(self.sum),
+            Aggregation::Average => (self.count > 0).then(|| self.sum / self.count as f64),
+            Aggregation::Min => self.min,
+            Aggregation::Max => self.max,
+        }
+    }
+}
+
+/// Whether a value counts as empty, like an unset field or an empty list of links
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(text) => text.is_empty(),
+        Value::Array(values) => values.is_empty(),
+        _ => false,
+    }
+}
+
+/// Reads a number, given as JSON number or, like decimals, as string
+fn number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(number) => number.as_f64(),
+        Value::String(text) => text.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+impl RowRequestBuilder {
+    /// Aggregates a field over the rows matching the query
+    ///
+    /// Returns the sum, average, minimum or maximum of the numbers in the
+    /// field, or the number of rows with a value or of distinct values.
+    /// Averages, minimums and maximums are `None` if no row has a value.
+    /// Number fields are returned as strings by Baserow and parsed, other
+    /// values fail numeric aggregations. The pagination of the query is
+    /// ignored. See the [module documentation](crate::aggregate).
+    #[instrument(skip(self), err)]
+    pub async fn aggregate(
+        &self,
+        field: &str,
+        aggregation: Aggregation,
+    ) -> Result<Option<f64>, BaserowError> {
+        let (table, baserow) = self.target()?;
+        let field_id = table.field_id(field);
+
+        if let (
+            Some(ViewSource::View {
+                view_type: ViewType::Grid,
+                id,
+            }),
+            None,
+            None,
+            Some(field_id),
+        ) = (
+            &self.request.view,
+            &self.request.filter,
+            &self.request.filter_tree,
+            field_id,
+        ) {
+            debug!("Aggregating via the grid view");
+            let path = format!(
+                "api/database/views/grid/{}/field-aggregation/{}/",
+                id, field_id
+            );
+            let url = baserow.configuration().api_url(&path);
+            let req = baserow
+                .auth_header()
+                .await
+                .apply(baserow.inner.client.get(url))
+                .query(&[("type", aggregation.as_str())]);
+            let resp = baserow
+                .trace_request(&baserow.inner.client, req.build()?)
+                .await?;
+            return match resp.status() {
+                StatusCode::OK => {
+                    let response: AggregationResponse = resp.json().await?;
+                    Ok(number(&response.value))
+                }
+                _ => Err(BaserowError::from_response(resp).await),
+            };
+        }
+
+        debug!("Aggregating on the client");
+        let keys = table.row_keys(field);
+
+        let mut request = self.request.clone();
+        request.fields = Some(vec![field.to_string()]);
+        request.page_size = Some(PAGE_SIZE);
+        request.include_metadata = false;
+
+        let mut accumulator = Accumulator::default();
+        for page in 1.. {
+            request.page = Some(page);
+            let response = table.get_rows(baserow.clone(), request.clone()).await?;
+            for value in response
+                .results
+                .iter()
+                .filter_map(|row| row_value(row, &keys))
+            {
+                accumulator.add(aggregation, field, value)?;
+            }
+            if response.next.is_none() {
+                break;
+            }
+        }
+        Ok(accumulator.finish(aggregation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::table_operations::RowsResponse,
+        filter::Filter,
+        fixtures::{fields_json, row},
+        Baserow, ConfigBuilder, TableField, TableId, ViewId,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_accumulator() {
+        let values = [
+            json!("12.50"),
+            json!(null),
+            json!("7.50"),
+            json!(""),
+            json!(10),
+        ];
+        let aggregate = |aggregation| {
+            let mut accumulator = Accumulator::default();
+            for value in &values {
+                accumulator.add(aggregation, "Price", value).unwrap();
+            }
+            accumulator.finish(aggregation)
+        };
+        assert_eq!(aggregate(Aggregation::Sum), Some(30.0));
+        assert_eq!(aggregate(Aggregation::Average), Some(10.0));
+        assert_eq!(aggregate(Aggregation::Min), Some(7.5));
+        assert_eq!(aggregate(Aggregation::Max), Some(12.5));
+        assert_eq!(aggregate(Aggregation::Count), Some(3.0));
+        assert_eq!(Accumulator::default().finish(Aggregation::Average), None);
+
+        let mut accumulator = Accumulator::default();
+        for value in [json!([{"id": 1}]), json!([{"id": 1}]), json!([])] {
+            accumulator
+                .add(Aggregation::UniqueCount, "Links", &value)
+                .unwrap();
+        }
+        assert_eq!(accumulator.finish(Aggregation::UniqueCount), Some(1.0));
+
+        let error = Accumulator::default().add(Aggregation::Sum, "Name", &json!("Ada"));
+        assert!(matches!(error, Err(BaserowError::InvalidRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_aggregate() {
+        let mut server = mockito::Server::new_async().await;
+        let fields_mock = server
+            .mock("GET", "/api/database/fields/table/1234/")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(fields_json(&[
+                TableField::text("Status", 1).as_primary(),
+                TableField::number("Amount", 2, 2),
+            ]))
+            .create_async()
+            .await;
+        let pages = RowsResponse::pages(
+            vec![
+                row(1, [("field_2", json!("10.00"))]),
+                row(2, [("field_2", json!("5.50"))]),
+            ],
+            1,
+        );
+        let mut page_mocks = Vec::new();
+        for (page, body) in pages.iter().enumerate() {
+            let mock = server
+                .mock("GET", "/api/database/rows/table/1234/")
+                .match_query(mockito::Matcher::AllOf(vec![
+                    mockito::Matcher::UrlEncoded("filter__field_1__equal".into(), "Paid".into()),
+                    mockito::Matcher::UrlEncoded("include".into(), "field_2".into()),
+                    mockito::Matcher::UrlEncoded("size".into(), "200".into()),
+                    mockito::Matcher::UrlEncoded("page".into(), (page + 1).to_string()),
+                ]))
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(body.to_json())
+                .create_async()
+                .await;
+            page_mocks.push(mock);
+        }
+        let view_mock = server
+            .mock("GET", "/api/database/views/grid/7/field-aggregation/2/")
+            .match_query(mockito::Matcher::UrlEncoded("type".into(), "max".into()))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(json!({"value": "10.00"}).to_string())
+            .create_async()
+            .await;
+
+        let config = ConfigBuilder::new()
+            .base_url(&server.url())
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(config);
+        let table = baserow.table_by_id(TableId(1234)).auto_map().await.unwrap();
+
+        let sum = table
+            .clone()
+            .query()
+            .filter_by("Status", Filter::Equal, "Paid")
+            .aggregate("Amount", Aggregation::Sum)
+            .await
+            .unwrap();
+        assert_eq!(sum, Some(15.5));
+
+        let max = table
+            .query()
+            .view(ViewSource::grid(ViewId(7)))
+            .aggregate("Amount", Aggregation::Max)
+            .await
+            .unwrap();
+        assert_eq!(max, Some(10.0));
+
+        fields_mock.assert_async().await;
+        for mock in page_mocks {
+            mock.assert_async().await;
+        }
+        view_mock.assert_async().await;
+    }
+}
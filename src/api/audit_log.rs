@@ -0,0 +1,445 @@
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, instrument};
+
+use crate::{api::client::BaserowClient, error::BaserowError};
+
+/// An entry of the audit log
+///
+/// Users and workspaces are described as Baserow renders them, e.g.
+/// `user@example.com (1)`, as they may have been deleted since.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AuditLogEntry {
+    pub id: u64,
+    /// The action that was performed, e.g. `create_row`
+    pub action_type: String,
+    /// The user who performed the action
+    pub user: String,
+    /// The workspace the action was performed in, if any
+    #[serde(default)]
+    pub workspace: Option<String>,
+    /// The human readable name of the action type
+    pub r#type: String,
+    /// A description of the action
+    pub description: String,
+    /// When the action was performed, as ISO 8601 timestamp
+    pub timestamp: String,
+    /// The IP address the action was performed from, if known
+    #[serde(default)]
+    pub ip_address: Option<String>,
+}
+
+/// A page of audit log entries
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AuditLogResponse {
+    /// Total count of entries matching the query, not just the current page
+    pub count: u64,
+    /// URL for the next page of results, if available
+    pub next: Option<String>,
+    /// URL for the previous page of results, if available
+    pub previous: Option<String>,
+    /// The entries of the current page, most recent first by default
+    pub results: Vec<AuditLogEntry>,
+}
+
+/// Filters and pagination of an audit log query
+///
+/// Timestamps are ISO 8601 strings, e.g. `2024-01-01T00:00:00Z`.
+///
+/// # Example
+/// ```
+/// use baserow_rs::api::audit_log::AuditLogQuery;
+///
+/// let query = AuditLogQuery::new()
+///     .workspace_id(12)
+///     .action_type("delete_row")
+///     .from_timestamp("2024-01-01T00:00:00Z")
+///     .size(50);
+/// ```
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct AuditLogQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    action_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from_timestamp: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    to_timestamp: Option<String>,
+}
+
+impl AuditLogQuery {
+    /// Creates a query for the first page of all entries
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the page to fetch, starting at 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Set the number of entries per page
+    pub fn size(mut self, size: u32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Only include actions performed by the given user
+    pub fn user_id(mut self, user_id: u64) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    /// Only include actions performed in the given workspace
+    pub fn workspace_id(mut self, workspace_id: u64) -> Self {
+        self.workspace_id = Some(workspace_id);
+        self
+    }
+
+    /// Only include actions of the given type, e.g. `create_row`
+    pub fn action_type(mut self, action_type: &str) -> Self {
+        self.action_type = Some(action_type.to_string());
+        self
+    }
+
+    /// Only include actions performed at or after the given time
+    pub fn from_timestamp(mut self, timestamp: &str) -> Self {
+        self.from_timestamp = Some(timestamp.to_string());
+        self
+    }
+
+    /// Only include actions performed at or before the given time
+    pub fn to_timestamp(mut self, timestamp: &str) -> Self {
+        self.to_timestamp = Some(timestamp.to_string());
+        self
+    }
+
+    /// Turns the filters into those of an export of the matching entries
+    pub fn export(self) -> AuditLogExportRequest {
+        AuditLogExportRequest {
+            filter_user_id: self.user_id,
+            filter_workspace_id: self.workspace_id,
+            filter_action_type: self.action_type,
+            filter_from_timestamp: self.from_timestamp,
+            filter_to_timestamp: self.to_timestamp,
+            ..AuditLogExportRequest::new()
+        }
+    }
+}
+
+/// Request to export the audit log to a CSV file
+///
+/// Create it from a query with [`AuditLogQuery::export`] to export the
+/// entries matching its filters.
+#[derive(Serialize, Debug, Clone)]
+pub struct AuditLogExportRequest {
+    export_charset: String,
+    csv_column_separator: String,
+    csv_first_row_header: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter_user_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter_workspace_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter_action_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter_from_timestamp: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter_to_timestamp: Option<String>,
+}
+
+impl Default for AuditLogExportRequest {
+    fn default() -> Self {
+        Self {
+            export_charset: "utf-8".to_string(),
+            csv_column_separator: ",".to_string(),
+            csv_first_row_header: true,
+            filter_user_id: None,
+            filter_workspace_id: None,
+            filter_action_type: None,
+            filter_from_timestamp: None,
+            filter_to_timestamp: None,
+        }
+    }
+}
+
+impl AuditLogExportRequest {
+    /// Creates a request exporting all entries as UTF-8 encoded, comma separated CSV with a header row
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the character set of the file, e.g. `utf-8` or `latin-1`
+    pub fn charset(mut self, charset: &str) -> Self {
+        self.export_charset = charset.to_string();
+        self
+    }
+
+    /// Set the column separator, e.g. `;` or `\t`
+    pub fn column_separator(mut self, separator: &str) -> Self {
+        self.csv_column_separator = separator.to_string();
+        self
+    }
+
+    /// Set whether the first row contains the column names
+    pub fn header_row(mut self, enabled: bool) -> Self {
+        self.csv_first_row_header = enabled;
+        self
+    }
+}
+
+/// A running or finished audit log export
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AuditLogExportJob {
+    pub id: u64,
+    /// The state of the job: `pending`, `started`, `finished` or `failed`
+    pub state: String,
+    #[serde(default)]
+    pub progress_percentage: u8,
+    /// Where to download the file from, once the export is finished
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Why the export failed, if it did
+    #[serde(default)]
+    pub human_readable_error: Option<String>,
+}
+
+impl AuditLogExportJob {
+    /// Returns whether the job is done, successfully or not
+    pub fn is_done(&self) -> bool {
+        matches!(self.state.as_str(), "finished" | "failed")
+    }
+}
+
+/// Trait defining the audit log operations available on a Baserow client
+///
+/// The audit log is a feature of Baserow's enterprise edition and only
+/// accessible to staff users, so the client has to be authenticated via
+/// `token_auth()` as such.
+///
+/// # Example
+/// ```no_run
+/// use baserow_rs::{
+///     api::{audit_log::{AuditLogQuery, BaserowAuditLogOperations}, client::BaserowClient},
+///     Baserow, ConfigBuilder,
+/// };
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let config = ConfigBuilder::new()
+///         .base_url("https://baserow.example.com")
+///         .email("admin@example.com")
+///         .password("password")
+///         .try_build()
+///         .expect("invalid configuration");
+///     let client = Baserow::with_configuration(config).token_auth().await.unwrap();
+///
+///     let entries = client
+///         .list_audit_log(AuditLogQuery::new().workspace_id(12))
+///         .await
+///         .unwrap();
+///     for entry in entries.results {
+///         println!("{} {}: {}", entry.timestamp, entry.user, entry.description);
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait BaserowAuditLogOperations: BaserowClient {
+    /// Lists the entries of the audit log matching a query
+    ///
+    /// # Arguments
+    /// * `query` - The filters and page to fetch
+    #[instrument(skip(self), err)]
+    async fn list_audit_log(&self, query: AuditLogQuery) -> Result<AuditLogResponse, BaserowError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url("api/admin/audit-log/");
+
+        let req = self
+            .auth_header()
+            .await
+            .apply_jwt(client.get(url))?
+            .query(&query);
+
+        debug!("Sending request to list audit log entries");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let response: AuditLogResponse = resp.json().await?;
+                info!(
+                    entry_count = response.results.len(),
+                    total = response.count,
+                    "Successfully retrieved audit log entries"
+                );
+                Ok(response)
+            }
+            _ => Err(BaserowError::from_response(resp).await),
+        }
+    }
+
+    /// Starts exporting the audit log to a CSV file
+    ///
+    /// The export runs in the background on the server, poll its state with
+    /// [`get_audit_log_export`](Self::get_audit_log_export).
+    #[instrument(skip(self), err)]
+    async fn export_audit_log(
+        &self,
+        request: AuditLogExportRequest,
+    ) -> Result<AuditLogExportJob, BaserowError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url("api/admin/audit-log/export/");
+
+        let req = self
+            .auth_header()
+            .await
+            .apply_jwt(client.post(url))?
+            .json(&request);
+
+        debug!("Sending request to export the audit log");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK | StatusCode::ACCEPTED => {
+                let job: AuditLogExportJob = resp.json().await?;
+                info!(job_id = job.id, "Started audit log export");
+                Ok(job)
+            }
+            _ => Err(BaserowError::from_response(resp).await),
+        }
+    }
+
+    /// Retrieves the state of an audit log export
+    ///
+    /// # Arguments
+    /// * `job_id` - The ID of the job returned by `export_audit_log`
+    #[instrument(skip(self), err)]
+    async fn get_audit_log_export(&self, job_id: u64) -> Result<AuditLogExportJob, BaserowError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url(&format!("api/jobs/{}/", job_id));
+
+        let req = self.auth_header().await.apply_jwt(client.get(url))?;
+
+        debug!("Sending request to fetch audit log export");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK => Ok(resp.json().await?),
+            _ => Err(BaserowError::from_response(resp).await),
+        }
+    }
+}
+
+impl<T: BaserowClient + ?Sized> BaserowAuditLogOperations for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Baserow, ConfigBuilder};
+    use mockito::Matcher;
+
+    #[tokio::test]
+    async fn test_audit_log() {
+        let mut server = mockito::Server::new_async().await;
+
+        let list_mock = server
+            .mock("GET", "/api/admin/audit-log/")
+            .match_header("Authorization", "JWT jwt-token")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("workspace_id".into(), "12".into()),
+                Matcher::UrlEncoded("action_type".into(), "delete_row".into()),
+                Matcher::UrlEncoded("from_timestamp".into(), "2024-01-01T00:00:00Z".into()),
+            ]))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+  "count": 1,
+  "next": null,
+  "previous": null,
+  "results": [{
+    "id": 7,
+    "action_type": "delete_row",
+    "user": "admin@example.com (1)",
+    "workspace": "Acme (12)",
+    "type": "Delete row",
+    "description": "Row \"3\" (3) deleted from table \"Tasks\" (5)",
+    "timestamp": "2024-01-02T10:00:00Z",
+    "ip_address": "127.0.0.1"
+  }]
+}"#,
+            )
+            .create_async()
+            .await;
+
+        let export_mock = server
+            .mock("POST", "/api/admin/audit-log/export/")
+            .match_header("Authorization", "JWT jwt-token")
+            .match_body(Matcher::PartialJson(serde_json::json!({
+                "export_charset": "utf-8",
+                "csv_column_separator": ";",
+                "filter_workspace_id": 12,
+                "filter_action_type": "delete_row"
+            })))
+            .with_status(202)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": 3, "state": "pending", "progress_percentage": 0}"#)
+            .create_async()
+            .await;
+
+        let config = ConfigBuilder::new()
+            .base_url(&server.url())
+            .access_token("jwt-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(config);
+
+        let query = AuditLogQuery::new()
+            .workspace_id(12)
+            .action_type("delete_row")
+            .from_timestamp("2024-01-01T00:00:00Z");
+        let response = baserow.list_audit_log(query.clone()).await.unwrap();
+        assert_eq!(response.count, 1);
+        assert_eq!(response.results[0].workspace.as_deref(), Some("Acme (12)"));
+
+        let job = baserow
+            .export_audit_log(query.export().column_separator(";"))
+            .await
+            .unwrap();
+        assert_eq!(job.id, 3);
+        assert!(!job.is_done());
+
+        list_mock.assert_async().await;
+        export_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_audit_log_requires_jwt() {
+        let config = ConfigBuilder::new()
+            .base_url("http://localhost")
+            .api_key("database-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(config);
+
+        let result = baserow.list_audit_log(AuditLogQuery::new()).await;
+        assert!(matches!(
+            result,
+            Err(BaserowError::MissingCredentials("JWT"))
+        ));
+    }
+}
@@ -1,19 +1,53 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use reqwest::{
+    header::{HeaderValue, AUTHORIZATION},
+    Request, RequestBuilder,
+};
 use serde::{Deserialize, Serialize};
 
+use crate::{error::BaserowError, secret::SecretString};
+
 #[derive(Serialize, Debug)]
 pub struct LoginRequest {
     pub email: String,
-    pub password: String,
+    pub password: SecretString,
 }
 
 #[derive(Deserialize, Debug)]
 pub struct TokenResponse {
-    pub access_token: String,
-    pub refresh_token: String,
-    pub token: String,
+    pub access_token: SecretString,
+    pub refresh_token: SecretString,
+    pub token: SecretString,
     pub user: User,
 }
 
+#[derive(Serialize, Debug)]
+pub struct TokenRefreshRequest {
+    pub refresh_token: SecretString,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TokenRefreshResponse {
+    pub access_token: SecretString,
+    pub user: Option<User>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct TokenVerifyRequest {
+    pub refresh_token: SecretString,
+}
+
+/// Tokens obtained by the client through a login or a token refresh
+///
+/// Passed to the callback registered with `Baserow::with_token_callback`.
+#[derive(Clone, Debug)]
+pub struct IssuedTokens {
+    pub access_token: SecretString,
+    pub refresh_token: SecretString,
+}
+
 #[derive(Deserialize, Clone, Debug)]
 pub struct User {
     pub first_name: String,
@@ -25,3 +59,148 @@ pub struct User {
 pub struct TokenAuthErrorResponse {
     pub error: String,
 }
+
+/// Credentials sent in the Authorization header of a request
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AuthHeader {
+    /// A user access token, sent as `JWT <token>`
+    Jwt(SecretString),
+    /// A database token, sent as `Token <token>`
+    Token(SecretString),
+    /// No credentials are sent
+    None,
+}
+
+impl AuthHeader {
+    /// Returns the value of the Authorization header, if any
+    pub fn header_value(&self) -> Option<String> {
+        match self {
+            AuthHeader::Jwt(token) => Some(format!("JWT {}", token.expose_secret())),
+            AuthHeader::Token(token) => Some(format!("Token {}", token.expose_secret())),
+            AuthHeader::None => None,
+        }
+    }
+
+    /// Returns the user access token, if these are JWT credentials
+    pub fn jwt(&self) -> Option<&str> {
+        match self {
+            AuthHeader::Jwt(token) => Some(token.expose_secret()),
+            _ => None,
+        }
+    }
+
+    /// Adds the Authorization header to a request that is being built
+    pub(crate) fn apply(&self, request: RequestBuilder) -> RequestBuilder {
+        match self.header_value() {
+            Some(value) => request.header(AUTHORIZATION, value),
+            None => request,
+        }
+    }
+
+    /// Adds the Authorization header to a request to an endpoint only accepting JWTs
+    pub(crate) fn apply_jwt(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<RequestBuilder, BaserowError> {
+        if self.jwt().is_none() {
+            let error = BaserowError::MissingCredentials("JWT");
+            error.log();
+            return Err(error);
+        }
+        Ok(self.apply(request))
+    }
+
+    /// Replaces the Authorization header of an already built request
+    pub(crate) fn apply_to(&self, request: &mut Request) {
+        if let Some(value) = self
+            .header_value()
+            .and_then(|value| HeaderValue::from_str(&value).ok())
+        {
+            request.headers_mut().insert(AUTHORIZATION, value);
+        }
+    }
+}
+
+/// A source of credentials that is consulted for every request
+///
+/// By default the client authenticates with the tokens from its configuration.
+/// Implement this trait to obtain credentials elsewhere, e.g. from a secret
+/// store, by minting short-lived tokens or by switching between tenants, and
+/// install it with `Baserow::with_token_provider`.
+///
+/// # Example
+/// ```no_run
+/// use baserow_rs::{api::authentication::{AuthHeader, TokenProvider}, Baserow, ConfigBuilder};
+///
+/// struct EnvTokenProvider;
+///
+/// #[async_trait::async_trait]
+/// impl TokenProvider for EnvTokenProvider {
+///     async fn token(&self) -> AuthHeader {
+///         match std::env::var("BASEROW_TOKEN") {
+///             Ok(token) => AuthHeader::Token(token.into()),
+///             Err(_) => AuthHeader::None,
+///         }
+///     }
+/// }
+///
+/// let config = ConfigBuilder::new().base_url("https://api.baserow.io").try_build().expect("invalid configuration");
+/// let baserow = Baserow::with_configuration(config).with_token_provider(EnvTokenProvider);
+/// ```
+#[async_trait::async_trait]
+pub trait TokenProvider: Send + Sync {
+    /// Returns the credentials for the next request
+    async fn token(&self) -> AuthHeader;
+}
+
+/// Fixed credentials can be used as a provider directly
+#[async_trait::async_trait]
+impl TokenProvider for AuthHeader {
+    async fn token(&self) -> AuthHeader {
+        self.clone()
+    }
+}
+
+#[derive(Deserialize)]
+struct JwtClaims {
+    exp: Option<u64>,
+}
+
+/// Reads the expiry time from the claims of a JWT
+///
+/// The signature is not verified, the expiry is only used to decide when to refresh.
+pub(crate) fn jwt_expiry(token: &str) -> Option<SystemTime> {
+    let payload = token.split('.').nth(1)?;
+    let claims: JwtClaims = serde_json::from_slice(&URL_SAFE_NO_PAD.decode(payload).ok()?).ok()?;
+    Some(UNIX_EPOCH + Duration::from_secs(claims.exp?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jwt_expiry() {
+        let claims = URL_SAFE_NO_PAD.encode(r#"{"token_type": "access", "exp": 1700000000}"#);
+        let token = format!("eyJhbGciOiJIUzI1NiJ9.{}.signature", claims);
+
+        assert_eq!(
+            jwt_expiry(&token),
+            Some(UNIX_EPOCH + Duration::from_secs(1700000000))
+        );
+        assert_eq!(jwt_expiry("not-a-jwt"), None);
+    }
+
+    #[test]
+    fn test_auth_header_value() {
+        assert_eq!(
+            AuthHeader::Jwt("abc".into()).header_value(),
+            Some("JWT abc".to_string())
+        );
+        assert_eq!(
+            AuthHeader::Token("abc".into()).header_value(),
+            Some("Token abc".to_string())
+        );
+        assert_eq!(AuthHeader::None.header_value(), None);
+    }
+}
@@ -0,0 +1,512 @@
+//! Batch operations that create, update or delete many rows at once
+//!
+//! Baserow accepts at most 200 rows per batch request and rejects the whole
+//! request if a single row is invalid. Batch operations therefore split the
+//! rows into chunks and, if Baserow rejects individual rows of a chunk, apply
+//! the remaining rows of the chunk without them.
+
+use std::ops::Range;
+
+use reqwest::{Method, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Value};
+use tracing::{debug, warn};
+
+use crate::{
+    api::{
+        client::{BaserowClient, RequestTracing},
+        table_operations::field_name,
+    },
+    error::BaserowError,
+    ids::{RowId, TableId},
+    mapper::TableMapper,
+    Baserow,
+};
+
+/// The maximum number of rows Baserow accepts per batch request
+pub const BATCH_SIZE: usize = 200;
+
+/// How much of a chunk of a batch operation was applied
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChunkStatus {
+    /// All rows of the chunk were applied
+    Applied,
+    /// Some rows of the chunk were rejected, the others were applied
+    PartiallyApplied,
+    /// No row of the chunk was applied
+    Failed,
+}
+
+/// A row of a batch operation that was not applied
+#[derive(Debug)]
+pub struct RowError {
+    /// The position of the row in the rows passed to the batch operation
+    pub index: usize,
+    /// Why the row was rejected
+    pub error: BaserowError,
+}
+
+/// The outcome of a chunk of up to [`BATCH_SIZE`] rows of a batch operation
+#[derive(Debug)]
+pub struct BatchChunk<T> {
+    /// The positions of the chunk's rows in the rows passed to the batch operation
+    pub range: Range<usize>,
+    /// How much of the chunk was applied
+    pub status: ChunkStatus,
+    /// The applied rows, as returned by Baserow
+    pub rows: Vec<T>,
+    /// The rows that were rejected individually
+    pub row_errors: Vec<RowError>,
+    /// The error that made the chunk fail as a whole, if any
+    pub error: Option<BaserowError>,
+}
+
+/// The outcome of a batch operation, chunk by chunk
+///
+/// # Example
+/// ```no_run
+/// use baserow_rs::{
+///     api::client::BaserowClient, Baserow, BaserowTableOperations, ConfigBuilder, TableId,
+/// };
+/// use serde_json::{json, Value};
+/// use std::collections::HashMap;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let config = ConfigBuilder::new()
+///         .base_url("https://api.baserow.io")
+///         .api_key("your-api-key")
+///         .try_build()
+///         .expect("invalid configuration");
+///     let baserow = Baserow::with_configuration(config);
+///
+///     let rows: Vec<HashMap<String, Value>> = (0..500)
+///         .map(|i| HashMap::from([("Name".to_string(), json!(format!("Row {}", i)))]))
+///         .collect();
+///     let result = baserow.table_by_id(TableId(1234)).create_many(rows, Some(true)).await.unwrap();
+///
+///     println!("Created {} rows", result.rows().count());
+///     for error in result.row_errors() {
+///         println!("Row {} was rejected: {}", error.index, error.error);
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct BatchResult<T> {
+    /// The chunks in the order the rows were passed
+    pub chunks: Vec<BatchChunk<T>>,
+}
+
+impl<T> BatchResult<T> {
+    /// Returns whether all rows were applied
+    pub fn is_complete(&self) -> bool {
+        self.chunks
+            .iter()
+            .all(|chunk| chunk.status == ChunkStatus::Applied)
+    }
+
+    /// Returns the applied rows of all chunks
+    pub fn rows(&self) -> impl Iterator<Item = &T> {
+        self.chunks.iter().flat_map(|chunk| chunk.rows.iter())
+    }
+
+    /// Consumes the result, returning the applied rows of all chunks
+    pub fn into_rows(self) -> Vec<T> {
+        self.chunks
+            .into_iter()
+            .flat_map(|chunk| chunk.rows)
+            .collect()
+    }
+
+    /// Returns the errors of the rows that were rejected individually
+    pub fn row_errors(&self) -> impl Iterator<Item = &RowError> {
+        self.chunks.iter().flat_map(|chunk| chunk.row_errors.iter())
+    }
+
+    /// Returns the chunks that failed as a whole
+    pub fn failed_chunks(&self) -> impl Iterator<Item = &BatchChunk<T>> {
+        self.chunks.iter().filter(|chunk| chunk.error.is_some())
+    }
+
+    /// Appends the chunks of a batch operation over the rows following `offset`
+    pub(crate) fn append(&mut self, other: BatchResult<T>, offset: usize) {
+        self.chunks
+            .extend(other.chunks.into_iter().map(|mut chunk| {
+                chunk.range = chunk.range.start + offset..chunk.range.end + offset;
+                for error in &mut chunk.row_errors {
+                    error.index += offset;
+                }
+                chunk
+            }));
+    }
+
+    pub(crate) fn map_rows<U>(self, f: impl Fn(T) -> U) -> BatchResult<U> {
+        BatchResult {
+            chunks: self
+                .chunks
+                .into_iter()
+                .map(|chunk| BatchChunk {
+                    range: chunk.range,
+                    status: chunk.status,
+                    rows: chunk.rows.into_iter().map(&f).collect(),
+                    row_errors: chunk.row_errors,
+                    error: chunk.error,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The batch endpoints of a table
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum BatchOperation {
+    Create,
+    Update,
+    Delete,
+}
+
+impl BatchOperation {
+    fn method(self) -> Method {
+        match self {
+            Self::Create | Self::Delete => Method::POST,
+            Self::Update => Method::PATCH,
+        }
+    }
+
+    fn endpoint(self) -> &'static str {
+        match self {
+            Self::Create | Self::Update => "batch",
+            Self::Delete => "batch-delete",
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct BatchResponse<T> {
+    items: Vec<T>,
+}
+
+/// Applies a batch operation to a table, chunk by chunk
+///
+/// `items` are the rows or row IDs as sent to Baserow, or the error that
+/// rejected them before sending.
+pub(crate) async fn apply<T: DeserializeOwned>(
+    baserow: &Baserow,
+    operation: BatchOperation,
+    table_id: TableId,
+    items: Vec<Result<Value, BaserowError>>,
+    user_field_names: Option<bool>,
+    mapper: Option<&TableMapper>,
+) -> BatchResult<T> {
+    let mut items = items.into_iter().enumerate().peekable();
+    let mut chunks = Vec::new();
+    while items.peek().is_some() {
+        let chunk: Vec<_> = items.by_ref().take(BATCH_SIZE).collect();
+        let chunk = apply_chunk(
+            baserow,
+            operation,
+            table_id,
+            chunk,
+            user_field_names,
+            mapper,
+        )
+        .await;
+        chunks.push(chunk);
+    }
+    BatchResult { chunks }
+}
+
+async fn apply_chunk<T: DeserializeOwned>(
+    baserow: &Baserow,
+    operation: BatchOperation,
+    table_id: TableId,
+    chunk: Vec<(usize, Result<Value, BaserowError>)>,
+    user_field_names: Option<bool>,
+    mapper: Option<&TableMapper>,
+) -> BatchChunk<T> {
+    let range = chunk.first().map_or(0, |(index, _)| *index)
+        ..chunk.last().map_or(0, |(index, _)| index + 1);
+    let mut row_errors = Vec::new();
+    let mut items = Vec::new();
+    for (index, item) in chunk {
+        match item {
+            Ok(item) => items.push((index, item)),
+            Err(error) => row_errors.push(RowError { index, error }),
+        }
+    }
+
+    let mut resubmitted = false;
+    let error = loop {
+        if items.is_empty() {
+            break None;
+        }
+
+        let values: Vec<Value> = items.iter().map(|(_, item)| item.clone()).collect();
+        let error = match send(baserow, operation, table_id, &values, user_field_names).await {
+            Ok(rows) => {
+                let status = match row_errors.is_empty() {
+                    true => ChunkStatus::Applied,
+                    false => ChunkStatus::PartiallyApplied,
+                };
+                row_errors.sort_by_key(|error| error.index);
+                return BatchChunk {
+                    range,
+                    status,
+                    rows,
+                    row_errors,
+                    error: None,
+                };
+            }
+            Err(error) => error,
+        };
+
+        // Apply the remaining rows once if Baserow rejected individual rows
+        let rejected = rejected_rows(&error, &values, table_id, mapper);
+        if resubmitted || rejected.is_empty() {
+            break Some(error);
+        }
+        warn!(
+            rejected = rejected.len(),
+            "Batch rejected individual rows, applying the others"
+        );
+        for (position, error) in rejected.into_iter().rev() {
+            let (index, _) = items.remove(position);
+            row_errors.push(RowError { index, error });
+        }
+        resubmitted = true;
+    };
+
+    row_errors.sort_by_key(|error| error.index);
+    BatchChunk {
+        range,
+        status: ChunkStatus::Failed,
+        rows: Vec::new(),
+        row_errors,
+        error,
+    }
+}
+
+/// Sends a single batch request, returning the applied rows
+async fn send<T: DeserializeOwned>(
+    baserow: &Baserow,
+    operation: BatchOperation,
+    table_id: TableId,
+    items: &[Value],
+    user_field_names: Option<bool>,
+) -> Result<Vec<T>, BaserowError> {
+    let configuration = baserow.configuration();
+    let url = configuration.api_url(&format!(
+        "api/database/rows/table/{}/{}/",
+        table_id,
+        operation.endpoint()
+    ));
+
+    let mut req = baserow.inner.client.request(operation.method(), url);
+    if let Some(use_names) = user_field_names {
+        req = req.query(&[("user_field_names", use_names.to_string())]);
+    }
+    req = baserow.auth_header().await.apply(req);
+
+    debug!(operation = ?operation, item_count = items.len(), "Sending batch request");
+    let resp = baserow
+        .trace_request(
+            &baserow.inner.client,
+            req.json(&json!({ "items": items })).build()?,
+        )
+        .await?;
+
+    match resp.status() {
+        StatusCode::OK => Ok(resp.json::<BatchResponse<T>>().await?.items),
+        // Deleting responds without content, the applied rows are the IDs sent
+        StatusCode::NO_CONTENT => Ok(items
+            .iter()
+            .cloned()
+            .map(serde_json::from_value)
+            .collect::<Result<_, _>>()?),
+        _ => Err(BaserowError::from_batch_response(resp, table_id).await),
+    }
+}
+
+/// Returns the positions of the rows Baserow rejected individually, in ascending order
+fn rejected_rows(
+    error: &BaserowError,
+    items: &[Value],
+    table_id: TableId,
+    mapper: Option<&TableMapper>,
+) -> Vec<(usize, BaserowError)> {
+    let BaserowError::ApiError {
+        response: Some(response),
+        ..
+    } = error
+    else {
+        return Vec::new();
+    };
+
+    if let Some(item_errors) = response.item_errors() {
+        return item_errors
+            .into_iter()
+            .filter(|(position, _)| *position < items.len())
+            .map(|(position, fields)| {
+                let error = BaserowError::InvalidFieldValues(fields)
+                    .map_field_names(|key| field_name(mapper, key));
+                (position, error)
+            })
+            .collect();
+    }
+
+    // Baserow reports rows that do not exist like "The rows [3, 7] do not exist."
+    if response.error == "ERROR_ROW_DOES_NOT_EXIST" {
+        let message = response.message();
+        let missing: Vec<RowId> = message
+            .split_once('[')
+            .and_then(|(_, ids)| ids.split_once(']'))
+            .map(|(ids, _)| {
+                ids.split(',')
+                    .filter_map(|id| id.trim().parse().ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+        return items
+            .iter()
+            .enumerate()
+            .filter_map(|(position, item)| {
+                let row_id = RowId(item.as_u64().or_else(|| item.get("id")?.as_u64())?);
+                missing
+                    .contains(&row_id)
+                    .then_some((position, BaserowError::RowNotFound { table_id, row_id }))
+            })
+            .collect();
+    }
+
+    Vec::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{api::client::BaserowClient, BaserowTableOperations, ConfigBuilder, TableId};
+    use mockito::Matcher;
+    use std::collections::HashMap;
+
+    #[tokio::test]
+    async fn test_create_many_partial_failure() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        let rejected_mock = server
+            .mock("POST", "/api/database/rows/table/1234/batch/")
+            .match_query(Matcher::Any)
+            .with_status(400)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"error": "ERROR_REQUEST_BODY_VALIDATION", "detail": {"items": {"1": {"field_2": [{"error": "A valid number is required.", "code": "invalid"}]}}}}"#,
+            )
+            .expect(1)
+            .create();
+        let created_mock = server
+            .mock("POST", "/api/database/rows/table/1234/batch/")
+            .match_query(Matcher::Any)
+            .match_body(Matcher::Json(serde_json::json!({
+                "items": [{"field_1": "a"}, {"field_1": "c"}]
+            })))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"items": [{"id": 1, "field_1": "a"}, {"id": 2, "field_1": "c"}]}"#)
+            .expect(1)
+            .create();
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+
+        let rows = vec![
+            HashMap::from([("field_1".to_string(), json!("a"))]),
+            HashMap::from([
+                ("field_1".to_string(), json!("b")),
+                ("field_2".to_string(), json!("two")),
+            ]),
+            HashMap::from([("field_1".to_string(), json!("c"))]),
+        ];
+        let result = baserow
+            .table_by_id(TableId(1234))
+            .create_many(rows, None)
+            .await
+            .unwrap();
+
+        assert!(!result.is_complete());
+        assert_eq!(result.chunks.len(), 1);
+        assert_eq!(result.chunks[0].status, ChunkStatus::PartiallyApplied);
+        assert_eq!(result.chunks[0].range, 0..3);
+        assert_eq!(result.rows().count(), 2);
+
+        let errors: Vec<_> = result.row_errors().collect();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].index, 1);
+        assert!(matches!(
+            &errors[0].error,
+            BaserowError::InvalidFieldValues(fields) if fields["field_2"] == ["A valid number is required."]
+        ));
+
+        rejected_mock.assert();
+        created_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_delete_many_chunks() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        let deleted_mock = server
+            .mock("POST", "/api/database/rows/table/1234/batch-delete/")
+            .with_status(204)
+            .expect(1)
+            .create();
+        let missing_mock = server
+            .mock("POST", "/api/database/rows/table/1234/batch-delete/")
+            .with_status(404)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"error": "ERROR_ROW_DOES_NOT_EXIST", "detail": "The rows [205, 210] do not exist."}"#,
+            )
+            .expect(1)
+            .create();
+        let failed_mock = server
+            .mock("POST", "/api/database/rows/table/1234/batch-delete/")
+            .with_status(500)
+            .expect(1)
+            .create();
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+
+        let result = baserow
+            .table_by_id(TableId(1234))
+            .delete_many((1..=250).map(RowId).collect())
+            .await
+            .unwrap();
+
+        assert_eq!(result.chunks.len(), 2);
+        assert_eq!(result.chunks[0].status, ChunkStatus::Applied);
+        assert_eq!(result.chunks[0].rows.len(), BATCH_SIZE);
+        assert_eq!(result.chunks[1].range, 200..250);
+        assert_eq!(result.chunks[1].status, ChunkStatus::Failed);
+        assert!(matches!(
+            result.chunks[1].error,
+            Some(BaserowError::ApiError { .. })
+        ));
+        let missing: Vec<_> = result.row_errors().map(|error| error.index).collect();
+        assert_eq!(missing, vec![204, 209]);
+        assert_eq!(result.failed_chunks().count(), 1);
+
+        deleted_mock.assert();
+        missing_mock.assert();
+        failed_mock.assert();
+    }
+}
@@ -1,48 +1,329 @@
-use std::{error::Error, fs::File};
+use std::{
+    fs::File,
+    sync::Arc,
+    time::{Duration, Instant, SystemTime},
+};
 
-use reqwest::{Client, Request, Response};
+use reqwest::{
+    header::{HeaderMap, AUTHORIZATION, RETRY_AFTER},
+    Client, Method, Request, RequestBuilder, Response, StatusCode,
+};
+use tokio::sync::Semaphore;
 use tracing::{debug, error, info, instrument, span, trace, warn, Instrument, Level};
 
+#[cfg(feature = "capture")]
+use crate::capture::Capture;
+#[cfg(feature = "vcr")]
+use crate::vcr::Cassette;
 use crate::{
-    api::file::File as BaserowFile,
-    error::{FileUploadError, TokenAuthError},
+    api::{
+        authentication::AuthHeader,
+        file::{File as BaserowFile, UploadFileOptions, UploadViaUrlOptions},
+        hook::RequestHook,
+        user::Account,
+    },
+    dry_run::DryRun,
+    error::{BaserowError, FileUploadError, TokenAuthError},
+    ids::TableId,
+    retry::{is_idempotent, RetryOn, RetryPolicy},
     BaserowTable, Configuration, TableField,
 };
 
+/// How long before its expiry a JWT is refreshed ahead of a request
+pub(crate) const TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(30);
+/// How long to wait before retrying a rate limited request without a `Retry-After` header
+/// if no retry policy is configured
+const DEFAULT_RATE_LIMIT_DELAY: Duration = Duration::from_secs(1);
+/// The longest `Retry-After` a rate limited request waits for if no retry policy is configured
+const MAX_RATE_LIMIT_DELAY: Duration = Duration::from_secs(30);
+
 #[async_trait::async_trait]
 pub trait RequestTracing {
     /// Trace an HTTP request and its response
     #[instrument(skip(self, client, request), fields(method = %request.method(), url = %request.url()), err)]
     async fn trace_request(&self, client: &Client, request: Request) -> reqwest::Result<Response> {
-        let span = span!(
-            Level::DEBUG,
-            "http_request",
-            method = %request.method(),
-            url = %request.url(),
-        );
-
-        async move {
-            debug!("Sending HTTP request");
-            trace!(headers = ?request.headers(), "Request headers");
-            let response = client.execute(request).await?;
-            let status = response.status();
-
-            if status.is_success() {
-                info!(status = %status, "HTTP request successful");
-                trace!(headers = ?response.headers(), "Response headers");
-            } else {
-                error!(status = %status, "HTTP request failed");
-                warn!(headers = ?response.headers(), "Failed response headers");
+        execute_traced(client, request, Pipeline::default()).await
+    }
+}
+
+/// What a request passes on its way to the server, as set up on the client
+#[derive(Clone, Copy, Default)]
+struct Pipeline<'a> {
+    hooks: &'a [Arc<dyn RequestHook>],
+    limit: Option<&'a Semaphore>,
+    #[cfg(feature = "vcr")]
+    cassette: Option<&'a Cassette>,
+    dry_run: Option<&'a DryRun>,
+    #[cfg(feature = "capture")]
+    capture: Option<&'a Capture>,
+}
+
+impl<'a> Pipeline<'a> {
+    fn of<C: BaserowClient + ?Sized>(client: &'a C) -> Self {
+        Self {
+            hooks: client.request_hooks(),
+            limit: client.request_limit(),
+            #[cfg(feature = "vcr")]
+            cassette: client.cassette(),
+            dry_run: client.dry_run(),
+            #[cfg(feature = "capture")]
+            capture: client.capture(),
+        }
+    }
+}
+
+/// Sends a request, or records or replays it with the cassette of the pipeline, if any
+#[cfg_attr(not(feature = "vcr"), allow(unused_variables))]
+async fn send(
+    client: &Client,
+    request: Request,
+    pipeline: &Pipeline<'_>,
+) -> reqwest::Result<Response> {
+    #[cfg(feature = "vcr")]
+    if let Some(cassette) = pipeline.cassette {
+        return cassette.execute(client, request).await;
+    }
+    client.execute(request).await
+}
+
+/// Send an HTTP request through the request hooks, logging the request and the response status
+///
+/// Waits for a permit of the request limit first, if any, which is held until
+/// the response headers arrived. With a cassette, the request is recorded or
+/// replayed instead of just sent. In a dry run, requests changing data are
+/// collected instead. With a capture, a copy of the exchange is passed on
+/// after the response body was read.
+async fn execute_traced(
+    client: &Client,
+    mut request: Request,
+    pipeline: Pipeline<'_>,
+) -> reqwest::Result<Response> {
+    let Pipeline {
+        hooks,
+        limit,
+        dry_run,
+        ..
+    } = pipeline;
+    let span = span!(
+        Level::DEBUG,
+        "http_request",
+        method = %request.method(),
+        url = %request.url(),
+    );
+
+    async move {
+        let _permit = match limit {
+            Some(limit) => {
+                trace!(
+                    available = limit.available_permits(),
+                    "Waiting for request permit"
+                );
+                limit.acquire().await.ok()
+            }
+            None => None,
+        };
+
+        #[cfg(feature = "capture")]
+        let unhooked = pipeline.capture.map(|_| request.headers().clone());
+        for hook in hooks {
+            hook.before_send(&mut request).await;
+        }
+
+        debug!("Sending HTTP request");
+        trace!(headers = ?request.headers(), "Request headers");
+        let method = request.method().clone();
+        #[cfg(feature = "capture")]
+        let captured_request = pipeline
+            .capture
+            .zip(unhooked)
+            .map(|(capture, unhooked)| (capture, capture.request(&request, &unhooked)));
+        let started = Instant::now();
+        let result = match dry_run {
+            Some(dry_run) if !dry_run.sends(&request) => Ok(dry_run.collect(request)),
+            _ => send(client, request, &pipeline).await,
+        };
+        let elapsed = started.elapsed();
+        #[cfg(feature = "capture")]
+        let result = match captured_request {
+            Some((capture, request)) => capture.exchange(request, result, elapsed).await,
+            None => result,
+        };
+        let mut response = match result {
+            Ok(response) => response,
+            Err(e) => {
+                for hook in hooks {
+                    hook.on_error(&e, started.elapsed()).await;
+                }
+                return Err(e);
             }
+        };
+        // Lets errors built from the response report the method of the request
+        response.extensions_mut().insert(method);
+        let status = response.status();
+
+        if status.is_success() {
+            info!(status = %status, "HTTP request successful");
+            trace!(headers = ?response.headers(), "Response headers");
+        } else {
+            error!(status = %status, "HTTP request failed");
+            warn!(headers = ?response.headers(), "Failed response headers");
+        }
+
+        for hook in hooks {
+            hook.after_receive(&response, elapsed).await;
+        }
+
+        Ok(response)
+    }
+    .instrument(span)
+    .await
+}
 
+/// Parses the `Retry-After` header, given either in seconds or as HTTP date
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    match value.parse::<u64>() {
+        Ok(seconds) => Some(Duration::from_secs(seconds)),
+        Err(_) => httpdate::parse_http_date(value)
+            .ok()
+            .map(|date| date.duration_since(SystemTime::now()).unwrap_or_default()),
+    }
+}
+
+/// Send an HTTP request, retrying it while it fails
+///
+/// Rate limited requests are retried up to `rate_limit_retries` times. The
+/// retry policy additionally retries idempotent requests on the failures it
+/// is configured for. A `Retry-After` longer than the policy's maximum delay
+/// is not waited for; the rate limited response is returned instead.
+async fn execute_with_retries(
+    client: &Client,
+    mut request: Request,
+    rate_limit_retries: u32,
+    policy: Option<&RetryPolicy>,
+    pipeline: Pipeline<'_>,
+) -> reqwest::Result<Response> {
+    let policy = policy.filter(|_| is_idempotent(request.method()));
+    let mut retries = 0;
+    loop {
+        let policy_allows_retry = policy.is_some_and(|policy| policy.allows_retry(retries));
+        let retries_on =
+            |failure| policy_allows_retry && policy.is_some_and(|p| p.retries(failure));
+        let backoff = || policy.map_or(DEFAULT_RATE_LIMIT_DELAY, |policy| policy.delay(retries));
+        let max_delay = policy.map_or(MAX_RATE_LIMIT_DELAY, |policy| policy.delay_limit());
+
+        // Requests with streaming bodies cannot be cloned and are not retried
+        let retry = match policy_allows_retry || retries < rate_limit_retries {
+            true => request.try_clone(),
+            false => None,
+        };
+
+        let result = execute_traced(client, request, pipeline).await;
+        let Some(retry) = retry else {
+            return result;
+        };
+
+        let delay = match &result {
             Ok(response)
+                if response.status() == StatusCode::TOO_MANY_REQUESTS
+                    && (retries < rate_limit_retries || retries_on(RetryOn::RateLimited)) =>
+            {
+                match retry_after(response.headers()) {
+                    Some(delay) if delay > max_delay => {
+                        warn!(retry_after = ?delay, max_delay = ?max_delay, "Retry-After exceeds the maximum delay, not retrying");
+                        None
+                    }
+                    delay => Some(delay.unwrap_or_else(backoff)),
+                }
+            }
+            Ok(response)
+                if response.status().is_server_error() && retries_on(RetryOn::ServerErrors) =>
+            {
+                Some(backoff())
+            }
+            Err(e)
+                if (e.is_connect() || e.is_timeout()) && retries_on(RetryOn::ConnectionErrors) =>
+            {
+                Some(backoff())
+            }
+            _ => None,
+        };
+
+        match delay {
+            Some(delay) => {
+                warn!(attempt = retries + 1, delay = ?delay, "Request failed, retrying");
+                tokio::time::sleep(delay).await;
+                request = retry;
+                retries += 1;
+            }
+            None => return result,
         }
-        .instrument(span)
-        .await
     }
 }
 
-impl<T: BaserowClient + ?Sized> RequestTracing for T {}
+#[async_trait::async_trait]
+impl<T: BaserowClient + ?Sized> RequestTracing for T {
+    /// Trace an HTTP request and its response
+    ///
+    /// Requests authenticated with a JWT that is about to expire get a freshly
+    /// refreshed token before they are sent. If re-authentication is enabled,
+    /// JWT-authenticated requests rejected with 401 are retried once after
+    /// renewing the token. Failed requests are retried as configured via
+    /// `ConfigBuilder::retry_rate_limited` and `ConfigBuilder::retry_policy`.
+    #[instrument(skip(self, client, request), fields(method = %request.method(), url = %request.url()), err)]
+    async fn trace_request(
+        &self,
+        client: &Client,
+        mut request: Request,
+    ) -> reqwest::Result<Response> {
+        let uses_jwt = request
+            .headers()
+            .get(AUTHORIZATION)
+            .is_some_and(|value| value.as_bytes().starts_with(b"JWT "));
+
+        let configuration = self.configuration();
+        let retries = configuration.rate_limit_retries;
+        let policy = configuration.retry_policy.as_ref();
+        let pipeline = Pipeline::of(self);
+
+        if !uses_jwt {
+            return execute_with_retries(client, request, retries, policy, pipeline).await;
+        }
+
+        if configuration.refresh_token.is_some()
+            && configuration.jwt_expires_within(TOKEN_REFRESH_MARGIN)
+        {
+            debug!("Access token is about to expire, refreshing");
+            match self.refresh_token().await {
+                Ok(()) => self.auth_header().await.apply_to(&mut request),
+                Err(e) => {
+                    warn!(error = %e, "Failed to refresh access token, using the current one")
+                }
+            }
+        }
+
+        // Requests with streaming bodies cannot be cloned and are not retried
+        let retry = match configuration.reauthenticate {
+            true => request.try_clone(),
+            false => None,
+        };
+
+        let response = execute_with_retries(client, request, retries, policy, pipeline).await?;
+
+        if let (StatusCode::UNAUTHORIZED, Some(mut retry)) = (response.status(), retry) {
+            warn!("Access token was rejected, re-authenticating");
+            match self.reauthenticate().await {
+                Ok(()) => {
+                    self.auth_header().await.apply_to(&mut retry);
+                    return execute_with_retries(client, retry, retries, policy, pipeline).await;
+                }
+                Err(e) => warn!(error = %e, "Re-authentication failed"),
+            }
+        }
+
+        Ok(response)
+    }
+}
 
 /// Trait defining the public API interface for Baserow
 ///
@@ -52,7 +333,7 @@ impl<T: BaserowClient + ?Sized> RequestTracing for T {}
 /// All HTTP operations are automatically traced through the RequestTracing trait,
 /// which provides detailed logging of request/response cycles.
 #[async_trait::async_trait]
-pub trait BaserowClient: RequestTracing {
+pub trait BaserowClient: RequestTracing + Send + Sync {
     /// Authenticates an existing user based on their email and their password.
     /// If successful, an access token and a refresh token will be returned.
     ///
@@ -60,34 +341,177 @@ pub trait BaserowClient: RequestTracing {
     /// excluding sensitive information like credentials.
     async fn token_auth(&self) -> Result<Box<dyn BaserowClient>, TokenAuthError>;
 
+    /// Obtains a new access token using the refresh token returned by `token_auth()`.
+    ///
+    /// The new token is used by this client and all of its clones. Access tokens
+    /// are refreshed automatically shortly before they expire, so calling this
+    /// explicitly is rarely necessary.
+    async fn refresh_token(&self) -> Result<(), TokenAuthError>;
+
+    /// Renews the authentication of this client after its access token was rejected.
+    ///
+    /// Tries to refresh the access token first and falls back to a new login with
+    /// the configured email and password. Used to retry requests that got a 401
+    /// when `ConfigBuilder::reauthenticate` is enabled.
+    async fn reauthenticate(&self) -> Result<(), TokenAuthError>;
+
     /// Retrieves all fields for a given table.
     ///
     /// This operation is traced with detailed logging of the request/response cycle
     /// and field retrieval results.
-    async fn table_fields(&self, table_id: u64) -> Result<Vec<TableField>, Box<dyn Error>>;
+    async fn table_fields(&self, table_id: TableId) -> Result<Vec<TableField>, BaserowError>;
 
     /// Returns a table by its ID.
-    fn table_by_id(&self, id: u64) -> BaserowTable;
+    fn table_by_id(&self, id: TableId) -> BaserowTable;
+
+    /// Retrieves a table including its name, order and database.
+    ///
+    /// Unlike `table_by_id`, this sends a request, which requires a JWT.
+    async fn get_table(&self, id: TableId) -> Result<BaserowTable, BaserowError>;
 
     /// Upload a file to Baserow
     ///
     /// This operation is traced with detailed logging of the upload process,
-    /// including file metadata and upload status.
+    /// including file metadata and upload status. The file is sent in a single
+    /// request, which is not retried, as a retry could store the file twice.
     async fn upload_file(
         &self,
         file: File,
         filename: String,
     ) -> Result<BaserowFile, FileUploadError>;
 
+    /// Upload a file to Baserow with explicit options
+    ///
+    /// Allows overriding the content type, which `upload_file` guesses from the
+    /// filename, or detecting it from the file content.
+    async fn upload_file_with_options(
+        &self,
+        file: File,
+        filename: String,
+        options: UploadFileOptions,
+    ) -> Result<BaserowFile, FileUploadError>;
+
     /// Upload a file to Baserow via URL
     ///
     /// This operation is traced with detailed logging of the URL validation
     /// and upload process.
     async fn upload_file_via_url(&self, url: &str) -> Result<BaserowFile, FileUploadError>;
 
+    /// Upload a file to Baserow via URL with explicit options
+    ///
+    /// Allows restricting the accepted URLs and setting a timeout for the upload.
+    async fn upload_file_via_url_with_options(
+        &self,
+        url: &str,
+        options: UploadViaUrlOptions,
+    ) -> Result<BaserowFile, FileUploadError>;
+
+    /// Returns the credentials for the next request
+    ///
+    /// Consults the token provider installed with `Baserow::with_token_provider`,
+    /// or falls back to the JWT and database token of the configuration.
+    async fn auth_header(&self) -> AuthHeader;
+
     /// Get the underlying configuration
     fn get_configuration(&self) -> Configuration;
 
+    /// Get a snapshot of the underlying configuration without copying it
+    ///
+    /// The snapshot is not updated by later token refreshes.
+    fn configuration(&self) -> Arc<Configuration> {
+        Arc::new(self.get_configuration())
+    }
+
     /// Get the underlying HTTP client
     fn get_client(&self) -> Client;
+
+    /// Returns the hooks invoked around every HTTP request
+    ///
+    /// See `Baserow::with_request_hook`.
+    fn request_hooks(&self) -> &[Arc<dyn RequestHook>] {
+        &[]
+    }
+
+    /// Returns the semaphore limiting the requests in flight, if any
+    ///
+    /// See `Baserow::with_max_concurrent_requests`.
+    fn request_limit(&self) -> Option<&Semaphore> {
+        None
+    }
+
+    /// Returns the cassette recording or replaying the HTTP requests, if any
+    ///
+    /// See `Baserow::with_cassette`.
+    #[cfg(feature = "vcr")]
+    fn cassette(&self) -> Option<&Cassette> {
+        None
+    }
+
+    /// Returns the requests collected instead of sent, if this client does a dry run
+    ///
+    /// See `Baserow::with_dry_run`.
+    fn dry_run(&self) -> Option<&DryRun> {
+        None
+    }
+
+    /// Returns the capture receiving copies of the HTTP exchanges, if any
+    ///
+    /// See `Baserow::with_capture`.
+    #[cfg(feature = "capture")]
+    fn capture(&self) -> Option<&Capture> {
+        None
+    }
+
+    /// Applies account settings changed via `update_account` to the configured user
+    ///
+    /// Does nothing by default.
+    fn apply_account(&self, _account: &Account) {}
+
+    /// Starts a request to an endpoint the client doesn't model yet
+    ///
+    /// The path is relative to the base URL, e.g. `api/database/views/1/`, and
+    /// the request carries the credentials of the client. Send it via
+    /// `send_request` to have it traced, retried and passed to the request hooks.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use baserow_rs::{api::client::BaserowClient, Baserow, ConfigBuilder};
+    /// use reqwest::Method;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let config = ConfigBuilder::new()
+    ///         .base_url("https://api.baserow.io")
+    ///         .api_key("your-api-key")
+    ///         .try_build()
+    ///         .expect("invalid configuration");
+    ///     let baserow = Baserow::with_configuration(config);
+    ///
+    ///     let request = baserow
+    ///         .request(Method::GET, "api/database/views/1/")
+    ///         .await
+    ///         .query(&[("include", "filters")]);
+    ///     let view: serde_json::Value = baserow.send_request(request).await.unwrap().json().await.unwrap();
+    /// }
+    /// ```
+    async fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        let url = self.configuration().api_url(path);
+        self.auth_header()
+            .await
+            .apply(self.get_client().request(method, url))
+    }
+
+    /// Sends a request started via `request`
+    ///
+    /// Responses with a status other than 2xx are returned as error.
+    #[instrument(skip(self, request), err)]
+    async fn send_request(&self, request: RequestBuilder) -> Result<Response, BaserowError> {
+        let client = self.get_client();
+        let resp = self.trace_request(&client, request.build()?).await?;
+
+        match resp.status().is_success() {
+            true => Ok(resp),
+            false => Err(BaserowError::from_response(resp).await),
+        }
+    }
 }
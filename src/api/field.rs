@@ -0,0 +1,219 @@
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use tracing::{debug, info, instrument};
+
+use crate::{
+    api::client::BaserowClient,
+    error::BaserowError,
+    ids::{FieldId, TableId},
+    TableField,
+};
+
+/// The definition of a field to create or update
+///
+/// Type specific options are sent as given, named as in Baserow's API, e.g.
+/// `number_decimal_places` or `select_options`.
+///
+/// # Example
+/// ```
+/// use baserow_rs::api::field::FieldSpec;
+/// use serde_json::json;
+///
+/// let price = FieldSpec::new("Price", "number").option("number_decimal_places", 2);
+/// let status = FieldSpec::new("Status", "single_select").option(
+///     "select_options",
+///     json!([{"value": "Open", "color": "blue"}, {"value": "Done", "color": "green"}]),
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FieldSpec {
+    pub name: String,
+    /// The type of the field, e.g. `text`, `number` or `single_select`
+    pub r#type: String,
+    /// The type specific options of the field
+    #[serde(flatten)]
+    pub options: Map<String, Value>,
+}
+
+impl FieldSpec {
+    pub fn new(name: &str, r#type: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            r#type: r#type.to_string(),
+            options: Map::new(),
+        }
+    }
+
+    /// Set a type specific option of the field
+    pub fn option(mut self, name: &str, value: impl Into<Value>) -> Self {
+        self.options.insert(name.to_string(), value.into());
+        self
+    }
+}
+
+/// Trait defining the field operations available on a Baserow client
+///
+/// Fields can only be changed by users, so the client has to be
+/// authenticated via `token_auth()`.
+///
+/// # Example
+/// ```no_run
+/// use baserow_rs::{
+///     api::{client::BaserowClient, field::{BaserowFieldOperations, FieldSpec}},
+///     Baserow, ConfigBuilder, TableId,
+/// };
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let config = ConfigBuilder::new()
+///         .base_url("https://api.baserow.io")
+///         .email("user@example.com")
+///         .password("password")
+///         .try_build()
+///         .expect("invalid configuration");
+///     let client = Baserow::with_configuration(config).token_auth().await.unwrap();
+///
+///     let field = client
+///         .create_field(TableId(1234), &FieldSpec::new("Notes", "long_text"))
+///         .await
+///         .unwrap();
+///     client
+///         .update_field(field.id, &FieldSpec::new("Comments", "long_text"))
+///         .await
+///         .unwrap();
+/// }
+/// ```
+#[async_trait]
+pub trait BaserowFieldOperations: BaserowClient {
+    /// Creates a field in a table
+    ///
+    /// # Arguments
+    /// * `table_id` - The table to create the field in
+    /// * `field` - The definition of the field
+    #[instrument(skip(self, field), fields(field_name = %field.name), err)]
+    async fn create_field(
+        &self,
+        table_id: TableId,
+        field: &FieldSpec,
+    ) -> Result<TableField, BaserowError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url(&format!("api/database/fields/table/{}/", table_id));
+
+        let req = self
+            .auth_header()
+            .await
+            .apply_jwt(client.post(url))?
+            .json(field);
+
+        debug!("Sending request to create field");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let field: TableField = resp.json().await?;
+                info!(field_id = %field.id, "Successfully created field");
+                Ok(field)
+            }
+            _ => Err(BaserowError::from_table_response(resp, table_id).await),
+        }
+    }
+
+    /// Changes the name, type or options of a field
+    ///
+    /// # Arguments
+    /// * `field_id` - The field to update
+    /// * `field` - The new definition of the field
+    #[instrument(skip(self, field), fields(field_name = %field.name), err)]
+    async fn update_field(
+        &self,
+        field_id: FieldId,
+        field: &FieldSpec,
+    ) -> Result<TableField, BaserowError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url(&format!("api/database/fields/{}/", field_id));
+
+        let req = self
+            .auth_header()
+            .await
+            .apply_jwt(client.patch(url))?
+            .json(field);
+
+        debug!("Sending request to update field");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let field: TableField = resp.json().await?;
+                info!("Successfully updated field");
+                Ok(field)
+            }
+            _ => Err(BaserowError::from_response(resp).await),
+        }
+    }
+}
+
+impl<T: BaserowClient + ?Sized> BaserowFieldOperations for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Baserow, ConfigBuilder};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_create_and_update_field() {
+        let mut server = mockito::Server::new_async().await;
+
+        let create_mock = server
+            .mock("POST", "/api/database/fields/table/1234/")
+            .match_header("Authorization", "JWT jwt-token")
+            .match_body(mockito::Matcher::Json(json!({
+                "name": "Price",
+                "type": "number",
+                "number_decimal_places": 2
+            })))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": 5, "table_id": 1234, "name": "Price", "order": 3, "type": "number", "primary": false, "read_only": false, "number_decimal_places": 2}"#)
+            .create_async()
+            .await;
+        let update_mock = server
+            .mock("PATCH", "/api/database/fields/5/")
+            .match_header("Authorization", "JWT jwt-token")
+            .match_body(mockito::Matcher::Json(
+                json!({"name": "Cost", "type": "number"}),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": 5, "table_id": 1234, "name": "Cost", "order": 3, "type": "number", "primary": false, "read_only": false}"#)
+            .create_async()
+            .await;
+
+        let config = ConfigBuilder::new()
+            .base_url(&server.url())
+            .access_token("jwt-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(config);
+
+        let price = FieldSpec::new("Price", "number").option("number_decimal_places", 2);
+        let field = baserow.create_field(TableId(1234), &price).await.unwrap();
+        assert_eq!(field.id, FieldId(5));
+        assert_eq!(field.number_decimal_places, Some(2));
+
+        let field = baserow
+            .update_field(field.id, &FieldSpec::new("Cost", "number"))
+            .await
+            .unwrap();
+        assert_eq!(field.name, "Cost");
+
+        create_mock.assert_async().await;
+        update_mock.assert_async().await;
+    }
+}
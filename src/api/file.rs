@@ -1,19 +1,57 @@
+use std::{
+    collections::HashMap,
+    io::{Read, Seek, SeekFrom},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use reqwest::{Response, Url};
 use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tracing::{debug, info, instrument};
 
-#[derive(Debug, Serialize, Deserialize)]
+use crate::{
+    api::client::BaserowClient,
+    error::{ApiErrorResponse, FileDownloadError, FileUploadError},
+};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Thumbnail {
     pub url: String,
     pub width: Option<u32>,
     pub height: Option<u32>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// The thumbnails Baserow generated for an image
+///
+/// `tiny` and `small` are always present. Other sizes, like the `card_cover`
+/// used by gallery views or sizes configured on self-hosted instances, are
+/// collected in `other`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Thumbnails {
     pub tiny: Thumbnail,
     pub small: Thumbnail,
+    #[serde(flatten)]
+    pub other: HashMap<String, Thumbnail>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl Thumbnails {
+    /// Returns the thumbnail of the given size, e.g. `"tiny"` or `"card_cover"`
+    pub fn get(&self, size: &str) -> Option<&Thumbnail> {
+        match size {
+            "tiny" => Some(&self.tiny),
+            "small" => Some(&self.small),
+            size => self.other.get(size),
+        }
+    }
+
+    /// Returns the thumbnail used as the cover of gallery view cards, if present
+    pub fn card_cover(&self) -> Option<&Thumbnail> {
+        self.get("card_cover")
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct File {
     pub url: String,
     pub thumbnails: Option<Thumbnails>,
@@ -26,9 +64,395 @@ pub struct File {
     pub uploaded_at: String,
 }
 
+/// A file stored in a file field of a row
+///
+/// Rows contain the full file metadata, while only `name` and `visible_name`
+/// are needed to attach a file, e.g. one returned by `upload_file()`, to a row.
+///
+/// # Example
+/// ```
+/// use baserow_rs::api::file::FileFieldValue;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Document {
+///     title: String,
+///     attachments: FileFieldValue,
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BaserowFileRef {
+    /// The unique name of the file in Baserow's storage
+    pub name: String,
+    /// The name the file is displayed with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visible_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnails: Option<Thumbnails>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_image: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_width: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_height: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uploaded_at: Option<String>,
+}
+
+impl BaserowFileRef {
+    /// Creates a reference to an uploaded file, displayed with the given name
+    pub fn new(name: &str, visible_name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            visible_name: Some(visible_name.to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&File> for BaserowFileRef {
+    fn from(file: &File) -> Self {
+        Self {
+            name: file.name.clone(),
+            visible_name: None,
+            url: Some(file.url.clone()),
+            thumbnails: file.thumbnails.clone(),
+            size: Some(file.size),
+            mime_type: Some(file.mime_type.clone()),
+            is_image: Some(file.is_image),
+            image_width: file.image_width,
+            image_height: file.image_height,
+            uploaded_at: Some(file.uploaded_at.clone()),
+        }
+    }
+}
+
+/// The value of a file field: the files attached to a row
+pub type FileFieldValue = Vec<BaserowFileRef>;
+
+impl File {
+    /// Returns the thumbnail of the given size, if the file is an image
+    pub fn thumbnail(&self, size: &str) -> Option<&Thumbnail> {
+        self.thumbnails.as_ref()?.get(size)
+    }
+}
+
+/// Magic bytes identifying common file formats, used to sniff content types
+const MAGIC_BYTES: &[(&[u8], &str)] = &[
+    (b"\x89PNG\r\n\x1a\n", "image/png"),
+    (b"\xff\xd8\xff", "image/jpeg"),
+    (b"GIF87a", "image/gif"),
+    (b"GIF89a", "image/gif"),
+    (b"%PDF-", "application/pdf"),
+    (b"PK\x03\x04", "application/zip"),
+    (b"\x1f\x8b", "application/gzip"),
+    (b"BM", "image/bmp"),
+];
+
+/// Detects the content type of a file from its first bytes
+fn sniff_content_type(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        return Some("image/webp");
+    }
+
+    MAGIC_BYTES
+        .iter()
+        .find(|(magic, _)| bytes.starts_with(magic))
+        .map(|(_, content_type)| *content_type)
+}
+
+/// Options for uploading a file
+///
+/// # Example
+/// ```
+/// use baserow_rs::api::file::UploadFileOptions;
+///
+/// let options = UploadFileOptions::new().content_type("text/csv");
+/// let sniffing = UploadFileOptions::new().sniff_content_type(true);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct UploadFileOptions {
+    content_type: Option<String>,
+    sniff_content_type: bool,
+}
+
+impl UploadFileOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the content type of the file instead of guessing it from the filename
+    pub fn content_type(mut self, content_type: &str) -> Self {
+        self.content_type = Some(content_type.to_string());
+        self
+    }
+
+    /// Detect the content type from the file content if the filename has no known extension
+    ///
+    /// Without sniffing, such files are uploaded as `application/octet-stream`.
+    pub fn sniff_content_type(mut self, enabled: bool) -> Self {
+        self.sniff_content_type = enabled;
+        self
+    }
+
+    /// Determines the content type of a file that is about to be uploaded
+    ///
+    /// Uses the explicit content type, the filename and, if enabled, the
+    /// content of the file in that order. The file position is restored after
+    /// sniffing.
+    pub(crate) fn resolve_content_type<F>(
+        &self,
+        filename: &str,
+        file: &mut F,
+    ) -> Result<String, FileUploadError>
+    where
+        F: Read + Seek,
+    {
+        if let Some(content_type) = &self.content_type {
+            return Ok(content_type.clone());
+        }
+
+        if let Some(mime_type) = mime_guess::from_path(filename).first() {
+            return Ok(mime_type.to_string());
+        }
+
+        if !self.sniff_content_type {
+            return Ok(mime_guess::mime::APPLICATION_OCTET_STREAM.to_string());
+        }
+
+        let position = file.stream_position()?;
+        let mut header = Vec::with_capacity(16);
+        file.by_ref().take(16).read_to_end(&mut header)?;
+        file.seek(SeekFrom::Start(position))?;
+
+        match sniff_content_type(&header) {
+            Some(content_type) => Ok(content_type.to_string()),
+            None => {
+                let error = FileUploadError::InvalidContentType;
+                error.log();
+                Err(error)
+            }
+        }
+    }
+}
+
+/// Options for uploading a file from a URL
+///
+/// # Example
+/// ```
+/// use baserow_rs::api::file::UploadViaUrlOptions;
+/// use std::time::Duration;
+///
+/// let options = UploadViaUrlOptions::new()
+///     .timeout(Duration::from_secs(30))
+///     .allow_scheme("https")
+///     .allow_host("images.example.com");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct UploadViaUrlOptions {
+    pub(crate) timeout: Option<Duration>,
+    allowed_schemes: Vec<String>,
+    allowed_hosts: Vec<String>,
+}
+
+impl UploadViaUrlOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the timeout of the upload request, including Baserow fetching the file
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Only allow URLs with the given scheme, can be called multiple times
+    ///
+    /// All schemes are allowed if none is set.
+    pub fn allow_scheme(mut self, scheme: &str) -> Self {
+        self.allowed_schemes.push(scheme.to_lowercase());
+        self
+    }
+
+    /// Only allow URLs pointing to the given host, can be called multiple times
+    ///
+    /// All hosts are allowed if none is set.
+    pub fn allow_host(mut self, host: &str) -> Self {
+        self.allowed_hosts.push(host.to_lowercase());
+        self
+    }
+
+    /// Checks a URL against the allowed schemes and hosts
+    pub(crate) fn check_url(&self, url: &Url) -> Result<(), FileUploadError> {
+        let scheme_allowed = self.allowed_schemes.is_empty()
+            || self.allowed_schemes.iter().any(|s| s == url.scheme());
+        let host_allowed = self.allowed_hosts.is_empty()
+            || url
+                .host_str()
+                .is_some_and(|host| self.allowed_hosts.iter().any(|h| h == host));
+
+        if scheme_allowed && host_allowed {
+            Ok(())
+        } else {
+            let error = FileUploadError::DisallowedURL(url.to_string());
+            error.log();
+            Err(error)
+        }
+    }
+}
+
+/// Converts an unsuccessful upload response into the matching upload error
+pub(crate) async fn upload_error(resp: Response) -> FileUploadError {
+    let status = resp.status();
+    let error_text = match resp.text().await {
+        Ok(text) => text,
+        Err(e) => return FileUploadError::UploadError(e),
+    };
+
+    let error = match serde_json::from_str::<ApiErrorResponse>(&error_text) {
+        Ok(response) => {
+            let detail = response.message();
+            match response.error.as_str() {
+                "ERROR_FILE_SIZE_TOO_LARGE" => FileUploadError::FileTooLarge(detail),
+                "ERROR_FILE_URL_COULD_NOT_BE_REACHED" => FileUploadError::URLUnreachable(detail),
+                "ERROR_INVALID_FILE" => FileUploadError::InvalidFile(detail),
+                "ERROR_INVALID_FILE_URL" => FileUploadError::InvalidURL(detail),
+                _ => FileUploadError::UnexpectedStatusCode(status),
+            }
+        }
+        Err(_) => FileUploadError::UnexpectedStatusCode(status),
+    };
+    error.log();
+    error
+}
+
+/// Trait defining the file operations available on a Baserow client
+///
+/// The trait is implemented for every [`BaserowClient`], including the client
+/// returned by `token_auth()`.
+#[async_trait]
+pub trait BaserowFileOperations: BaserowClient {
+    /// Downloads the content of an uploaded file into a writer
+    ///
+    /// The content is streamed, so large files are not held in memory. Credentials
+    /// are only sent if the file is served by the configured Baserow instance,
+    /// not when it is hosted on external storage.
+    ///
+    /// # Arguments
+    /// * `file` - The file to download, as returned by an upload or a file field
+    /// * `writer` - The destination of the file content
+    ///
+    /// # Returns
+    /// The number of bytes written
+    #[instrument(skip(self, file, writer), fields(name = %file.name), err)]
+    async fn download_file<W>(&self, file: &File, mut writer: W) -> Result<u64, FileDownloadError>
+    where
+        W: AsyncWrite + Unpin + Send,
+    {
+        download(self, &file.url, &mut writer).await
+    }
+
+    /// Downloads a thumbnail of an image into a writer
+    ///
+    /// # Arguments
+    /// * `file` - The image to download the thumbnail of
+    /// * `size` - The thumbnail size, e.g. `"tiny"`, `"small"` or `"card_cover"`
+    /// * `writer` - The destination of the thumbnail content
+    ///
+    /// # Returns
+    /// The number of bytes written, or `None` if the file has no thumbnail of this size
+    #[instrument(skip(self, file, writer), fields(name = %file.name), err)]
+    async fn download_thumbnail<W>(
+        &self,
+        file: &File,
+        size: &str,
+        mut writer: W,
+    ) -> Result<Option<u64>, FileDownloadError>
+    where
+        W: AsyncWrite + Unpin + Send,
+    {
+        match file.thumbnail(size) {
+            Some(thumbnail) => Ok(Some(download(self, &thumbnail.url, &mut writer).await?)),
+            None => {
+                debug!(size, "File has no thumbnail of this size");
+                Ok(None)
+            }
+        }
+    }
+
+    /// Downloads the content of an uploaded file into memory
+    ///
+    /// # Arguments
+    /// * `file` - The file to download, as returned by an upload or a file field
+    ///
+    /// # Returns
+    /// The file content
+    async fn download_file_bytes(&self, file: &File) -> Result<Vec<u8>, FileDownloadError> {
+        let mut content = Vec::with_capacity(file.size as usize);
+        self.download_file(file, &mut content).await?;
+        Ok(content)
+    }
+}
+
+impl<T: BaserowClient + ?Sized> BaserowFileOperations for T {}
+
+/// Streams the content behind a file URL into a writer
+///
+/// Credentials are only sent if the URL points to the configured Baserow instance.
+async fn download<C, W>(
+    baserow: &C,
+    file_url: &str,
+    writer: &mut W,
+) -> Result<u64, FileDownloadError>
+where
+    C: BaserowClient + ?Sized,
+    W: AsyncWrite + Unpin + Send,
+{
+    let configuration = baserow.configuration();
+    let client = baserow.get_client();
+
+    let url = Url::parse(file_url).map_err(|_| {
+        let error = FileDownloadError::InvalidURL(file_url.to_string());
+        error.log();
+        error
+    })?;
+
+    let mut req = client.get(url.clone());
+    if Url::parse(&configuration.base_url).is_ok_and(|base| base.origin() == url.origin()) {
+        req = baserow.auth_header().await.apply(req);
+    }
+
+    debug!("Sending file download request");
+    let mut resp = baserow.trace_request(&client, req.build()?).await?;
+
+    let status = resp.status();
+    if !status.is_success() {
+        let error = FileDownloadError::UnexpectedStatusCode(status);
+        error.log();
+        return Err(error);
+    }
+
+    let mut written = 0;
+    while let Some(chunk) = resp.chunk().await? {
+        writer.write_all(&chunk).await?;
+        written += chunk.len() as u64;
+    }
+    writer.flush().await?;
+
+    info!(size = written, "File download successful");
+    Ok(written)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Baserow, ConfigBuilder};
     use serde_json::json;
 
     #[test]
@@ -47,6 +471,7 @@ mod tests {
                     width: Some(200),
                     height: Some(200),
                 },
+                other: HashMap::new(),
             }),
             name: "file.jpg".to_string(),
             size: 1024,
@@ -98,6 +523,247 @@ mod tests {
         assert!(file.image_height.is_none());
         assert!(file.thumbnails.is_none());
     }
+
+    #[test]
+    fn test_additional_thumbnail_sizes() {
+        let json = json!({
+            "url": "https://example.com/image.jpg",
+            "thumbnails": {
+                "tiny": {"url": "https://example.com/tiny.jpg", "width": null, "height": 21},
+                "small": {"url": "https://example.com/small.jpg", "width": 48, "height": 48},
+                "card_cover": {"url": "https://example.com/card_cover.jpg", "width": 300, "height": 160}
+            },
+            "name": "image.jpg",
+            "size": 1024,
+            "mime_type": "image/jpeg",
+            "is_image": true,
+            "image_width": 800,
+            "image_height": 600,
+            "uploaded_at": "2023-01-01T00:00:00Z"
+        });
+
+        let file: File = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            file.thumbnail("small").unwrap().url,
+            "https://example.com/small.jpg"
+        );
+        let card_cover = file.thumbnails.as_ref().unwrap().card_cover().unwrap();
+        assert_eq!(card_cover.url, "https://example.com/card_cover.jpg");
+        assert_eq!(card_cover.width, Some(300));
+        assert!(file.thumbnail("huge").is_none());
+
+        let serialized = serde_json::to_value(&file).unwrap();
+        assert!(serialized["thumbnails"]["card_cover"].is_object());
+    }
+
+    #[test]
+    fn test_file_field_value() {
+        let json = json!([{
+            "url": "https://example.com/media/user_files/abc_report.pdf",
+            "thumbnails": null,
+            "visible_name": "report.pdf",
+            "name": "abc_report.pdf",
+            "size": 2048,
+            "mime_type": "application/pdf",
+            "is_image": false,
+            "image_width": null,
+            "image_height": null,
+            "uploaded_at": "2023-01-01T00:00:00Z"
+        }]);
+
+        let value: FileFieldValue = serde_json::from_value(json).unwrap();
+        assert_eq!(value.len(), 1);
+        assert_eq!(value[0].name, "abc_report.pdf");
+        assert_eq!(value[0].visible_name.as_deref(), Some("report.pdf"));
+        assert_eq!(value[0].size, Some(2048));
+
+        let new_value = vec![BaserowFileRef::new("abc_report.pdf", "report.pdf")];
+        assert_eq!(
+            serde_json::to_value(&new_value).unwrap(),
+            json!([{"name": "abc_report.pdf", "visible_name": "report.pdf"}])
+        );
+    }
+
+    #[test]
+    fn test_resolve_content_type() {
+        use std::io::Cursor;
+
+        let mut png = Cursor::new(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR".to_vec());
+        let mut unknown = Cursor::new(b"just some text".to_vec());
+
+        let guessing = UploadFileOptions::new();
+        assert_eq!(
+            guessing
+                .resolve_content_type("image.jpg", &mut png)
+                .unwrap(),
+            "image/jpeg"
+        );
+        assert_eq!(
+            guessing.resolve_content_type("image", &mut png).unwrap(),
+            "application/octet-stream"
+        );
+
+        let explicit = UploadFileOptions::new().content_type("text/csv");
+        assert_eq!(
+            explicit
+                .resolve_content_type("export", &mut unknown)
+                .unwrap(),
+            "text/csv"
+        );
+
+        let sniffing = UploadFileOptions::new().sniff_content_type(true);
+        assert_eq!(
+            sniffing.resolve_content_type("image", &mut png).unwrap(),
+            "image/png"
+        );
+        assert_eq!(png.position(), 0);
+        assert!(matches!(
+            sniffing.resolve_content_type("notes", &mut unknown),
+            Err(FileUploadError::InvalidContentType)
+        ));
+    }
+
+    fn create_test_file(url: String) -> File {
+        File {
+            url,
+            thumbnails: None,
+            name: "file.txt".to_string(),
+            size: 11,
+            mime_type: "text/plain".to_string(),
+            is_image: false,
+            image_width: None,
+            image_height: None,
+            uploaded_at: "2023-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_download_file() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        let mock = server
+            .mock("GET", "/media/user_files/file.txt")
+            .match_header("Authorization", "Token 123")
+            .with_status(200)
+            .with_body("hello world")
+            .create();
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .api_key("123")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+
+        let file = create_test_file(format!("{}/media/user_files/file.txt", mock_url));
+        let content = baserow.download_file_bytes(&file).await.unwrap();
+        assert_eq!(content, b"hello world");
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_download_file_from_external_storage_without_credentials() {
+        let mut server = mockito::Server::new_async().await;
+        let storage_url = server.url();
+
+        let mock = server
+            .mock("GET", "/bucket/file.txt")
+            .match_header("Authorization", mockito::Matcher::Missing)
+            .with_status(200)
+            .with_body("hello world")
+            .create();
+
+        let configuration = ConfigBuilder::new()
+            .base_url("https://baserow.example.com")
+            .api_key("123")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+
+        let file = create_test_file(format!("{}/bucket/file.txt", storage_url));
+        let mut content = Vec::new();
+        let written = baserow.download_file(&file, &mut content).await.unwrap();
+        assert_eq!(written, 11);
+        assert_eq!(content, b"hello world");
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_download_thumbnail() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        let mock = server
+            .mock("GET", "/media/thumbnails/small/image.jpg")
+            .with_status(200)
+            .with_body("thumbnail")
+            .create();
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+
+        let mut file = create_test_file(format!("{}/media/user_files/image.jpg", mock_url));
+        let thumbnail = |size: &str| Thumbnail {
+            url: format!("{}/media/thumbnails/{}/image.jpg", mock_url, size),
+            width: None,
+            height: None,
+        };
+        file.thumbnails = Some(Thumbnails {
+            tiny: thumbnail("tiny"),
+            small: thumbnail("small"),
+            other: HashMap::new(),
+        });
+
+        let mut content = Vec::new();
+        let written = baserow
+            .download_thumbnail(&file, "small", &mut content)
+            .await
+            .unwrap();
+        assert_eq!(written, Some(9));
+        assert_eq!(content, b"thumbnail");
+
+        let missing = baserow
+            .download_thumbnail(&file, "card_cover", &mut content)
+            .await
+            .unwrap();
+        assert_eq!(missing, None);
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_download_missing_file() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        let mock = server
+            .mock("GET", "/media/user_files/file.txt")
+            .with_status(404)
+            .create();
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+
+        let file = create_test_file(format!("{}/media/user_files/file.txt", mock_url));
+        let result = baserow.download_file_bytes(&file).await;
+        assert!(matches!(
+            result,
+            Err(FileDownloadError::UnexpectedStatusCode(
+                reqwest::StatusCode::NOT_FOUND
+            ))
+        ));
+
+        mock.assert();
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
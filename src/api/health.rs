@@ -0,0 +1,229 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, instrument, warn};
+
+use crate::{api::client::BaserowClient, error::BaserowError};
+
+/// The result of a basic health check
+#[derive(Clone, Debug, PartialEq)]
+pub struct HealthStatus {
+    /// Whether the instance reported itself as healthy
+    pub healthy: bool,
+    /// The status code of the health check response
+    pub status: StatusCode,
+    /// How long the instance took to respond
+    pub response_time: Duration,
+}
+
+/// The result of the full health check of an instance
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct FullHealthCheck {
+    /// Whether all checks passed
+    pub passing: bool,
+    /// The state of every checked component, e.g. `"working"` for the database
+    pub checks: HashMap<String, String>,
+    /// The number of tasks waiting in the main background queue
+    #[serde(default)]
+    pub celery_queue_size: Option<u64>,
+    /// The number of tasks waiting in the export queue
+    #[serde(default)]
+    pub celery_export_queue_size: Option<u64>,
+}
+
+/// The result of sending a test email
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct EmailTestResult {
+    pub succeeded: bool,
+    /// The kind of error if sending failed, e.g. `SMTPAuthenticationError`
+    #[serde(default)]
+    pub error_type: Option<String>,
+    #[serde(default)]
+    pub error: Option<String>,
+    #[serde(default)]
+    pub error_stack: Option<String>,
+}
+
+#[derive(Serialize)]
+struct EmailTestRequest<'a> {
+    target_email: &'a str,
+}
+
+/// Trait defining the health checks available on a Baserow client
+///
+/// The basic check needs no credentials, so readiness probes can reuse an
+/// already configured client. The full check and the email test are only
+/// available to staff users authenticated via `token_auth()`.
+///
+/// # Example
+/// ```no_run
+/// use baserow_rs::{api::health::BaserowHealthOperations, Baserow, ConfigBuilder};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let config = ConfigBuilder::new()
+///         .base_url("https://baserow.example.com")
+///         .try_build()
+///         .expect("invalid configuration");
+///     let baserow = Baserow::with_configuration(config);
+///
+///     match baserow.health().await {
+///         Ok(status) if status.healthy => println!("Ready in {:?}", status.response_time),
+///         Ok(status) => println!("Not ready: {}", status.status),
+///         Err(e) => println!("Unreachable: {}", e),
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait BaserowHealthOperations: BaserowClient {
+    /// Checks whether the instance is up and able to serve requests
+    ///
+    /// Unhealthy instances are reported through the returned status, only
+    /// unreachable ones as error.
+    #[instrument(skip(self), err)]
+    async fn health(&self) -> Result<HealthStatus, BaserowError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url("api/_health/");
+
+        debug!("Sending health check request");
+        let started = Instant::now();
+        let resp = self
+            .trace_request(&client, client.get(url).build()?)
+            .await?;
+        let status = HealthStatus {
+            healthy: resp.status().is_success(),
+            status: resp.status(),
+            response_time: started.elapsed(),
+        };
+
+        match status.healthy {
+            true => info!(response_time = ?status.response_time, "Instance is healthy"),
+            false => warn!(status_code = %status.status, "Instance is unhealthy"),
+        }
+        Ok(status)
+    }
+
+    /// Runs all health checks of the instance, including its database, cache and background workers
+    #[instrument(skip(self), err)]
+    async fn full_health(&self) -> Result<FullHealthCheck, BaserowError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url("api/_health/full/");
+
+        let req = self.auth_header().await.apply_jwt(client.get(url))?;
+
+        debug!("Sending full health check request");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let check: FullHealthCheck = resp.json().await?;
+                info!(passing = check.passing, "Full health check completed");
+                Ok(check)
+            }
+            _ => Err(BaserowError::from_response(resp).await),
+        }
+    }
+
+    /// Sends a test email to check the email settings of the instance
+    ///
+    /// # Arguments
+    /// * `target_email` - The address to send the test email to
+    #[instrument(skip(self), err)]
+    async fn test_email(&self, target_email: &str) -> Result<EmailTestResult, BaserowError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url("api/_health/email/");
+
+        let request = EmailTestRequest { target_email };
+        let req = self
+            .auth_header()
+            .await
+            .apply_jwt(client.post(url))?
+            .json(&request);
+
+        debug!("Sending test email request");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let result: EmailTestResult = resp.json().await?;
+                info!(succeeded = result.succeeded, "Test email sent");
+                Ok(result)
+            }
+            _ => Err(BaserowError::from_response(resp).await),
+        }
+    }
+}
+
+impl<T: BaserowClient + ?Sized> BaserowHealthOperations for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Baserow, ConfigBuilder};
+
+    #[tokio::test]
+    async fn test_health() {
+        let mut server = mockito::Server::new_async().await;
+
+        let healthy_mock = server
+            .mock("GET", "/api/_health/")
+            .with_status(200)
+            .with_body("OK")
+            .expect(1)
+            .create_async()
+            .await;
+        let unhealthy_mock = server
+            .mock("GET", "/api/_health/")
+            .with_status(503)
+            .create_async()
+            .await;
+        let full_mock = server
+            .mock("GET", "/api/_health/full/")
+            .match_header("Authorization", "JWT jwt-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+  "passing": false,
+  "checks": {"DatabaseBackend": "working", "CacheBackend": "unavailable"},
+  "celery_queue_size": 3,
+  "celery_export_queue_size": 0
+}"#,
+            )
+            .create_async()
+            .await;
+
+        let config = ConfigBuilder::new()
+            .base_url(&server.url())
+            .access_token("jwt-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(config);
+
+        let status = baserow.health().await.unwrap();
+        assert!(status.healthy);
+        let status = baserow.health().await.unwrap();
+        assert!(!status.healthy);
+        assert_eq!(status.status, StatusCode::SERVICE_UNAVAILABLE);
+
+        let check = baserow.full_health().await.unwrap();
+        assert!(!check.passing);
+        assert_eq!(check.checks["CacheBackend"], "unavailable");
+        assert_eq!(check.celery_queue_size, Some(3));
+
+        healthy_mock.assert_async().await;
+        unhealthy_mock.assert_async().await;
+        full_mock.assert_async().await;
+    }
+}
@@ -0,0 +1,83 @@
+use std::time::Duration;
+
+use reqwest::{
+    header::{HeaderName, HeaderValue},
+    Request, Response,
+};
+
+/// Hook invoked around every HTTP request of a client
+///
+/// Register hooks with `Baserow::with_request_hook` to add custom request
+/// signing, metrics or audit logging. Hooks run in the order they were
+/// registered, once per attempt, so retried requests pass them again.
+///
+/// # Example
+/// ```no_run
+/// use baserow_rs::{api::hook::RequestHook, Baserow, ConfigBuilder};
+/// use reqwest::{Request, Response};
+/// use std::time::Duration;
+///
+/// struct AuditLog;
+///
+/// #[async_trait::async_trait]
+/// impl RequestHook for AuditLog {
+///     async fn before_send(&self, request: &mut Request) {
+///         request
+///             .headers_mut()
+///             .insert("X-Audit-Source", "inventory-sync".parse().unwrap());
+///     }
+///
+///     async fn after_receive(&self, response: &Response, elapsed: Duration) {
+///         println!("{} {} in {:?}", response.status(), response.url(), elapsed);
+///     }
+/// }
+///
+/// let config = ConfigBuilder::new()
+///     .base_url("https://api.baserow.io")
+///     .api_key("your-api-key")
+///     .try_build()
+///     .expect("invalid configuration");
+/// let baserow = Baserow::with_configuration(config).with_request_hook(AuditLog);
+/// ```
+#[async_trait::async_trait]
+pub trait RequestHook: Send + Sync {
+    /// Called right before a request is sent
+    ///
+    /// The request already carries its Authorization header and may be changed.
+    async fn before_send(&self, _request: &mut Request) {}
+
+    /// Called with the response of a request and the time it took
+    async fn after_receive(&self, _response: &Response, _elapsed: Duration) {}
+
+    /// Called when a request failed without a response, e.g. on connection errors
+    async fn on_error(&self, _error: &reqwest::Error, _elapsed: Duration) {}
+}
+
+/// Header identifying the client session, see `Baserow::with_client_session_id`
+pub const CLIENT_SESSION_ID: HeaderName = HeaderName::from_static("clientsessionid");
+/// Header grouping actions for undo and redo, see `Baserow::with_action_group`
+pub const CLIENT_UNDO_REDO_ACTION_GROUP_ID: HeaderName =
+    HeaderName::from_static("clientundoredoactiongroupid");
+
+/// Sets a fixed header on every request
+pub(crate) struct HeaderHook {
+    name: HeaderName,
+    value: HeaderValue,
+}
+
+impl HeaderHook {
+    /// Creates the hook, or returns `None` if the value is not a valid header value
+    pub(crate) fn new(name: HeaderName, value: &str) -> Option<Self> {
+        let value = HeaderValue::from_str(value).ok()?;
+        Some(Self { name, value })
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestHook for HeaderHook {
+    async fn before_send(&self, request: &mut Request) {
+        request
+            .headers_mut()
+            .insert(self.name.clone(), self.value.clone());
+    }
+}
@@ -0,0 +1,311 @@
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, instrument};
+
+use crate::{api::client::BaserowClient, error::BaserowError};
+
+/// A license registered on a self-hosted instance
+///
+/// Timestamps are ISO 8601 strings.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct License {
+    pub id: u64,
+    /// The ID of the license key, as issued by Baserow
+    pub license_id: String,
+    /// Whether the license is currently valid
+    pub is_active: bool,
+    /// When the license was last checked with Baserow's servers
+    #[serde(default)]
+    pub last_check: Option<String>,
+    pub valid_from: String,
+    pub valid_through: String,
+    /// The number of seats the license grants
+    pub seats: u64,
+    /// The number of seats assigned to users
+    #[serde(default)]
+    pub seats_taken: u64,
+    /// The number of users who can use the instance without a seat, if limited
+    #[serde(default)]
+    pub free_users_count: Option<u64>,
+    /// The product the license is for, e.g. `premium` or `enterprise`
+    pub product_code: String,
+    pub issued_on: String,
+    pub issued_to_email: String,
+    pub issued_to_name: String,
+    /// The users with a seat, only returned by `get_license`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub users: Vec<LicenseUser>,
+}
+
+/// A user holding a seat of a license
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct LicenseUser {
+    pub id: u64,
+    pub first_name: String,
+    pub email: String,
+}
+
+#[derive(Serialize)]
+struct RegisterLicenseRequest<'a> {
+    license: &'a str,
+}
+
+/// Trait defining the license operations available on a Baserow client
+///
+/// Licenses are managed on self-hosted instances by staff users, so the
+/// client has to be authenticated via `token_auth()` as such.
+///
+/// # Example
+/// ```no_run
+/// use baserow_rs::{
+///     api::{client::BaserowClient, license::BaserowLicenseOperations},
+///     Baserow, ConfigBuilder,
+/// };
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let config = ConfigBuilder::new()
+///         .base_url("https://baserow.example.com")
+///         .email("admin@example.com")
+///         .password("password")
+///         .try_build()
+///         .expect("invalid configuration");
+///     let client = Baserow::with_configuration(config).token_auth().await.unwrap();
+///
+///     let license = client.register_license("license-key").await.unwrap();
+///     client.assign_license_seat(license.id, 42).await.unwrap();
+/// }
+/// ```
+#[async_trait]
+pub trait BaserowLicenseOperations: BaserowClient {
+    /// Lists all licenses registered on the instance
+    #[instrument(skip(self), err)]
+    async fn list_licenses(&self) -> Result<Vec<License>, BaserowError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url("api/licenses/");
+
+        let req = self.auth_header().await.apply_jwt(client.get(url))?;
+
+        debug!("Sending request to list licenses");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let licenses: Vec<License> = resp.json().await?;
+                info!(
+                    license_count = licenses.len(),
+                    "Successfully retrieved licenses"
+                );
+                Ok(licenses)
+            }
+            _ => Err(BaserowError::from_response(resp).await),
+        }
+    }
+
+    /// Registers a license key on the instance
+    ///
+    /// # Arguments
+    /// * `license_key` - The license key as issued by Baserow
+    #[instrument(skip(self, license_key), err)]
+    async fn register_license(&self, license_key: &str) -> Result<License, BaserowError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url("api/licenses/");
+
+        let request = RegisterLicenseRequest {
+            license: license_key,
+        };
+        let req = self
+            .auth_header()
+            .await
+            .apply_jwt(client.post(url))?
+            .json(&request);
+
+        debug!("Sending request to register license");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let license: License = resp.json().await?;
+                info!(license_id = license.id, "Successfully registered license");
+                Ok(license)
+            }
+            _ => Err(BaserowError::from_response(resp).await),
+        }
+    }
+
+    /// Retrieves a license including the users holding its seats
+    ///
+    /// # Arguments
+    /// * `license_id` - The ID of the license on the instance
+    #[instrument(skip(self), err)]
+    async fn get_license(&self, license_id: u64) -> Result<License, BaserowError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url(&format!("api/licenses/{}/", license_id));
+
+        let req = self.auth_header().await.apply_jwt(client.get(url))?;
+
+        debug!("Sending request to fetch license");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK => Ok(resp.json().await?),
+            _ => Err(BaserowError::from_response(resp).await),
+        }
+    }
+
+    /// Removes a license from the instance
+    ///
+    /// # Arguments
+    /// * `license_id` - The ID of the license on the instance
+    #[instrument(skip(self), err)]
+    async fn delete_license(&self, license_id: u64) -> Result<(), BaserowError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url(&format!("api/licenses/{}/", license_id));
+
+        let req = self.auth_header().await.apply_jwt(client.delete(url))?;
+
+        debug!("Sending request to delete license");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK | StatusCode::NO_CONTENT => {
+                info!("Successfully deleted license");
+                Ok(())
+            }
+            _ => Err(BaserowError::from_response(resp).await),
+        }
+    }
+
+    /// Assigns a seat of a license to a user
+    ///
+    /// # Arguments
+    /// * `license_id` - The ID of the license on the instance
+    /// * `user_id` - The user to assign the seat to
+    #[instrument(skip(self), err)]
+    async fn assign_license_seat(
+        &self,
+        license_id: u64,
+        user_id: u64,
+    ) -> Result<LicenseUser, BaserowError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url(&format!("api/licenses/{}/{}/", license_id, user_id));
+
+        let req = self.auth_header().await.apply_jwt(client.post(url))?;
+
+        debug!("Sending request to assign license seat");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let user: LicenseUser = resp.json().await?;
+                info!("Successfully assigned license seat");
+                Ok(user)
+            }
+            _ => Err(BaserowError::from_response(resp).await),
+        }
+    }
+
+    /// Frees the seat of a license held by a user
+    ///
+    /// # Arguments
+    /// * `license_id` - The ID of the license on the instance
+    /// * `user_id` - The user holding the seat
+    #[instrument(skip(self), err)]
+    async fn remove_license_seat(&self, license_id: u64, user_id: u64) -> Result<(), BaserowError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url(&format!("api/licenses/{}/{}/", license_id, user_id));
+
+        let req = self.auth_header().await.apply_jwt(client.delete(url))?;
+
+        debug!("Sending request to remove license seat");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK | StatusCode::NO_CONTENT => {
+                info!("Successfully removed license seat");
+                Ok(())
+            }
+            _ => Err(BaserowError::from_response(resp).await),
+        }
+    }
+}
+
+impl<T: BaserowClient + ?Sized> BaserowLicenseOperations for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Baserow, ConfigBuilder};
+
+    #[tokio::test]
+    async fn test_register_license_and_assign_seat() {
+        let mut server = mockito::Server::new_async().await;
+
+        let register_mock = server
+            .mock("POST", "/api/licenses/")
+            .match_header("Authorization", "JWT jwt-token")
+            .match_body(mockito::Matcher::Json(
+                serde_json::json!({"license": "license-key"}),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+  "id": 1,
+  "license_id": "4dc1f8b4-0c7b-4b7f-8c1e-2d9c3c3b1f00",
+  "is_active": true,
+  "last_check": null,
+  "valid_from": "2024-01-01T00:00:00Z",
+  "valid_through": "2025-01-01T00:00:00Z",
+  "free_users_count": 0,
+  "seats_taken": 0,
+  "seats": 10,
+  "product_code": "premium",
+  "issued_on": "2024-01-01T00:00:00Z",
+  "issued_to_email": "admin@example.com",
+  "issued_to_name": "Acme"
+}"#,
+            )
+            .create_async()
+            .await;
+
+        let seat_mock = server
+            .mock("POST", "/api/licenses/1/42/")
+            .match_header("Authorization", "JWT jwt-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": 42, "first_name": "Jane", "email": "jane@example.com"}"#)
+            .create_async()
+            .await;
+
+        let config = ConfigBuilder::new()
+            .base_url(&server.url())
+            .access_token("jwt-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(config);
+
+        let license = baserow.register_license("license-key").await.unwrap();
+        assert_eq!(license.seats, 10);
+        assert!(license.users.is_empty());
+
+        let user = baserow.assign_license_seat(license.id, 42).await.unwrap();
+        assert_eq!(user.email, "jane@example.com");
+
+        register_mock.assert_async().await;
+        seat_mock.assert_async().await;
+    }
+}
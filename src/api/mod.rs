@@ -1,5 +1,18 @@
+#[cfg(feature = "admin")]
+pub mod audit_log;
 pub mod authentication;
+pub mod batch;
 pub mod client;
+pub mod field;
 pub mod file;
-pub mod table;
+#[cfg(feature = "admin")]
+pub mod health;
+pub mod hook;
+#[cfg(feature = "admin")]
+pub mod license;
+pub mod search;
 pub mod table_operations;
+pub mod user;
+pub mod view;
+#[cfg(feature = "webhooks")]
+pub mod webhook;
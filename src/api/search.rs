@@ -0,0 +1,278 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::{debug, info, instrument};
+
+use crate::{
+    api::client::BaserowClient,
+    error::BaserowError,
+    ids::{FieldId, RowId, TableId},
+};
+
+/// The type of search results that are rows
+pub const ROW_RESULT_TYPE: &str = "database_row";
+
+/// The text to search for and the page of results to fetch
+///
+/// # Example
+/// ```
+/// use baserow_rs::api::search::SearchQuery;
+///
+/// let query = SearchQuery::new("invoice 2024").limit(20).offset(40);
+/// ```
+#[derive(Serialize, Debug, Clone)]
+pub struct SearchQuery {
+    query: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offset: Option<u32>,
+}
+
+impl SearchQuery {
+    /// Creates a query for the first results matching the given text
+    pub fn new(query: &str) -> Self {
+        Self {
+            query: query.to_string(),
+            limit: None,
+            offset: None,
+        }
+    }
+
+    /// Set the maximum number of results to return
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Set the number of results to skip
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// Where a search result was found
+///
+/// Which IDs are set depends on the type of the result, rows carry the IDs
+/// of their table and of the field that matched.
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct SearchMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace_id: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub database_id: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub table_id: Option<TableId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub row_id: Option<RowId>,
+    /// The field whose value matched the query
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field_id: Option<FieldId>,
+    /// Further details Baserow returns for the type of the result
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// An object matching a search query
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct SearchResult {
+    /// The kind of object, e.g. `database_row` or `database_table`
+    pub r#type: String,
+    /// The ID of the object as given by Baserow
+    pub id: Value,
+    /// The name of the object, e.g. the primary field value of a row
+    pub title: String,
+    /// Context like the name of the table a row belongs to
+    #[serde(default)]
+    pub subtitle: Option<String>,
+    /// The text that matched the query, if different from the title
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub metadata: SearchMetadata,
+}
+
+/// A row matching a search query
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RowHit {
+    pub table_id: TableId,
+    pub row_id: RowId,
+    /// The field whose value matched the query, if known
+    pub field_id: Option<FieldId>,
+}
+
+impl SearchResult {
+    /// Returns the table, row and field of the result if it is a row
+    pub fn row_hit(&self) -> Option<RowHit> {
+        if self.r#type != ROW_RESULT_TYPE {
+            return None;
+        }
+        Some(RowHit {
+            table_id: self.metadata.table_id?,
+            row_id: self.metadata.row_id?,
+            field_id: self.metadata.field_id,
+        })
+    }
+}
+
+/// A page of search results
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SearchResponse {
+    /// The results of the current page, best matches first
+    pub results: Vec<SearchResult>,
+    /// Whether there are more results beyond the current page
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+impl SearchResponse {
+    /// Returns the rows among the results
+    pub fn row_hits(&self) -> impl Iterator<Item = RowHit> + '_ {
+        self.results.iter().filter_map(SearchResult::row_hit)
+    }
+}
+
+/// Trait defining the search operations available on a Baserow client
+///
+/// Searches across all databases, tables and rows of a workspace the user
+/// has access to. The client has to be authenticated via `token_auth()`.
+///
+/// # Example
+/// ```no_run
+/// use baserow_rs::{
+///     api::{client::BaserowClient, search::{BaserowSearchOperations, SearchQuery}},
+///     Baserow, ConfigBuilder,
+/// };
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let config = ConfigBuilder::new()
+///         .base_url("https://api.baserow.io")
+///         .email("user@example.com")
+///         .password("password")
+///         .try_build()
+///         .expect("invalid configuration");
+///     let client = Baserow::with_configuration(config).token_auth().await.unwrap();
+///
+///     let response = client.search_workspace(12, SearchQuery::new("ACME")).await.unwrap();
+///     for hit in response.row_hits() {
+///         println!("Row {} of table {} matches", hit.row_id, hit.table_id);
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait BaserowSearchOperations: BaserowClient {
+    /// Searches a workspace for objects matching a query
+    ///
+    /// # Arguments
+    /// * `workspace_id` - The workspace to search
+    /// * `query` - The text to search for and the page to fetch
+    #[instrument(skip(self), err)]
+    async fn search_workspace(
+        &self,
+        workspace_id: u64,
+        query: SearchQuery,
+    ) -> Result<SearchResponse, BaserowError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url(&format!("api/search/workspace/{}/", workspace_id));
+
+        let req = self
+            .auth_header()
+            .await
+            .apply_jwt(client.get(url))?
+            .query(&query);
+
+        debug!("Sending request to search the workspace");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let response: SearchResponse = resp.json().await?;
+                info!(
+                    result_count = response.results.len(),
+                    has_more = response.has_more,
+                    "Successfully searched workspace"
+                );
+                Ok(response)
+            }
+            _ => Err(BaserowError::from_response(resp).await),
+        }
+    }
+}
+
+impl<T: BaserowClient + ?Sized> BaserowSearchOperations for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Baserow, ConfigBuilder};
+    use mockito::Matcher;
+
+    #[tokio::test]
+    async fn test_search_workspace() {
+        let mut server = mockito::Server::new_async().await;
+
+        let search_mock = server
+            .mock("GET", "/api/search/workspace/12/")
+            .match_header("Authorization", "JWT jwt-token")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("query".into(), "ACME".into()),
+                Matcher::UrlEncoded("limit".into(), "2".into()),
+            ]))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+  "results": [
+    {
+      "type": "database_row",
+      "id": "5_3",
+      "title": "ACME Corp",
+      "subtitle": "Customers",
+      "metadata": {"workspace_id": 12, "database_id": 2, "table_id": 5, "row_id": 3, "field_id": 40}
+    },
+    {
+      "type": "database_table",
+      "id": 6,
+      "title": "ACME Orders",
+      "metadata": {"workspace_id": 12, "database_id": 2, "table_id": 6}
+    }
+  ],
+  "has_more": true
+}"#,
+            )
+            .create_async()
+            .await;
+
+        let config = ConfigBuilder::new()
+            .base_url(&server.url())
+            .access_token("jwt-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(config);
+
+        let response = baserow
+            .search_workspace(12, SearchQuery::new("ACME").limit(2))
+            .await
+            .unwrap();
+        assert!(response.has_more);
+        assert_eq!(response.results.len(), 2);
+        assert_eq!(
+            response.row_hits().collect::<Vec<_>>(),
+            vec![RowHit {
+                table_id: TableId(5),
+                row_id: RowId(3),
+                field_id: Some(FieldId(40)),
+            }]
+        );
+        assert_eq!(response.results[1].metadata.table_id, Some(TableId(6)));
+
+        search_mock.assert_async().await;
+    }
+}
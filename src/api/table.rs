@@ -1 +0,0 @@
-pub use crate::api::table_operations::{RowRequestBuilder, RowsResponse, TypedRowsResponse};
@@ -1,14 +1,26 @@
 use crate::{
-    api::client::{BaserowClient, RequestTracing},
-    filter::{Filter, FilterTriple},
+    api::{
+        batch::{self, BatchOperation, BatchResult},
+        client::{BaserowClient, RequestTracing},
+        view::{ViewSource, ViewType},
+    },
+    error::BaserowError,
+    filter::{Filter, FilterTree, FilterTriple, FilterType},
+    ids::{FieldId, RowId, ViewId},
     mapper::{FieldMapper, TableMapper},
-    Baserow, BaserowTable, OrderDirection,
+    Baserow, BaserowTable, FieldNamesMode, OrderDirection,
 };
 use async_trait::async_trait;
-use reqwest::{header::AUTHORIZATION, Client, StatusCode};
-use serde::{de::DeserializeOwned, Deserialize, Serialize};
-use serde_json::Value;
-use std::{collections::HashMap, error::Error, vec};
+use bytes::Bytes;
+use futures_util::{stream, Stream, StreamExt, TryStreamExt};
+use reqwest::StatusCode;
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
+use serde_json::{json, Value};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+    vec,
+};
 use tracing::{debug, info, instrument};
 
 /// Response structure for table row queries
@@ -42,48 +54,301 @@ pub struct TypedRowsResponse<T> {
     pub results: Vec<T>,
 }
 
+/// Metadata Baserow keeps for every row
+///
+/// Only returned when requested via `RowRequestBuilder::include_metadata()`.
+/// Timestamps are ISO 8601 strings.
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct RowMetadata {
+    /// When the row was created
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_on: Option<String>,
+    /// When the row was last updated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_on: Option<String>,
+}
+
+impl RowMetadata {
+    /// Reads the metadata of a row returned as a HashMap
+    pub fn from_row(row: &HashMap<String, Value>) -> Self {
+        let timestamp = |key: &str| row.get(key).and_then(Value::as_str).map(String::from);
+        Self {
+            created_on: timestamp("created_on"),
+            updated_on: timestamp("updated_on"),
+        }
+    }
+}
+
+/// A row deserialized into `T` together with its metadata
+///
+/// # Example
+/// ```no_run
+/// use baserow_rs::{
+///     api::{client::BaserowClient, table_operations::Row},
+///     Baserow, BaserowTableOperations, ConfigBuilder, TableId,
+/// };
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Product {
+///     name: String,
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let config = ConfigBuilder::new()
+///         .base_url("https://api.baserow.io")
+///         .api_key("your-api-key")
+///         .try_build()
+///         .expect("invalid configuration");
+///     let baserow = Baserow::with_configuration(config);
+///
+///     let rows = baserow
+///         .table_by_id(TableId(1234))
+///         .auto_map()
+///         .await
+///         .unwrap()
+///         .query()
+///         .include_metadata()
+///         .get::<Row<Product>>()
+///         .await
+///         .unwrap();
+///
+///     for row in rows.results {
+///         println!("{} was updated on {:?}", row.fields.name, row.metadata.updated_on);
+///     }
+/// }
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Row<T> {
+    /// The created_on and updated_on timestamps of the row
+    #[serde(flatten)]
+    pub metadata: RowMetadata,
+    /// The field values of the row
+    #[serde(flatten)]
+    pub fields: T,
+}
+
 /// Represents a query request for table rows
 ///
 /// This struct encapsulates all the parameters that can be used to query rows
 /// from a Baserow table, including filtering, sorting, and pagination options.
-#[derive(Clone, Debug)]
+///
+/// Requests can be serialized, e.g. to persist the queries of scheduled jobs
+/// or to log them. Fields missing when deserializing take their defaults.
+///
+/// # Example
+/// ```
+/// use baserow_rs::{api::table_operations::RowRequest, filter::{Filter, FilterTriple}};
+///
+/// let request = RowRequest {
+///     filter: Some(vec![FilterTriple {
+///         field: "Status".to_string(),
+///         filter: Filter::Equal,
+///         value: "Open".to_string(),
+///     }]),
+///     ..Default::default()
+/// };
+///
+/// let json = serde_json::to_string(&request).unwrap();
+/// let restored: RowRequest = serde_json::from_str(&json).unwrap();
+/// assert_eq!(restored, request);
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct RowRequest {
-    /// Optional view ID to query rows from a specific view
-    pub view_id: Option<i32>,
-    /// Optional sorting criteria
-    pub order: Option<HashMap<String, OrderDirection>>,
+    /// Optional view to query rows from
+    ///
+    /// Requests persisted with a `view_id` read from that view via the table.
+    #[serde(alias = "view_id", deserialize_with = "deserialize_view")]
+    pub view: Option<ViewSource>,
+    /// Optional sorting criteria, by priority
+    ///
+    /// Requests persisted with the criteria as map of fields to directions
+    /// can still be read.
+    #[serde(deserialize_with = "deserialize_order")]
+    pub order: Option<Vec<(String, OrderDirection)>>,
     /// Optional filter conditions
     pub filter: Option<Vec<FilterTriple>>,
+    /// How the conditions of `filter` are combined, all must match if not set
+    pub filter_type: Option<FilterType>,
+    /// Optional conditions combined with AND and OR, in addition to `filter`
+    pub filter_tree: Option<FilterTree>,
+    /// The fields to return, all fields if not set
+    pub fields: Option<Vec<String>>,
     /// Optional page size for pagination
     pub page_size: Option<i32>,
     /// Optional page number for pagination
     pub page: Option<i32>,
-    /// Optional flag to use user-friendly field names in the response
-    pub user_field_names: Option<bool>,
+    /// How fields are keyed, the mode of the queried table if not set
+    pub field_names: Option<FieldNamesMode>,
+    /// Whether to include the created_on and updated_on metadata of the rows
+    pub include_metadata: bool,
+    /// The IDs of the rows to return, read from a grid view in the request body
+    pub row_ids: Option<Vec<RowId>>,
+}
+
+/// Sorting criteria as persisted, by priority or, formerly, as map without a priority
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PersistedOrder {
+    List(Vec<(String, OrderDirection)>),
+    Map(BTreeMap<String, OrderDirection>),
+}
+
+/// A view as persisted, or formerly as ID of a view read via the table
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PersistedView {
+    Source(ViewSource),
+    Id(ViewId),
+}
+
+fn deserialize_view<'de, D>(deserializer: D) -> Result<Option<ViewSource>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(
+        Option::<PersistedView>::deserialize(deserializer)?.map(|view| match view {
+            PersistedView::Source(source) => source,
+            PersistedView::Id(id) => ViewSource::Table(id),
+        }),
+    )
+}
+
+fn deserialize_order<'de, D>(
+    deserializer: D,
+) -> Result<Option<Vec<(String, OrderDirection)>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(
+        Option::<PersistedOrder>::deserialize(deserializer)?.map(|order| match order {
+            PersistedOrder::List(order) => order,
+            PersistedOrder::Map(order) => order.into_iter().collect(),
+        }),
+    )
 }
 
 impl Default for RowRequest {
     fn default() -> Self {
         Self {
-            view_id: None,
+            view: None,
             order: None,
             filter: None,
+            filter_type: None,
+            filter_tree: None,
+            fields: None,
             page_size: Some(100),
             page: Some(1),
-            user_field_names: None,
+            field_names: None,
+            include_metadata: false,
+            row_ids: None,
         }
     }
 }
 
+impl RowRequest {
+    /// Moves the conditions of a filter tree without nested groups to `filter`
+    ///
+    /// Separate filter parameters make for a much shorter query string than
+    /// the JSON of the `filters` parameter. Returns `None` if the request has
+    /// no tree, or if the conditions cannot be combined by one filter type.
+    fn flattened(&self) -> Option<RowRequest> {
+        let (tree_type, mut conditions) = flat_conditions(self.filter_tree.as_ref()?)?;
+        let current = self.filter.clone().unwrap_or_default();
+        let current_type = self.filter_type.unwrap_or_default();
+        // Both sets of conditions must match, which they only can if each of
+        // them is combined with AND, or has no more than one condition
+        let combines_with_and =
+            |filter_type, count: usize| filter_type == FilterType::And || count <= 1;
+        let filter_type = if current.is_empty() {
+            tree_type
+        } else if conditions.is_empty() {
+            current_type
+        } else if combines_with_and(current_type, current.len())
+            && combines_with_and(tree_type, conditions.len())
+        {
+            FilterType::And
+        } else {
+            return None;
+        };
+        conditions.splice(0..0, current);
+
+        Some(RowRequest {
+            filter: Some(conditions),
+            filter_type: Some(filter_type),
+            filter_tree: None,
+            ..self.clone()
+        })
+    }
+}
+
+/// Returns the conditions of a tree without nested groups and how they are combined
+fn flat_conditions(tree: &FilterTree) -> Option<(FilterType, Vec<FilterTriple>)> {
+    match tree {
+        FilterTree::Condition(triple) => Some((FilterType::And, vec![triple.clone()])),
+        // Groups of a single group are a common result of combining trees
+        FilterTree::Group { filters, .. } if filters.len() == 1 => flat_conditions(&filters[0]),
+        FilterTree::Group {
+            filter_type,
+            filters,
+        } => filters
+            .iter()
+            .map(|filter| match filter {
+                FilterTree::Condition(triple) => Some(triple.clone()),
+                FilterTree::Group { .. } => None,
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(|conditions| (*filter_type, conditions)),
+    }
+}
+
 /// Builder for constructing table row queries
 ///
 /// Provides a fluent interface for building queries with filtering, sorting,
-/// and other options.
-#[derive(Default)]
+/// and other options. A builder can be executed any number of times and
+/// cloned to derive queries from a common base, e.g. to fetch several pages
+/// or to run the same query against other tables.
+///
+/// # Example
+/// ```no_run
+/// use baserow_rs::{api::table_operations::RowRequestBuilder, prelude::*};
+/// use serde_json::Value;
+/// use std::collections::HashMap;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let config = ConfigBuilder::new()
+///         .base_url("https://api.baserow.io")
+///         .api_key("your-api-key")
+///         .try_build()
+///         .expect("invalid configuration");
+///     let baserow = Baserow::with_configuration(config);
+///
+///     let open = RowRequestBuilder::new()
+///         .filter_by("Status", Filter::Equal, "Open")
+///         .order_by("Name", OrderDirection::Asc)
+///         .user_field_names(true);
+///
+///     for page in 1..=3 {
+///         let rows = open
+///             .clone()
+///             .page(page)
+///             .get_from::<HashMap<String, Value>>(&baserow.table_by_id(TableId(1234)))
+///             .await
+///             .unwrap();
+///         println!("{:?}", rows.results);
+///     }
+///
+///     let archived = open.get_from::<HashMap<String, Value>>(&baserow.table_by_id(TableId(5678))).await;
+/// }
+/// ```
+#[derive(Clone, Default)]
 pub struct RowRequestBuilder {
     baserow: Option<Baserow>,
     table: Option<BaserowTable>,
-    request: RowRequest,
+    pub(crate) request: RowRequest,
 }
 
 impl RowRequestBuilder {
@@ -95,9 +360,14 @@ impl RowRequestBuilder {
         }
     }
 
-    /// Set the view ID to query rows from a specific view
-    pub fn view(mut self, id: i32) -> Self {
-        self.request.view_id = Some(id);
+    /// Query the rows of a view, with its filters and sorts applied
+    ///
+    /// A `ViewId` reads through the rows endpoint of the table, which works
+    /// with database tokens. Pass a [`ViewSource`] to read from the endpoints
+    /// of grid and gallery views, including publicly shared ones. Filters and
+    /// sorts added to the query apply on top of those of the view.
+    pub fn view(mut self, view: impl Into<ViewSource>) -> Self {
+        self.request.view = Some(view.into());
         self
     }
 
@@ -119,15 +389,29 @@ impl RowRequestBuilder {
         self
     }
 
+    /// Set how the fields of the returned rows are keyed
+    ///
+    /// Overrides the mode of the queried table, see [`FieldNamesMode`].
+    pub fn field_names(mut self, mode: FieldNamesMode) -> Self {
+        self.request.field_names = Some(mode);
+        self
+    }
+
     /// Set whether to use user-friendly field names in the response
     ///
-    /// Note: This option is mutually exclusive with auto_map(). If auto_map() has been called,
-    /// this setting will be ignored as field name mapping is handled by the TableMapper.
-    pub fn user_field_names(mut self, enabled: bool) -> Self {
-        // Only set user_field_names if we don't have a mapper
-        if self.table.as_ref().map_or(true, |t| t.mapper.is_none()) {
-            self.request.user_field_names = Some(enabled);
-        }
+    /// Shorthand for `field_names(FieldNamesMode::UserNames)` and
+    /// `field_names(FieldNamesMode::Ids)` respectively.
+    pub fn user_field_names(self, enabled: bool) -> Self {
+        self.field_names(FieldNamesMode::from_user_field_names(enabled))
+    }
+
+    /// Include the created_on and updated_on timestamps of the rows in the response
+    ///
+    /// The timestamps are returned next to the field values. Read them with
+    /// [`Row`] or [`RowMetadata::from_row`], e.g. to only process rows that
+    /// changed since the last run.
+    pub fn include_metadata(mut self) -> Self {
+        self.request.include_metadata = true;
         self
     }
 
@@ -142,22 +426,24 @@ impl RowRequestBuilder {
     }
 
     /// Add sorting criteria to the query
+    ///
+    /// Rows are sorted by the fields in the order they were added. Ordering
+    /// by a field again changes its direction but keeps its priority.
     pub fn order_by(mut self, field: &str, direction: OrderDirection) -> Self {
-        match self.request.order {
-            Some(mut order) => {
-                order.insert(String::from(field), direction);
-                self.request.order = Some(order);
-            }
-            None => {
-                let mut order = HashMap::new();
-                order.insert(String::from(field), direction);
-                self.request.order = Some(order);
-            }
+        let order = self.request.order.get_or_insert_with(Vec::new);
+        match order.iter_mut().find(|(name, _)| name == field) {
+            Some((_, current)) => *current = direction,
+            None => order.push((String::from(field), direction)),
         }
         self
     }
 
     /// Add a filter condition to the query
+    ///
+    /// Filters are sent in the query string, as Baserow lists the rows of a
+    /// table via GET only. Proxies may reject requests with many or long
+    /// filters; save such filters in a view instead and query it via `view`.
+    /// Long lists of row IDs can be sent in a body instead, see `row_ids`.
     pub fn filter_by(mut self, field: &str, filter_op: Filter, value: &str) -> Self {
         match self.request.filter {
             Some(mut filter) => {
@@ -169,65 +455,336 @@ impl RowRequestBuilder {
                 self.request.filter = Some(filter);
             }
             None => {
-                let mut filter: Vec<FilterTriple> = vec![];
-                filter.push(FilterTriple {
+                let filter: Vec<FilterTriple> = vec![FilterTriple {
                     field: String::from(field),
                     filter: filter_op,
                     value: String::from(value),
-                });
+                }];
                 self.request.filter = Some(filter);
             }
         }
         self
     }
 
-    /// Execute the query and return typed results
-    pub async fn get<T>(self) -> Result<TypedRowsResponse<T>, Box<dyn Error>>
+    /// Set how the conditions added via `filter_by` are combined
+    ///
+    /// By default, rows must match all conditions. With `FilterType::Or`,
+    /// rows matching any of them are returned. Conditions added via `filter`
+    /// must match in any case.
+    ///
+    /// # Example
+    /// ```
+    /// use baserow_rs::{api::table_operations::RowRequestBuilder, filter::{Filter, FilterType}};
+    ///
+    /// let query = RowRequestBuilder::new()
+    ///     .filter_by("Status", Filter::Equal, "Open")
+    ///     .filter_by("Status", Filter::Equal, "Blocked")
+    ///     .filter_type(FilterType::Or);
+    /// ```
+    pub fn filter_type(mut self, filter_type: FilterType) -> Self {
+        self.request.filter_type = Some(filter_type);
+        self
+    }
+
+    /// Add conditions combined with AND and OR to the query
+    ///
+    /// Conditions added repeatedly, or via `filter_by`, must all match.
+    /// Trees are sent as JSON in the `filters` parameter, or, if the query
+    /// string would exceed `max_url_length` and the tree nests no groups, as
+    /// the much shorter separate filter parameters.
+    ///
+    /// # Example
+    /// ```
+    /// use baserow_rs::{api::table_operations::RowRequestBuilder, filter::field};
+    ///
+    /// let query = RowRequestBuilder::new()
+    ///     .filter(field("Age").gt(18).and(field("Status").eq("Active")))
+    ///     .filter(field("Team").eq("Sales").or(field("Team").eq("Support")));
+    /// ```
+    pub fn filter(mut self, tree: FilterTree) -> Self {
+        self.request.filter_tree = Some(match self.request.filter_tree.take() {
+            Some(current) => current.and(tree),
+            None => tree,
+        });
+        self
+    }
+
+    /// Only return the given fields of the rows, besides their ID and order
+    ///
+    /// Saves bandwidth on tables with many or large fields. Not supported by
+    /// the endpoints of views, which return all visible fields.
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.request.fields = Some(fields.iter().map(|field| field.to_string()).collect());
+        self
+    }
+
+    /// Only return the rows with the given IDs, read from a grid view
+    ///
+    /// Baserow only accepts lists of row IDs in the body of a request to the
+    /// endpoint of a grid view, so long lists don't run into URL length
+    /// limits. The query must read from a grid view via
+    /// [`ViewSource::grid`], which requires a JWT. The filters and sorts of
+    /// the view don't apply, and the rows are returned as a single page.
+    /// Selected fields are requested by ID.
+    ///
+    /// Fails with `InvalidRequest` without a grid view, or if the query is
+    /// filtered, sorted, includes metadata or asks for a page other than the
+    /// first.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use baserow_rs::{api::view::ViewSource, prelude::*};
+    /// use serde_json::Value;
+    /// use std::collections::HashMap;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let config = ConfigBuilder::new()
+    ///         .base_url("https://api.baserow.io")
+    ///         .access_token("your-access-token")
+    ///         .try_build()
+    ///         .expect("invalid configuration");
+    ///     let baserow = Baserow::with_configuration(config);
+    ///
+    ///     let ids: Vec<RowId> = (1..=5000).map(RowId).collect();
+    ///     let rows = baserow
+    ///         .table_by_id(TableId(1234))
+    ///         .auto_map()
+    ///         .await
+    ///         .unwrap()
+    ///         .query()
+    ///         .view(ViewSource::grid(ViewId(7)))
+    ///         .row_ids(&ids)
+    ///         .get::<HashMap<String, Value>>()
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{} rows", rows.results.len());
+    /// }
+    /// ```
+    pub fn row_ids(mut self, ids: &[RowId]) -> Self {
+        self.request.row_ids = Some(ids.to_vec());
+        self
+    }
+
+    /// Streams all rows matching the query, fetching a page at a time
+    ///
+    /// Pages are requested as the stream is polled, so large tables can be
+    /// processed without holding them in memory. The page of the query is
+    /// ignored, its size is used for the requests.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use baserow_rs::prelude::*;
+    /// use futures_util::TryStreamExt;
+    ///
+    /// # async fn example(baserow: Baserow) -> Result<(), BaserowError> {
+    /// let query = baserow.table_by_id(TableId(1234)).query().size(200);
+    /// let mut rows = std::pin::pin!(query.stream());
+    /// while let Some(row) = rows.try_next().await? {
+    ///     println!("{:?}", row.get("id"));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream(
+        &self,
+    ) -> impl Stream<Item = Result<HashMap<String, Value>, BaserowError>> + Send + '_ {
+        match self.target() {
+            Ok((table, baserow)) => table
+                .pages(baserow, self.request.clone())
+                .map_ok(|rows| stream::iter(rows.into_iter().map(Ok)))
+                .try_flatten()
+                .left_stream(),
+            Err(e) => stream::once(async { Err(e) }).right_stream(),
+        }
+    }
+
+    /// Execute the query against the table it was created for and return typed results
+    pub async fn get<T>(&self) -> Result<TypedRowsResponse<T>, BaserowError>
+    where
+        T: DeserializeOwned + 'static,
+    {
+        let (table, baserow) = self.target()?;
+        table.get(baserow.clone(), self.request.clone()).await
+    }
+
+    /// Returns the query parameters the query is sent with, in the order they are sent
+    ///
+    /// Field names are mapped to IDs and the field names mode is resolved as
+    /// when executing the query against the table it was created for. Without
+    /// a table, field names are sent as given.
+    ///
+    /// # Example
+    /// ```
+    /// use baserow_rs::{api::table_operations::RowRequestBuilder, filter::Filter, OrderDirection};
+    ///
+    /// let params = RowRequestBuilder::new()
+    ///     .filter_by("Status", Filter::Equal, "Open")
+    ///     .order_by("Name", OrderDirection::Desc)
+    ///     .user_field_names(true)
+    ///     .to_query_params()
+    ///     .unwrap();
+    /// assert!(params.contains(&("order_by".to_string(), "-Name".to_string())));
+    /// assert!(params.contains(&("filter__Status__equal".to_string(), "Open".to_string())));
+    /// ```
+    pub fn to_query_params(&self) -> Result<Vec<(String, String)>, BaserowError> {
+        match &self.table {
+            Some(table) => table.query_params(&self.request),
+            None => BaserowTable::default().query_params(&self.request),
+        }
+    }
+
+    /// Returns the table the query was created for and the client to query it with
+    pub(crate) fn target(&self) -> Result<(&BaserowTable, &Baserow), BaserowError> {
+        let table = self
+            .table
+            .as_ref()
+            .ok_or(BaserowError::MissingState("Table"))?;
+        let baserow = self
+            .baserow
+            .as_ref()
+            .or(table.baserow.as_ref())
+            .ok_or(BaserowError::MissingState("Baserow instance"))?;
+        Ok((table, baserow))
+    }
+
+    /// Execute the query against the given table and return typed results
+    ///
+    /// The table and client the builder was created with, if any, are not
+    /// used. Unless set on the builder, the field names mode of the given
+    /// table applies.
+    pub async fn get_from<T>(
+        &self,
+        table: &BaserowTable,
+    ) -> Result<TypedRowsResponse<T>, BaserowError>
     where
         T: DeserializeOwned + 'static,
     {
-        let table = self.table.expect("Table instance is missing");
-        let baserow = self.baserow.expect("Baserow instance is missing");
-        table.get(baserow, self.request).await
+        let baserow = table
+            .baserow
+            .clone()
+            .ok_or(BaserowError::MissingState("Baserow instance"))?;
+        table.get(baserow, self.request.clone()).await
     }
 }
 
+/// Returns the ID of a row
+#[cfg(any(feature = "changes", feature = "sync"))]
+pub(crate) fn row_id(row: &HashMap<String, Value>) -> Option<RowId> {
+    row.get("id").and_then(Value::as_u64).map(RowId)
+}
+
+/// Returns the value of a row under the first of the given keys it has
+pub(crate) fn row_value<'a>(row: &'a HashMap<String, Value>, keys: &[String]) -> Option<&'a Value> {
+    keys.iter().find_map(|key| row.get(key))
+}
+
+/// Resolves a field key like `field_12`, as used in error details, to the field name
+pub(crate) fn field_name(mapper: Option<&TableMapper>, key: &str) -> Option<String> {
+    let id = key.strip_prefix("field_")?.parse().ok()?;
+    mapper?.get_field_name(id)
+}
+
 /// Trait defining the public operations available on a Baserow table
 ///
 /// This trait provides the core CRUD operations for working with Baserow tables.
 /// All operations are async and return Results to handle potential errors.
+///
+/// Rows are exchanged as maps of field names to values, so the trait is
+/// object safe and can be mocked, e.g. with `mockall`. Typed access to rows
+/// is provided by [`TypedTableOperations`], which is implemented for every
+/// implementation of this trait.
+///
+/// # Example
+/// ```
+/// use baserow_rs::{BaserowTableOperations, RowId};
+/// use serde_json::Value;
+/// use std::collections::HashMap;
+///
+/// async fn names(table: &dyn BaserowTableOperations) -> Vec<String> {
+///     let row = table.get_row(RowId(1), Some(true)).await.unwrap();
+///     row.get("Name").and_then(Value::as_str).map(String::from).into_iter().collect()
+/// }
+/// ```
 #[async_trait]
-pub trait BaserowTableOperations {
+pub trait BaserowTableOperations: Send + Sync {
     /// Automatically maps the table fields to their corresponding types
     ///
     /// This method fetches the table schema and sets up field mappings for type conversion.
     /// Call this before performing operations if you need type-safe field access.
-    async fn auto_map(self) -> Result<BaserowTable, Box<dyn Error>>;
+    async fn auto_map(self) -> Result<BaserowTable, BaserowError>
+    where
+        Self: Sized;
 
     /// Creates a new query builder for constructing complex table queries
     ///
     /// This is the preferred method for building queries with filters, sorting,
     /// and pagination options. The builder provides a fluent interface for
     /// constructing queries.
-    fn query(self) -> RowRequestBuilder;
+    fn query(self) -> RowRequestBuilder
+    where
+        Self: Sized;
 
-    /// Execute a row request and return typed results
+    /// Fetches the name, order and database of the table
     ///
-    /// # Type Parameters
-    /// * `T` - The type to deserialize the results into
+    /// Tables created via `table_by_id` only know their ID. The mapping and
+    /// defaults of the table are kept.
+    async fn refresh(self) -> Result<BaserowTable, BaserowError>
+    where
+        Self: Sized;
+
+    /// Execute a row request and return the rows as maps of field names to values
+    ///
+    /// In `Mapped` mode, the field IDs of the rows are converted to names.
     ///
     /// # Arguments
+    /// * `baserow` - The client to send the request with
     /// * `request` - The query parameters encapsulated in a RowRequest
     ///
     /// # Returns
-    /// A TypedRowsResponse containing the query results and pagination information
-    async fn get<T>(
+    /// A RowsResponse containing the query results and pagination information
+    async fn get_rows(
         &self,
         baserow: Baserow,
         request: RowRequest,
-    ) -> Result<TypedRowsResponse<T>, Box<dyn Error>>
-    where
-        T: DeserializeOwned + 'static;
+    ) -> Result<RowsResponse, BaserowError>;
+
+    /// Retrieves a single record from the table by ID as a map of field names to values
+    ///
+    /// # Arguments
+    /// * `id` - The unique identifier of the record to retrieve
+    /// * `user_field_names` - Whether to use user-friendly field names in the response
+    async fn get_row(
+        &self,
+        id: RowId,
+        user_field_names: Option<bool>,
+    ) -> Result<HashMap<String, Value>, BaserowError>;
+
+    /// Execute a row request and return the response body, if its rows need no conversion
+    ///
+    /// Lets [`TypedTableOperations::get`] deserialize the rows straight from
+    /// the body. Returns `None` without sending the request if the rows have
+    /// to be converted, e.g. in `Mapped` mode. Returns `None` by default.
+    async fn get_rows_body(
+        &self,
+        _baserow: Baserow,
+        _request: RowRequest,
+    ) -> Result<Option<Bytes>, BaserowError> {
+        Ok(None)
+    }
+
+    /// Retrieves the body of a single record, if it needs no conversion
+    ///
+    /// Lets [`TypedTableOperations::get_one`] deserialize the record straight
+    /// from the body. Returns `None` without sending a request if the record
+    /// has to be converted, e.g. in `Mapped` mode. Returns `None` by default.
+    async fn get_row_body(
+        &self,
+        _id: RowId,
+        _user_field_names: Option<bool>,
+    ) -> Result<Option<Bytes>, BaserowError> {
+        Ok(None)
+    }
 
     /// Creates a single record in the table
     ///
@@ -238,10 +795,120 @@ pub trait BaserowTableOperations {
     /// # Returns
     /// The created record including any auto-generated fields (like ID)
     async fn create_one(
-        self,
+        &self,
+        data: HashMap<String, Value>,
+        user_field_names: Option<bool>,
+    ) -> Result<HashMap<String, Value>, BaserowError>;
+
+    /// Updates a single record in the table
+    ///
+    /// # Arguments
+    /// * `id` - The unique identifier of the record to update
+    /// * `data` - A map of field names to new values
+    /// * `user_field_names` - Whether to use user-friendly field names in the response
+    ///
+    /// # Returns
+    /// The updated record
+    async fn update(
+        &self,
+        id: RowId,
         data: HashMap<String, Value>,
         user_field_names: Option<bool>,
-    ) -> Result<HashMap<String, Value>, Box<dyn Error>>;
+    ) -> Result<HashMap<String, Value>, BaserowError>;
+
+    /// Deletes a single record from the table
+    ///
+    /// # Arguments
+    /// * `id` - The unique identifier of the record to delete
+    async fn delete(&self, id: RowId) -> Result<(), BaserowError>;
+
+    /// Creates many records in the table, in chunks of up to 200 rows
+    ///
+    /// Rows Baserow rejects are reported per row while the other rows of their
+    /// chunk are still created. With `validate_writes()` enabled, rows are
+    /// validated before sending.
+    ///
+    /// # Arguments
+    /// * `rows` - The records to create, as maps of field names to values
+    /// * `user_field_names` - Whether to use user-friendly field names in the response
+    ///
+    /// # Returns
+    /// The created records and the rejected rows, by chunk
+    async fn create_many(
+        &self,
+        rows: Vec<HashMap<String, Value>>,
+        user_field_names: Option<bool>,
+    ) -> Result<BatchResult<HashMap<String, Value>>, BaserowError>;
+
+    /// Updates many records in the table, in chunks of up to 200 rows
+    ///
+    /// # Arguments
+    /// * `rows` - The IDs of the records to update with their new values
+    /// * `user_field_names` - Whether to use user-friendly field names in the response
+    ///
+    /// # Returns
+    /// The updated records and the rejected rows, by chunk
+    async fn update_many(
+        &self,
+        rows: Vec<(RowId, HashMap<String, Value>)>,
+        user_field_names: Option<bool>,
+    ) -> Result<BatchResult<HashMap<String, Value>>, BaserowError>;
+
+    /// Deletes many records from the table, in chunks of up to 200 rows
+    ///
+    /// Records that do not exist are reported as `RowNotFound` per row.
+    ///
+    /// # Returns
+    /// The IDs of the deleted records and the rejected rows, by chunk
+    async fn delete_many(&self, ids: Vec<RowId>) -> Result<BatchResult<RowId>, BaserowError>;
+}
+
+/// Typed access to the rows of a table
+///
+/// Deserializes the rows returned by [`BaserowTableOperations`] into any
+/// type implementing `Deserialize`. Implemented for every implementation of
+/// [`BaserowTableOperations`], including mocks. Rows that need no conversion
+/// are deserialized straight from the response body, without building maps
+/// first.
+#[async_trait]
+pub trait TypedTableOperations: BaserowTableOperations {
+    /// Execute a row request and return typed results
+    ///
+    /// # Type Parameters
+    /// * `T` - The type to deserialize the results into
+    ///
+    /// # Arguments
+    /// * `baserow` - The client to send the request with
+    /// * `request` - The query parameters encapsulated in a RowRequest
+    ///
+    /// # Returns
+    /// A TypedRowsResponse containing the query results and pagination information
+    async fn get<T>(
+        &self,
+        baserow: Baserow,
+        request: RowRequest,
+    ) -> Result<TypedRowsResponse<T>, BaserowError>
+    where
+        T: DeserializeOwned + 'static,
+    {
+        if let Some(body) = self.get_rows_body(baserow.clone(), request.clone()).await? {
+            return Ok(serde_json::from_slice(&body)?);
+        }
+
+        let response = self.get_rows(baserow, request).await?;
+        let results = response
+            .results
+            .into_iter()
+            .map(deserialize_row)
+            .collect::<Result<Vec<T>, _>>()?;
+
+        Ok(TypedRowsResponse {
+            count: response.count,
+            next: response.next,
+            previous: response.previous,
+            results,
+        })
+    }
 
     /// Retrieves a single record from the table by ID
     ///
@@ -254,40 +921,93 @@ pub trait BaserowTableOperations {
     ///
     /// # Returns
     /// The requested record if found
-    async fn get_one<T>(self, id: u64, user_field_names: Option<bool>) -> Result<T, Box<dyn Error>>
+    async fn get_one<T>(&self, id: RowId, user_field_names: Option<bool>) -> Result<T, BaserowError>
     where
-        T: DeserializeOwned + 'static;
+        T: DeserializeOwned + 'static,
+    {
+        if let Some(body) = self.get_row_body(id, user_field_names).await? {
+            return Ok(serde_json::from_slice(&body)?);
+        }
 
-    /// Updates a single record in the table
+        let row = self.get_row(id, user_field_names).await?;
+        Ok(deserialize_row(row)?)
+    }
+
+    /// Retrieves many records from the table by ID
+    ///
+    /// Fetches the records like `get_one`, with up to `concurrency` requests
+    /// in flight at a time. A record that cannot be retrieved does not affect
+    /// the others.
     ///
     /// # Arguments
-    /// * `id` - The unique identifier of the record to update
-    /// * `data` - A map of field names to new values
+    /// * `ids` - The unique identifiers of the records to retrieve
+    /// * `concurrency` - The maximum number of concurrent requests, at least 1
     /// * `user_field_names` - Whether to use user-friendly field names in the response
     ///
     /// # Returns
-    /// The updated record
-    async fn update(
-        self,
-        id: u64,
-        data: HashMap<String, Value>,
+    /// The result for each ID, in the order of `ids`
+    ///
+    /// # Example
+    /// ```no_run
+    /// use baserow_rs::{Baserow, ConfigBuilder, RowId, TableId, TypedTableOperations, api::client::BaserowClient};
+    /// use serde_json::Value;
+    /// use std::collections::HashMap;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let config = ConfigBuilder::new()
+    ///         .base_url("https://api.baserow.io")
+    ///         .api_key("your-api-key")
+    ///         .try_build()
+    ///         .expect("invalid configuration");
+    ///     let baserow = Baserow::with_configuration(config);
+    ///
+    ///     let rows = baserow
+    ///         .table_by_id(TableId(1234))
+    ///         .get_many::<HashMap<String, Value>>(&[RowId(1), RowId(2), RowId(3)], 8, Some(true))
+    ///         .await;
+    ///     for row in rows {
+    ///         println!("{:?}", row);
+    ///     }
+    /// }
+    /// ```
+    async fn get_many<T>(
+        &self,
+        ids: &[RowId],
+        concurrency: usize,
         user_field_names: Option<bool>,
-    ) -> Result<HashMap<String, Value>, Box<dyn Error>>;
+    ) -> Vec<Result<T, BaserowError>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        debug!("Fetching records concurrently");
+        stream::iter(ids.iter().copied())
+            .map(|id| self.get_one(id, user_field_names))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+}
 
-    /// Deletes a single record from the table
-    ///
-    /// # Arguments
-    /// * `id` - The unique identifier of the record to delete
-    async fn delete(self, id: u64) -> Result<(), Box<dyn Error>>;
+impl<T: BaserowTableOperations + ?Sized> TypedTableOperations for T {}
+
+/// Deserializes a row, moving the values instead of copying them into a new tree
+fn deserialize_row<T: DeserializeOwned>(
+    row: HashMap<String, Value>,
+) -> Result<T, serde_json::Error> {
+    serde_json::from_value(Value::Object(row.into_iter().collect()))
 }
 
 #[async_trait]
 impl BaserowTableOperations for BaserowTable {
     #[instrument(skip(self), fields(table_id = ?self.id), err)]
-    async fn auto_map(mut self) -> Result<BaserowTable, Box<dyn Error>> {
-        let id = self.id.ok_or("Table ID is missing")?;
+    async fn auto_map(mut self) -> Result<BaserowTable, BaserowError> {
+        let id = self.id.ok_or(BaserowError::MissingState("Table ID"))?;
 
-        let baserow = self.baserow.clone().ok_or("Baserow instance is missing")?;
+        let baserow = self
+            .baserow
+            .clone()
+            .ok_or(BaserowError::MissingState("Baserow instance"))?;
         debug!("Fetching table fields for mapping");
         let fields = baserow.table_fields(id).await?;
         info!(
@@ -297,392 +1017,789 @@ impl BaserowTableOperations for BaserowTable {
 
         let mut mapper = TableMapper::new();
         mapper.map_fields(fields.clone());
-        self.mapper = Some(mapper);
+        self.mapper = Some(Arc::new(mapper));
+
+        Ok(self)
+    }
+
+    #[instrument(skip(self), fields(table_id = ?self.id), err)]
+    async fn refresh(mut self) -> Result<BaserowTable, BaserowError> {
+        let id = self.id.ok_or(BaserowError::MissingState("Table ID"))?;
+        let baserow = self
+            .baserow
+            .clone()
+            .ok_or(BaserowError::MissingState("Baserow instance"))?;
+
+        debug!("Fetching table metadata");
+        let table = baserow.get_table(id).await?;
+        self.name = table.name;
+        self.order = table.order;
+        self.database_id = table.database_id;
 
         Ok(self)
     }
 
     fn query(self) -> RowRequestBuilder {
-        RowRequestBuilder::new()
-            .with_baserow(self.baserow.clone().unwrap())
-            .with_table(self.clone())
+        let mut builder = RowRequestBuilder::new();
+        if let Some(size) = self.page_size {
+            builder.request.page_size = Some(size);
+        }
+        RowRequestBuilder {
+            baserow: self.baserow.clone(),
+            ..builder.with_table(self)
+        }
     }
 
     #[instrument(skip(self, baserow), fields(table_id = ?self.id), err)]
-    async fn get<T>(
+    async fn get_rows(
         &self,
         baserow: Baserow,
         request: RowRequest,
-    ) -> Result<TypedRowsResponse<T>, Box<dyn Error>>
-    where
-        T: DeserializeOwned + 'static,
-    {
-        // Validate pagination parameters
-        if let Some(size) = request.page_size {
-            if size <= 0 {
-                return Err("Page size must be a positive integer".into());
-            }
-        }
-        if let Some(page) = request.page {
-            if page <= 0 {
-                return Err("Page number must be a positive integer".into());
-            }
+    ) -> Result<RowsResponse, BaserowError> {
+        let mapper = self.rows_mapper(&request)?;
+        let mut response: RowsResponse =
+            serde_json::from_slice(&self.fetch_rows(&baserow, &request).await?)?;
+        // In `Mapped` mode, or for views, convert field IDs to names
+        if let Some(mapper) = mapper {
+            response.results = response
+                .results
+                .into_iter()
+                .map(|row| mapper.convert_to_field_names(row))
+                .collect();
         }
+        Ok(response)
+    }
 
-        let url = format!(
-            "{}/api/database/rows/table/{}/",
-            &baserow.configuration.base_url,
-            self.id.unwrap()
-        );
-
-        let mut req = Client::new().get(url);
-
-        if let Some(view_id) = request.view_id {
-            req = req.query(&[("view_id", view_id.to_string())]);
+    #[instrument(skip(self, baserow), fields(table_id = ?self.id), err)]
+    async fn get_rows_body(
+        &self,
+        baserow: Baserow,
+        request: RowRequest,
+    ) -> Result<Option<Bytes>, BaserowError> {
+        if self.rows_mapper(&request)?.is_some() {
+            return Ok(None);
         }
+        Ok(Some(self.fetch_rows(&baserow, &request).await?))
+    }
 
-        if baserow.configuration.jwt.is_some() {
-            req = req.header(
-                AUTHORIZATION,
-                format!(
-                    "JWT {}",
-                    &baserow.configuration.database_token.as_ref().unwrap()
-                ),
-            );
-        } else if baserow.configuration.database_token.is_some() {
-            req = req.header(
-                AUTHORIZATION,
-                format!(
-                    "Token {}",
-                    &baserow.configuration.database_token.as_ref().unwrap()
-                ),
-            );
-        }
-
-        if let Some(order) = request.order {
-            let mut order_str = String::new();
-            for (field, direction) in order {
-                // Map field name to ID if auto_map is enabled
-                let field_key = if let Some(mapper) = &self.mapper {
-                    if let Some(field_id) = mapper.get_field_id(&field) {
-                        format!("field_{}", field_id)
-                    } else {
-                        field
-                    }
-                } else {
-                    field
-                };
-
-                order_str.push_str(&format!(
-                    "{}{}",
-                    match direction {
-                        OrderDirection::Asc => "",
-                        OrderDirection::Desc => "-",
-                    },
-                    field_key
-                ));
-            }
-
-            req = req.query(&[("order_by", order_str)]);
-        }
-
-        if let Some(filter) = request.filter {
-            for triple in filter {
-                // Map field name to ID if auto_map is enabled
-                let field_key = if let Some(mapper) = &self.mapper {
-                    if let Some(field_id) = mapper.get_field_id(&triple.field) {
-                        format!("field_{}", field_id)
-                    } else {
-                        triple.field
-                    }
-                } else {
-                    triple.field
-                };
-
-                req = req.query(&[(
-                    &format!("filter__{}__{}", field_key, triple.filter.as_str()),
-                    triple.value,
-                )]);
+    #[instrument(skip(self, data), fields(table_id = ?self.id, field_count = data.len()), err)]
+    async fn create_one(
+        &self,
+        data: HashMap<String, Value>,
+        user_field_names: Option<bool>,
+    ) -> Result<HashMap<String, Value>, BaserowError> {
+        let table_id = self.id.ok_or(BaserowError::MissingState("Table ID"))?;
+        let field_names =
+            self.resolve_field_names(user_field_names.map(FieldNamesMode::from_user_field_names))?;
+        let baserow = self
+            .baserow
+            .as_ref()
+            .ok_or(BaserowError::MissingState("Baserow instance"))?;
+        let configuration = baserow.configuration();
+
+        let url = configuration.api_url(&format!("api/database/rows/table/{}/", table_id));
+
+        let mut req = baserow.inner.client.post(url);
+
+        // Validate if enabled and convert field names to IDs if auto_map is enabled
+        let request_data = match &self.mapper {
+            Some(mapper) => {
+                self.validate_row(mapper, &data)?;
+                mapper.convert_to_field_ids(data)
             }
-        }
-
-        if let Some(size) = request.page_size {
-            req = req.query(&[("size", size.to_string())]);
-        }
+            None => data,
+        };
 
-        if let Some(page) = request.page {
-            req = req.query(&[("page", page.to_string())]);
+        if let Some(use_names) = field_names.user_field_names() {
+            req = req.query(&[("user_field_names", use_names.to_string())]);
         }
 
-        if let Some(user_field_names) = request.user_field_names {
-            req = req.query(&[("user_field_names", user_field_names.to_string())]);
-        }
+        req = baserow.auth_header().await.apply(req);
 
-        debug!("Executing table query");
-        let resp = baserow.trace_request(&baserow.client, req.build()?).await?;
+        debug!("Creating new record");
+        let resp = baserow
+            .trace_request(&baserow.inner.client, req.json(&request_data).build()?)
+            .await?;
 
         match resp.status() {
             StatusCode::OK => {
-                let response: RowsResponse = resp.json().await?;
-
-                let typed_results = if let Some(mapper) = &self.mapper {
-                    // When using auto_map, convert field IDs to names first
-                    response
-                        .results
-                        .into_iter()
-                        .map(|row| mapper.deserialize_row(row))
-                        .collect::<Result<Vec<T>, _>>()?
-                } else {
-                    // When not using auto_map, try direct deserialization
-                    serde_json::from_value::<Vec<T>>(Value::Array(
-                        response
-                            .results
-                            .into_iter()
-                            .map(|m| Value::Object(serde_json::Map::from_iter(m.into_iter())))
-                            .collect(),
-                    ))?
-                };
+                let response_data = resp.json::<HashMap<String, Value>>().await?;
 
-                Ok(TypedRowsResponse {
-                    count: response.count,
-                    next: response.next,
-                    previous: response.previous,
-                    results: typed_results,
-                })
+                // Convert response field IDs to names in `Mapped` mode
+                match self.row_mapper(field_names) {
+                    Some(mapper) => Ok(mapper.convert_to_field_names(response_data)),
+                    None => Ok(response_data),
+                }
             }
-            _ => Err(Box::new(resp.error_for_status().unwrap_err())),
+            _ => Err(BaserowError::from_table_response(resp, table_id)
+                .await
+                .map_field_names(|key| field_name(self.mapper.as_deref(), key))),
         }
     }
 
-    #[instrument(skip(self, data), fields(table_id = ?self.id, field_count = data.len()), err)]
-    async fn create_one(
-        self,
-        data: HashMap<String, Value>,
+    #[instrument(skip(self), fields(table_id = ?self.id, record_id = %id), err)]
+    async fn get_row(
+        &self,
+        id: RowId,
         user_field_names: Option<bool>,
-    ) -> Result<HashMap<String, Value>, Box<dyn Error>> {
-        let baserow = self.baserow.expect("Baserow instance is missing");
-
-        let url = format!(
-            "{}/api/database/rows/table/{}/",
-            &baserow.configuration.base_url,
-            self.id.unwrap()
-        );
+    ) -> Result<HashMap<String, Value>, BaserowError> {
+        let field_names =
+            self.resolve_field_names(user_field_names.map(FieldNamesMode::from_user_field_names))?;
+        let row = serde_json::from_slice(&self.fetch_row(id, field_names).await?)?;
+        match self.row_mapper(field_names) {
+            Some(mapper) => Ok(mapper.convert_to_field_names(row)),
+            None => Ok(row),
+        }
+    }
 
-        let mut req = baserow.client.post(url);
+    #[instrument(skip(self), fields(table_id = ?self.id, record_id = %id), err)]
+    async fn get_row_body(
+        &self,
+        id: RowId,
+        user_field_names: Option<bool>,
+    ) -> Result<Option<Bytes>, BaserowError> {
+        let field_names =
+            self.resolve_field_names(user_field_names.map(FieldNamesMode::from_user_field_names))?;
+        if self.row_mapper(field_names).is_some() {
+            return Ok(None);
+        }
+        Ok(Some(self.fetch_row(id, field_names).await?))
+    }
 
-        // Convert field names to IDs if auto_map is enabled
-        let request_data = if self.mapper.is_some() {
-            self.mapper.as_ref().unwrap().convert_to_field_ids(data)
-        } else {
-            data
+    #[instrument(skip(self, data), fields(table_id = ?self.id, record_id = %id, field_count = data.len()), err)]
+    async fn update(
+        &self,
+        id: RowId,
+        data: HashMap<String, Value>,
+        user_field_names: Option<bool>,
+    ) -> Result<HashMap<String, Value>, BaserowError> {
+        let table_id = self.id.ok_or(BaserowError::MissingState("Table ID"))?;
+        let field_names =
+            self.resolve_field_names(user_field_names.map(FieldNamesMode::from_user_field_names))?;
+        let baserow = self
+            .baserow
+            .as_ref()
+            .ok_or(BaserowError::MissingState("Baserow instance"))?;
+        let configuration = baserow.configuration();
+
+        let url = configuration.api_url(&format!("api/database/rows/table/{}/{}/", table_id, id));
+
+        let mut req = baserow.inner.client.patch(url);
+
+        // Validate if enabled and convert field names to IDs if auto_map is enabled
+        let request_data = match &self.mapper {
+            Some(mapper) => {
+                self.validate_row(mapper, &data)?;
+                mapper.convert_to_field_ids(data)
+            }
+            None => data,
         };
 
-        // Use user_field_names parameter for response format
-        if let Some(use_names) = user_field_names {
+        if let Some(use_names) = field_names.user_field_names() {
             req = req.query(&[("user_field_names", use_names.to_string())]);
         }
 
-        if baserow.configuration.jwt.is_some() {
-            req = req.header(
-                AUTHORIZATION,
-                format!("JWT {}", &baserow.configuration.jwt.as_ref().unwrap()),
-            );
-        } else if baserow.configuration.database_token.is_some() {
-            req = req.header(
-                AUTHORIZATION,
-                format!(
-                    "Token {}",
-                    &baserow.configuration.database_token.as_ref().unwrap()
-                ),
-            );
-        }
+        req = baserow.auth_header().await.apply(req);
 
-        debug!("Creating new record");
+        debug!("Updating record");
         let resp = baserow
-            .trace_request(&baserow.client, req.json(&request_data).build()?)
-            .await?;
+            .trace_request(&baserow.inner.client, req.json(&request_data).build()?)
+            .await;
+        // Only after the update, so concurrent reads can't cache the previous row
+        baserow.invalidate_cached_row(table_id, id);
+        let resp = resp?;
 
         match resp.status() {
             StatusCode::OK => {
-                let response_data = resp.json::<HashMap<String, Value>>().await?;
-
-                // Convert response field IDs to names if auto_map is enabled
-                if self.mapper.is_some() && user_field_names != Some(true) {
-                    Ok(self
-                        .mapper
-                        .as_ref()
-                        .unwrap()
-                        .convert_to_field_names(response_data))
-                } else {
-                    Ok(response_data)
+                let body = resp.bytes().await?;
+                let response_data = serde_json::from_slice::<HashMap<String, Value>>(&body)?;
+                baserow.cache_changed_row(table_id, id, field_names.user_field_names(), body);
+
+                // Convert response field IDs to names in `Mapped` mode
+                match self.row_mapper(field_names) {
+                    Some(mapper) => Ok(mapper.convert_to_field_names(response_data)),
+                    None => Ok(response_data),
                 }
             }
-            _ => Err(Box::new(resp.error_for_status().unwrap_err())),
+            _ => Err(BaserowError::from_row_response(resp, table_id, id)
+                .await
+                .map_field_names(|key| field_name(self.mapper.as_deref(), key))),
         }
     }
 
     #[instrument(skip(self), fields(table_id = ?self.id, record_id = %id), err)]
-    async fn get_one<T>(
-        mut self,
-        id: u64,
+    async fn delete(&self, id: RowId) -> Result<(), BaserowError> {
+        let table_id = self.id.ok_or(BaserowError::MissingState("Table ID"))?;
+        let baserow = self
+            .baserow
+            .as_ref()
+            .ok_or(BaserowError::MissingState("Baserow instance"))?;
+        let configuration = baserow.configuration();
+
+        let url = configuration.api_url(&format!("api/database/rows/table/{}/{}/", table_id, id));
+
+        let mut req = baserow.inner.client.delete(url);
+
+        req = baserow.auth_header().await.apply(req);
+
+        debug!("Deleting record");
+        let resp = baserow
+            .trace_request(&baserow.inner.client, req.build()?)
+            .await;
+        baserow.invalidate_cached_row(table_id, id);
+        let resp = resp?;
+
+        match resp.status() {
+            StatusCode::OK => Ok(()),
+            _ => Err(BaserowError::from_row_response(resp, table_id, id).await),
+        }
+    }
+
+    #[instrument(skip(self, rows), fields(table_id = ?self.id, row_count = rows.len()), err)]
+    async fn create_many(
+        &self,
+        rows: Vec<HashMap<String, Value>>,
         user_field_names: Option<bool>,
-    ) -> Result<T, Box<dyn Error>>
-    where
-        T: DeserializeOwned + 'static,
-    {
-        let baserow = self.baserow.expect("Baserow instance is missing");
+    ) -> Result<BatchResult<HashMap<String, Value>>, BaserowError> {
+        let table_id = self.id.ok_or(BaserowError::MissingState("Table ID"))?;
+        let field_names =
+            self.resolve_field_names(user_field_names.map(FieldNamesMode::from_user_field_names))?;
+        let baserow = self
+            .baserow
+            .as_ref()
+            .ok_or(BaserowError::MissingState("Baserow instance"))?;
+
+        let items = rows.into_iter().map(|row| self.prepare_row(row)).collect();
+
+        debug!("Creating records in batches");
+        let result = batch::apply(
+            baserow,
+            BatchOperation::Create,
+            table_id,
+            items,
+            field_names.user_field_names(),
+            self.mapper.as_deref(),
+        )
+        .await;
+        Ok(self.convert_batch_rows(result, field_names))
+    }
 
-        let url = format!(
-            "{}/api/database/rows/table/{}/{}/",
-            &baserow.configuration.base_url,
-            self.id.unwrap(),
-            id
-        );
+    #[instrument(skip(self, rows), fields(table_id = ?self.id, row_count = rows.len()), err)]
+    async fn update_many(
+        &self,
+        rows: Vec<(RowId, HashMap<String, Value>)>,
+        user_field_names: Option<bool>,
+    ) -> Result<BatchResult<HashMap<String, Value>>, BaserowError> {
+        let table_id = self.id.ok_or(BaserowError::MissingState("Table ID"))?;
+        let field_names =
+            self.resolve_field_names(user_field_names.map(FieldNamesMode::from_user_field_names))?;
+        let baserow = self
+            .baserow
+            .as_ref()
+            .ok_or(BaserowError::MissingState("Baserow instance"))?;
+
+        let ids: Vec<RowId> = rows.iter().map(|(id, _)| *id).collect();
+        let items = rows
+            .into_iter()
+            .map(|(id, row)| {
+                let mut item = self.prepare_row(row)?;
+                item["id"] = Value::from(id.get());
+                Ok(item)
+            })
+            .collect();
+
+        debug!("Updating records in batches");
+        let result: BatchResult<HashMap<String, Value>> = batch::apply(
+            baserow,
+            BatchOperation::Update,
+            table_id,
+            items,
+            field_names.user_field_names(),
+            self.mapper.as_deref(),
+        )
+        .await;
+
+        // Only after the update, so concurrent reads can't cache the previous rows
+        for id in ids {
+            baserow.invalidate_cached_row(table_id, id);
+        }
+        if baserow.inner.row_cache.is_some() {
+            for row in result.rows() {
+                let id = row.get("id").and_then(Value::as_u64);
+                if let (Some(id), Ok(body)) = (id, serde_json::to_vec(row)) {
+                    let user_field_names = field_names.user_field_names();
+                    baserow.cache_changed_row(table_id, RowId(id), user_field_names, body.into());
+                }
+            }
+        }
+        Ok(self.convert_batch_rows(result, field_names))
+    }
 
-        let mut req = baserow.client.get(url);
+    #[instrument(skip(self, ids), fields(table_id = ?self.id, row_count = ids.len()), err)]
+    async fn delete_many(&self, ids: Vec<RowId>) -> Result<BatchResult<RowId>, BaserowError> {
+        let table_id = self.id.ok_or(BaserowError::MissingState("Table ID"))?;
+        let baserow = self
+            .baserow
+            .as_ref()
+            .ok_or(BaserowError::MissingState("Baserow instance"))?;
+
+        let items = ids.iter().map(|id| Ok(Value::from(id.get()))).collect();
+
+        debug!("Deleting records in batches");
+        let result = batch::apply(
+            baserow,
+            BatchOperation::Delete,
+            table_id,
+            items,
+            None,
+            self.mapper.as_deref(),
+        )
+        .await;
+        // Only after the deletion, so concurrent reads can't cache the deleted rows
+        for id in ids {
+            baserow.invalidate_cached_row(table_id, id);
+        }
+        Ok(result)
+    }
+}
 
-        if let Some(use_names) = user_field_names {
-            req = req.query(&[("user_field_names", use_names.to_string())]);
+impl BaserowTable {
+    /// Chooses the mapper converting the rows returned for a row request, if any
+    fn rows_mapper(&self, request: &RowRequest) -> Result<Option<&TableMapper>, BaserowError> {
+        let field_names = self.resolve_field_names(request.field_names)?;
+        let view_path = request.view.as_ref().and_then(ViewSource::path);
+        // The endpoints of views key fields by their IDs in any mode
+        match (view_path, field_names) {
+            (Some(_), FieldNamesMode::UserNames | FieldNamesMode::Mapped) => Ok(Some(
+                self.mapper
+                    .as_deref()
+                    .ok_or(BaserowError::MissingState("Table mapper"))?,
+            )),
+            _ => Ok(self.row_mapper(field_names)),
         }
+    }
 
-        if baserow.configuration.jwt.is_some() {
-            req = req.header(
-                AUTHORIZATION,
-                format!("JWT {}", &baserow.configuration.jwt.as_ref().unwrap()),
-            );
-        } else if baserow.configuration.database_token.is_some() {
-            req = req.header(
-                AUTHORIZATION,
-                format!(
-                    "Token {}",
-                    &baserow.configuration.database_token.as_ref().unwrap()
-                ),
+    /// Sends a row request and returns the response body
+    async fn fetch_rows(
+        &self,
+        baserow: &Baserow,
+        request: &RowRequest,
+    ) -> Result<Bytes, BaserowError> {
+        let configuration = baserow.configuration();
+        let view_path = request.view.as_ref().and_then(ViewSource::path);
+        let (url, table_id) = match &view_path {
+            Some(path) => (configuration.api_url(path), None),
+            None => {
+                let table_id = self.id.ok_or(BaserowError::MissingState("Table ID"))?;
+                let path = format!("api/database/rows/table/{}/", table_id);
+                (configuration.api_url(&path), Some(table_id))
+            }
+        };
+        if let Some(row_ids) = &request.row_ids {
+            let req = self
+                .rows_by_id_request(baserow, &url, request, row_ids)
+                .await?;
+            debug!(
+                row_count = row_ids.len(),
+                "Executing grid view query by row IDs"
             );
+            let resp = baserow.trace_request(&baserow.inner.client, req).await?;
+            if resp.status() != StatusCode::OK {
+                return Err(BaserowError::from_response(resp).await);
+            }
+            // The endpoint returns the rows as a list, which is turned into a single page
+            let results: Vec<Value> = serde_json::from_slice(&resp.bytes().await?)?;
+            let page = json!({
+                "count": results.len(),
+                "next": null,
+                "previous": null,
+                "results": results,
+            });
+            return Ok(Bytes::from(serde_json::to_vec(&page)?));
+        }
+        let limit = configuration.max_url_length();
+
+        let mut req = self.rows_request(baserow, &url, request).await?;
+        if req.url().as_str().len() > limit {
+            if let Some(flattened) = request.flattened() {
+                debug!("Sending the filter tree as separate filter parameters");
+                req = self.rows_request(baserow, &url, &flattened).await?;
+            }
+        }
+        let length = req.url().as_str().len();
+        if length > limit {
+            let error = BaserowError::QueryTooLarge { length, limit };
+            error.log();
+            return Err(error);
         }
 
-        debug!("Fetching single record");
-        let resp = baserow.trace_request(&baserow.client, req.build()?).await?;
+        debug!("Executing table query");
+        let resp = baserow.trace_request(&baserow.inner.client, req).await?;
 
         match resp.status() {
-            StatusCode::OK => {
-                let row: HashMap<String, Value> = resp.json().await?;
-
-                // For HashMap<String, Value>, use serde to convert
-                if std::any::TypeId::of::<T>() == std::any::TypeId::of::<HashMap<String, Value>>() {
-                    Ok(serde_json::from_value(serde_json::to_value(row)?)?)
-                } else {
-                    // For other types, use the mapper if available
-                    let mapper = self.mapper.clone().ok_or("Table mapper is missing. Call auto_map() first when using typed responses.")?;
-                    Ok(mapper.deserialize_row(row)?)
-                }
+            StatusCode::OK => Ok(resp.bytes().await?),
+            _ => match table_id {
+                Some(table_id) => Err(BaserowError::from_table_response(resp, table_id).await),
+                None => Err(BaserowError::from_response(resp).await),
+            },
+        }
+    }
+
+    /// Builds the request listing the rows of a row request
+    async fn rows_request(
+        &self,
+        baserow: &Baserow,
+        url: &str,
+        request: &RowRequest,
+    ) -> Result<reqwest::Request, BaserowError> {
+        let params = self.query_params(request)?;
+        let req = baserow
+            .auth_header()
+            .await
+            .apply(baserow.inner.client.get(url))
+            .query(&params);
+        Ok(req.build()?)
+    }
+
+    /// Builds the request listing rows of a grid view by their IDs, which are sent in the body
+    async fn rows_by_id_request(
+        &self,
+        baserow: &Baserow,
+        url: &str,
+        request: &RowRequest,
+        row_ids: &[RowId],
+    ) -> Result<reqwest::Request, BaserowError> {
+        let invalid = |message: &str| {
+            let error = BaserowError::InvalidRequest(message.to_string());
+            error.log();
+            Err(error)
+        };
+        if !matches!(
+            request.view,
+            Some(ViewSource::View {
+                view_type: ViewType::Grid,
+                ..
+            })
+        ) {
+            return invalid("Rows can only be requested by ID from grid views");
+        }
+        if request.filter.is_some()
+            || request.filter_tree.is_some()
+            || request.order.is_some()
+            || request.include_metadata
+        {
+            return invalid("Rows requested by ID cannot be filtered, sorted or include metadata");
+        }
+        if request.page.is_some_and(|page| page != 1) {
+            return invalid("Rows requested by ID are returned as a single page");
+        }
+
+        let mut body = json!({ "row_ids": row_ids });
+        if let Some(fields) = &request.fields {
+            let field_ids = fields
+                .iter()
+                .map(|field| self.field_id(field).ok_or(field))
+                .collect::<Result<Vec<FieldId>, _>>();
+            match field_ids {
+                Ok(field_ids) => body["field_ids"] = json!(field_ids),
+                Err(field) => return invalid(&format!("Unknown field {}", field)),
             }
-            _ => Err(Box::new(resp.error_for_status().unwrap_err())),
         }
+        let req = baserow
+            .auth_header()
+            .await
+            .apply(baserow.inner.client.post(url))
+            .json(&body);
+        Ok(req.build()?)
     }
 
-    #[instrument(skip(self, data), fields(table_id = ?self.id, record_id = %id, field_count = data.len()), err)]
-    async fn update(
-        self,
-        id: u64,
-        data: HashMap<String, Value>,
-        user_field_names: Option<bool>,
-    ) -> Result<HashMap<String, Value>, Box<dyn Error>> {
-        let baserow = self.baserow.expect("Baserow instance is missing");
-
-        let url = format!(
-            "{}/api/database/rows/table/{}/{}/",
-            &baserow.configuration.base_url,
-            self.id.unwrap(),
-            id
-        );
+    /// Retrieves the body of a single record, from the row cache if enabled
+    async fn fetch_row(
+        &self,
+        id: RowId,
+        field_names: FieldNamesMode,
+    ) -> Result<Bytes, BaserowError> {
+        let table_id = self.id.ok_or(BaserowError::MissingState("Table ID"))?;
+        let baserow = self
+            .baserow
+            .as_ref()
+            .ok_or(BaserowError::MissingState("Baserow instance"))?;
+        let cache = baserow.inner.row_cache.as_deref();
+
+        let user_field_names = field_names.user_field_names();
+        match cache.and_then(|cache| cache.get(table_id, id, user_field_names)) {
+            Some(body) => {
+                debug!("Using cached record");
+                Ok(body)
+            }
+            None => {
+                // Taken before the request, so a change completing meanwhile discards the response
+                let generation = cache.map(|cache| cache.generation(table_id, id));
+                let configuration = baserow.configuration();
 
-        let mut req = baserow.client.patch(url);
+                let url =
+                    configuration.api_url(&format!("api/database/rows/table/{}/{}/", table_id, id));
 
-        // Convert field names to IDs if auto_map is enabled
-        let request_data = if self.mapper.is_some() {
-            self.mapper.as_ref().unwrap().convert_to_field_ids(data)
-        } else {
-            data
-        };
+                let mut req = baserow.inner.client.get(url);
 
-        // Use user_field_names parameter for response format
-        if let Some(use_names) = user_field_names {
-            req = req.query(&[("user_field_names", use_names.to_string())]);
+                if let Some(use_names) = user_field_names {
+                    req = req.query(&[("user_field_names", use_names.to_string())]);
+                }
+
+                req = baserow.auth_header().await.apply(req);
+
+                debug!("Fetching single record");
+                let resp = baserow
+                    .trace_request(&baserow.inner.client, req.build()?)
+                    .await?;
+
+                if resp.status() != StatusCode::OK {
+                    return Err(BaserowError::from_row_response(resp, table_id, id).await);
+                }
+
+                let body = resp.bytes().await?;
+                if let (Some(cache), Some(generation)) = (cache, generation) {
+                    cache.insert(table_id, id, user_field_names, body.clone(), generation);
+                }
+                Ok(body)
+            }
         }
+    }
 
-        if baserow.configuration.jwt.is_some() {
-            req = req.header(
-                AUTHORIZATION,
-                format!("JWT {}", &baserow.configuration.jwt.as_ref().unwrap()),
-            );
-        } else if baserow.configuration.database_token.is_some() {
-            req = req.header(
-                AUTHORIZATION,
-                format!(
-                    "Token {}",
-                    &baserow.configuration.database_token.as_ref().unwrap()
-                ),
-            );
+    /// Computes the query parameters of a row request, in the order they are sent
+    ///
+    /// Validates the pagination of the request and, unless fields are keyed by
+    /// their names, maps the field names in orders and filters to IDs.
+    pub(crate) fn query_params(
+        &self,
+        request: &RowRequest,
+    ) -> Result<Vec<(String, String)>, BaserowError> {
+        if request.page_size.is_some_and(|size| size <= 0) {
+            return Err(BaserowError::InvalidRequest(
+                "Page size must be a positive integer".to_string(),
+            ));
+        }
+        if request.page.is_some_and(|page| page <= 0) {
+            return Err(BaserowError::InvalidRequest(
+                "Page number must be a positive integer".to_string(),
+            ));
         }
 
-        debug!("Updating record");
-        let resp = baserow
-            .trace_request(&baserow.client, req.json(&request_data).build()?)
-            .await?;
+        let field_names = self.resolve_field_names(request.field_names)?;
+        let view_type = request.view.as_ref().and_then(ViewSource::view_type);
+        // Field names in orders and filters are resolved by Baserow itself in
+        // `UserNames` mode, except by the endpoints of views, otherwise they
+        // are mapped to IDs if possible
+        let key_mapper = match field_names {
+            FieldNamesMode::UserNames if view_type.is_none() => None,
+            _ => self.mapper.as_deref(),
+        };
+        let field_key = |field: &str| match key_mapper.and_then(|m| m.get_field_id(field)) {
+            Some(field_id) => format!("field_{}", field_id),
+            None => field.to_string(),
+        };
 
-        match resp.status() {
-            StatusCode::OK => {
-                let response_data = resp.json::<HashMap<String, Value>>().await?;
+        let mut params = Vec::new();
+        if let Some(ViewSource::Table(view_id)) = &request.view {
+            params.push(("view_id".to_string(), view_id.to_string()));
+        }
+
+        if let Some(order) = &request.order {
+            let fields: Vec<String> = order
+                .iter()
+                .map(|(field, direction)| match direction {
+                    OrderDirection::Asc => field_key(field),
+                    OrderDirection::Desc => format!("-{}", field_key(field)),
+                })
+                .collect();
+            params.push(("order_by".to_string(), fields.join(",")));
+        }
 
-                // Convert response field IDs to names if auto_map is enabled
-                if self.mapper.is_some() && user_field_names != Some(true) {
-                    Ok(self
-                        .mapper
-                        .as_ref()
-                        .unwrap()
-                        .convert_to_field_names(response_data))
-                } else {
-                    Ok(response_data)
+        match &request.filter_tree {
+            // Baserow ignores the other filter parameters if a tree is given
+            Some(tree) => {
+                let conditions: Vec<FilterTree> = request
+                    .filter
+                    .iter()
+                    .flatten()
+                    .cloned()
+                    .map(FilterTree::from)
+                    .collect();
+                let tree = match request.filter_type {
+                    Some(FilterType::Or) if !conditions.is_empty() => {
+                        FilterTree::all([FilterTree::any(conditions), tree.clone()])
+                    }
+                    _ => FilterTree::all(conditions.into_iter().chain([tree.clone()])),
+                };
+                // Fields are given by ID, or by name where Baserow resolves names
+                let resolves_names =
+                    matches!(field_names, FieldNamesMode::UserNames) && view_type.is_none();
+                let field_json = |field: &str| {
+                    let key = field_key(field);
+                    match key.strip_prefix("field_").map(str::parse::<u64>) {
+                        Some(Ok(field_id)) if !resolves_names || key != field => {
+                            Value::from(field_id)
+                        }
+                        _ => Value::from(key),
+                    }
+                };
+                params.push(("filters".to_string(), tree.to_json(&field_json).to_string()));
+            }
+            None => {
+                for triple in request.filter.iter().flatten() {
+                    params.push((
+                        format!(
+                            "filter__{}__{}",
+                            field_key(&triple.field),
+                            triple.filter.as_str()
+                        ),
+                        triple.value.clone(),
+                    ));
+                }
+                if let (Some(filter_type), Some(_)) = (request.filter_type, &request.filter) {
+                    params.push(("filter_type".to_string(), filter_type.as_str().to_string()));
                 }
             }
-            _ => Err(Box::new(resp.error_for_status().unwrap_err())),
         }
-    }
 
-    #[instrument(skip(self), fields(table_id = ?self.id, record_id = %id), err)]
-    async fn delete(self, id: u64) -> Result<(), Box<dyn Error>> {
-        let baserow = self.baserow.expect("Baserow instance is missing");
-
-        let url = format!(
-            "{}/api/database/rows/table/{}/{}/",
-            &baserow.configuration.base_url,
-            self.id.unwrap(),
-            id
-        );
+        match (view_type, request.page_size, request.page) {
+            // Gallery views are paginated by offset only
+            (Some(ViewType::Gallery), size, page) => {
+                let size = size.unwrap_or(100);
+                params.push(("limit".to_string(), size.to_string()));
+                let offset = (page.unwrap_or(1) as i64 - 1) * size as i64;
+                params.push(("offset".to_string(), offset.to_string()));
+            }
+            (_, size, page) => {
+                if let Some(size) = size {
+                    params.push(("size".to_string(), size.to_string()));
+                }
+                if let Some(page) = page {
+                    params.push(("page".to_string(), page.to_string()));
+                }
+            }
+        }
+        // The endpoints of views neither resolve field names nor select fields
+        if view_type.is_none() {
+            // Baserow reads a single `include` parameter, so the selected fields
+            // and the metadata are requested together
+            let mut include: Vec<String> = request
+                .fields
+                .iter()
+                .flatten()
+                .map(|field| field_key(field))
+                .collect();
+            if request.include_metadata {
+                include.push("metadata".to_string());
+            }
+            if !include.is_empty() {
+                params.push(("include".to_string(), include.join(",")));
+            }
+            if let Some(user_field_names) = field_names.user_field_names() {
+                params.push(("user_field_names".to_string(), user_field_names.to_string()));
+            }
+        }
+        Ok(params)
+    }
 
-        let mut req = baserow.client.delete(url);
+    /// Resolves a field given by name or as `field_<id>` key to its ID
+    pub(crate) fn field_id(&self, field: &str) -> Option<FieldId> {
+        self.mapper
+            .as_ref()
+            .and_then(|mapper| mapper.get_field_id(field))
+            .or_else(|| field.strip_prefix("field_")?.parse().ok().map(FieldId))
+    }
 
-        if baserow.configuration.jwt.is_some() {
-            req = req.header(
-                AUTHORIZATION,
-                format!("JWT {}", &baserow.configuration.jwt.as_ref().unwrap()),
-            );
-        } else if baserow.configuration.database_token.is_some() {
-            req = req.header(
-                AUTHORIZATION,
-                format!(
-                    "Token {}",
-                    &baserow.configuration.database_token.as_ref().unwrap()
-                ),
-            );
+    /// Returns the keys a field given by name or ID may have in the rows of the table
+    ///
+    /// Rows are keyed by the name or the ID of a field, depending on the field
+    /// names mode. Look up values with [`row_value`].
+    pub(crate) fn row_keys(&self, field: &str) -> Vec<String> {
+        let mut keys = vec![field.to_string()];
+        if let Some(field_id) = self.field_id(field) {
+            keys.push(format!("field_{}", field_id));
+            if let Some(name) = self
+                .mapper
+                .as_ref()
+                .and_then(|m| m.get_field_name(field_id))
+            {
+                keys.push(name);
+            }
         }
+        keys
+    }
 
-        debug!("Deleting record");
-        let resp = baserow.trace_request(&baserow.client, req.build()?).await?;
+    /// Fetches all rows matching a request, page by page
+    ///
+    /// The page of the request is ignored.
+    #[cfg(any(feature = "changes", feature = "sync"))]
+    pub(crate) async fn all_rows(
+        &self,
+        request: RowRequest,
+    ) -> Result<Vec<HashMap<String, Value>>, BaserowError> {
+        let baserow = self
+            .baserow
+            .as_ref()
+            .ok_or(BaserowError::MissingState("Baserow instance"))?;
+        self.pages(baserow, request).try_concat().await
+    }
 
-        match resp.status() {
-            StatusCode::OK => Ok(()),
-            _ => Err(Box::new(resp.error_for_status().unwrap_err())),
+    /// Streams the pages of rows matching a request, fetching each page when it is polled
+    ///
+    /// The page of the request is ignored.
+    pub(crate) fn pages<'a>(
+        &'a self,
+        baserow: &'a Baserow,
+        mut request: RowRequest,
+    ) -> impl Stream<Item = Result<Vec<HashMap<String, Value>>, BaserowError>> + Send + 'a {
+        request.page = Some(1);
+        stream::try_unfold(Some(request), move |request| async move {
+            let Some(mut request) = request else {
+                return Ok(None);
+            };
+            debug!(page = request.page, "Fetching page");
+            let response = self.get_rows(baserow.clone(), request.clone()).await?;
+            let next = response.next.map(|_| {
+                request.page = request.page.map(|page| page + 1);
+                request
+            });
+            Ok(Some((response.results, next)))
+        })
+    }
+
+    /// Validates a row if enabled and converts its field names to IDs if auto_map is enabled
+    fn prepare_row(&self, row: HashMap<String, Value>) -> Result<Value, BaserowError> {
+        let row = match &self.mapper {
+            Some(mapper) => {
+                self.validate_row(mapper, &row)?;
+                mapper.convert_to_field_ids(row)
+            }
+            None => row,
+        };
+        Ok(Value::Object(row.into_iter().collect()))
+    }
+
+    /// Validates a row against the schema if enabled via `validate_writes()`
+    fn validate_row(
+        &self,
+        mapper: &TableMapper,
+        row: &HashMap<String, Value>,
+    ) -> Result<(), BaserowError> {
+        if self.validate_writes {
+            mapper.validate_row(row)?;
+        }
+        Ok(())
+    }
+
+    /// Converts the field IDs of rows returned by a batch operation to names in `Mapped` mode
+    fn convert_batch_rows(
+        &self,
+        result: BatchResult<HashMap<String, Value>>,
+        field_names: FieldNamesMode,
+    ) -> BatchResult<HashMap<String, Value>> {
+        match self.row_mapper(field_names) {
+            Some(mapper) => result.map_rows(|row| mapper.convert_to_field_names(row)),
+            None => result,
         }
     }
 }
@@ -691,11 +1808,12 @@ impl BaserowTableOperations for BaserowTable {
 mod tests {
     use super::*;
     use crate::{
-        api::client::BaserowClient, filter::Filter, Baserow, BaserowTableOperations, ConfigBuilder,
-        OrderDirection,
+        api::client::BaserowClient,
+        filter::{field, Filter, FilterType},
+        Baserow, BaserowTableOperations, ConfigBuilder, OrderDirection, TableId,
     };
     use serde::Deserialize;
-    use serde_json::Value;
+    use serde_json::{json, Value};
     use std::collections::HashMap;
 
     #[derive(Debug, Deserialize, PartialEq)]
@@ -769,9 +1887,10 @@ mod tests {
         let configuration = ConfigBuilder::new()
             .base_url(&mock_url)
             .api_key("test-token")
-            .build();
+            .try_build()
+            .unwrap();
         let baserow = Baserow::with_configuration(configuration);
-        let table = baserow.table_by_id(1234);
+        let table = baserow.table_by_id(TableId(1234));
 
         // Test deserialization of multiple records with complex types
         let mapped_table = table.auto_map().await.unwrap();
@@ -786,7 +1905,7 @@ mod tests {
         assert_eq!(record1.name, "John Doe");
         assert_eq!(record1.email, "john@example.com");
         assert_eq!(record1.age, Some(30));
-        assert_eq!(record1.is_active, true);
+        assert!(record1.is_active);
         assert_eq!(record1.created_at, "2023-01-01T00:00:00Z");
 
         // Verify second record with null field
@@ -795,7 +1914,7 @@ mod tests {
         assert_eq!(record2.name, "Jane Smith");
         assert_eq!(record2.email, "jane@example.com");
         assert_eq!(record2.age, None);
-        assert_eq!(record2.is_active, false);
+        assert!(!record2.is_active);
         assert_eq!(record2.created_at, "2023-01-02T00:00:00Z");
 
         fields_mock.assert();
@@ -803,23 +1922,32 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_auto_map_and_user_field_names_exclusivity() {
+    async fn test_field_names_modes() {
         let mut server = mockito::Server::new_async().await;
         let mock_url = server.url();
 
-        // Mock the fields endpoint
         let fields_mock = server
             .mock("GET", "/api/database/fields/table/1234/")
             .with_status(200)
             .with_header("Content-Type", "application/json")
             .with_body(r#"[{"id": 1, "table_id": 1234, "name": "Name", "order": 0, "type": "text", "primary": true, "read_only": false}]"#)
             .create();
-
-        // Mock the rows endpoint
-        let rows_mock = server
+        let names_mock = server
+            .mock("GET", "/api/database/rows/table/1234/")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "user_field_names".into(),
+                "true".into(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"count": 1, "next": null, "previous": null, "results": [{"Name": "test"}]}"#,
+            )
+            .create();
+        let ids_mock = server
             .mock("GET", "/api/database/rows/table/1234/")
             .match_query(mockito::Matcher::Any)
-            .expect_at_least(1)
+            .expect(2)
             .with_status(200)
             .with_header("Content-Type", "application/json")
             .with_body(
@@ -830,38 +1958,177 @@ mod tests {
         let configuration = ConfigBuilder::new()
             .base_url(&mock_url)
             .api_key("test-token")
-            .build();
+            .try_build()
+            .unwrap();
         let baserow = Baserow::with_configuration(configuration);
-        let table = baserow.table_by_id(1234);
+        let table = baserow.table_by_id(TableId(1234));
 
-        // First test: auto_map should take precedence over user_field_names
-        let mapped_table = table.clone().auto_map().await.unwrap();
-        let _query = mapped_table
+        // Mapped mode requires the fields of the table
+        let result = table
+            .clone()
+            .with_field_names(FieldNamesMode::Mapped)
+            .query()
+            .get::<HashMap<String, Value>>()
+            .await;
+        assert!(matches!(
+            result,
+            Err(BaserowError::MissingState("Table mapper"))
+        ));
+
+        let mapped_table = table.auto_map().await.unwrap();
+        assert_eq!(mapped_table.field_names(), FieldNamesMode::Mapped);
+
+        // Names resolved by Baserow are returned as is
+        let rows = mapped_table
+            .clone()
+            .query()
+            .user_field_names(true)
+            .get::<HashMap<String, Value>>()
+            .await
+            .unwrap();
+        assert_eq!(rows.results[0]["Name"], "test");
+
+        // Mapped tables resolve names themselves by default
+        let rows = mapped_table
+            .clone()
             .query()
-            .user_field_names(true) // This should be ignored since we have auto_map
             .get::<HashMap<String, Value>>()
             .await
             .unwrap();
+        assert_eq!(rows.results[0]["Name"], "test");
+
+        let rows = mapped_table
+            .query()
+            .field_names(FieldNamesMode::Ids)
+            .get::<HashMap<String, Value>>()
+            .await
+            .unwrap();
+        assert_eq!(rows.results[0]["field_1"], "test");
 
-        // Verify that user_field_names parameter was not included in the request
-        rows_mock.assert();
         fields_mock.assert();
+        names_mock.assert();
+        ids_mock.assert();
     }
 
     #[tokio::test]
-    async fn test_field_mapping_in_query_params() {
+    async fn test_rows_body() {
         let mut server = mockito::Server::new_async().await;
         let mock_url = server.url();
 
-        // Mock the fields endpoint
-        let fields_mock = server
-            .mock("GET", "/api/database/fields/table/1234/")
+        let rows_mock = server
+            .mock("GET", "/api/database/rows/table/1234/")
+            .match_query(mockito::Matcher::Any)
             .with_status(200)
             .with_header("Content-Type", "application/json")
-            .with_body(r#"[
-                {"id": 1, "table_id": 1234, "name": "name", "order": 0, "type": "text", "primary": true, "read_only": false},
-                {"id": 2, "table_id": 1234, "name": "age", "order": 1, "type": "number", "primary": false, "read_only": false}
-            ]"#)
+            .with_body(
+                r#"{"count": 1, "next": null, "previous": null, "results": [{"Name": "test"}]}"#,
+            )
+            .create();
+        let row_mock = server
+            .mock("GET", "/api/database/rows/table/1234/1/")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": 1, "Name": "test"}"#)
+            .create();
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+        let table = baserow.table_by_id(TableId(1234));
+
+        // Rows needing no conversion are handed over as the raw body
+        let body = table
+            .get_rows_body(baserow.clone(), RowRequest::default())
+            .await
+            .unwrap()
+            .unwrap();
+        let rows: TypedRowsResponse<HashMap<String, Value>> =
+            serde_json::from_slice(&body).unwrap();
+        assert_eq!(rows.results[0]["Name"], "test");
+        let body = table.get_row_body(RowId(1), Some(true)).await.unwrap();
+        assert!(body.is_some());
+
+        // Rows to be converted are left to `get_rows` without a request
+        let mut mapped_table = table.with_field_names(FieldNamesMode::Mapped);
+        mapped_table.mapper = Some(Arc::new(TableMapper::new()));
+        let body = mapped_table
+            .get_rows_body(baserow, RowRequest::default())
+            .await
+            .unwrap();
+        assert!(body.is_none());
+        let body = mapped_table.get_row_body(RowId(1), None).await.unwrap();
+        assert!(body.is_none());
+
+        rows_mock.assert();
+        row_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_refresh() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        let table_mock = server
+            .mock("GET", "/api/database/tables/1234/")
+            .match_header("Authorization", "JWT jwt-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": 1234, "name": "Customers", "order": 2, "database_id": 12}"#)
+            .create();
+        let missing_mock = server
+            .mock("GET", "/api/database/tables/5678/")
+            .with_status(404)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"error": "ERROR_TABLE_DOES_NOT_EXIST", "detail": "The requested table does not exist."}"#)
+            .create();
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .access_token("jwt-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+
+        let table = baserow
+            .table_by_id(TableId(1234))
+            .with_default_page_size(50)
+            .refresh()
+            .await
+            .unwrap();
+        assert_eq!(table.id(), Some(TableId(1234)));
+        assert_eq!(table.name.as_deref(), Some("Customers"));
+        assert_eq!(table.order(), Some(2));
+        assert_eq!(table.database_id(), Some(12));
+        assert_eq!(table.page_size, Some(50));
+
+        let result = baserow.get_table(TableId(5678)).await;
+        assert!(matches!(
+            result,
+            Err(BaserowError::TableNotFound(TableId(5678)))
+        ));
+
+        table_mock.assert();
+        missing_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_field_mapping_in_query_params() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        // Mock the fields endpoint
+        let fields_mock = server
+            .mock("GET", "/api/database/fields/table/1234/")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"[
+                {"id": 1, "table_id": 1234, "name": "name", "order": 0, "type": "text", "primary": true, "read_only": false},
+                {"id": 2, "table_id": 1234, "name": "age", "order": 1, "type": "number", "primary": false, "read_only": false}
+            ]"#)
             .create();
 
         // Mock the rows endpoint with field ID mapping
@@ -870,7 +2137,7 @@ mod tests {
             .match_query(mockito::Matcher::AllOf(vec![
                 mockito::Matcher::UrlEncoded(
                     "order_by".into(),
-                    "field_1".into(), // Should use field_1 instead of "name"
+                    "field_1,-field_2".into(), // Should use field IDs instead of names, by priority
                 ),
                 mockito::Matcher::UrlEncoded(
                     "filter__field_2__equal".into(), // Should use field_2 instead of "age"
@@ -885,14 +2152,17 @@ mod tests {
         let configuration = ConfigBuilder::new()
             .base_url(&mock_url)
             .api_key("test-token")
-            .build();
+            .try_build()
+            .unwrap();
         let baserow = Baserow::with_configuration(configuration);
-        let table = baserow.table_by_id(1234);
+        let table = baserow.table_by_id(TableId(1234));
 
         // Test that field names are properly mapped to IDs in query parameters
         let mapped_table = table.auto_map().await.unwrap();
         let _result = mapped_table
             .query()
+            .order_by("name", OrderDirection::Desc)
+            .order_by("age", OrderDirection::Desc)
             .order_by("name", OrderDirection::Asc)
             .filter_by("age", Filter::Equal, "25")
             .get::<HashMap<String, Value>>()
@@ -904,6 +2174,1057 @@ mod tests {
         rows_mock.assert();
     }
 
+    #[tokio::test]
+    async fn test_api_error() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        let mock = server
+            .mock("GET", "/api/database/rows/table/1234/1/")
+            .with_status(404)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"error": "ERROR_ROW_DOES_NOT_EXIST", "detail": "The row does not exist."}"#,
+            )
+            .create();
+
+        let fields_mock = server
+            .mock("GET", "/api/database/fields/table/4321/")
+            .with_status(404)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"error": "ERROR_TABLE_DOES_NOT_EXIST", "detail": "The requested table does not exist."}"#)
+            .create();
+
+        let create_mock = server
+            .mock("POST", "/api/database/rows/table/1234/")
+            .with_status(400)
+            .with_header("Content-Type", "application/json")
+            .with_header("X-Request-Id", "req-42")
+            .with_body(r#"{"error": "ERROR_PERMISSION_DENIED", "detail": "You don't have the required permission."}"#)
+            .create();
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+
+        let result = baserow
+            .table_by_id(TableId(1234))
+            .get_one::<HashMap<String, Value>>(RowId(1), None)
+            .await;
+        assert!(matches!(
+            result,
+            Err(BaserowError::RowNotFound {
+                table_id: TableId(1234),
+                row_id: RowId(1)
+            })
+        ));
+
+        let result = baserow.table_by_id(TableId(4321)).auto_map().await;
+        assert!(matches!(
+            result,
+            Err(BaserowError::TableNotFound(TableId(4321)))
+        ));
+
+        let result = baserow
+            .table_by_id(TableId(1234))
+            .create_one(HashMap::from([("name".to_string(), Value::Null)]), None)
+            .await;
+        let error = result.unwrap_err();
+        assert_eq!(error.error_code(), Some("ERROR_PERMISSION_DENIED"));
+        assert_eq!(
+            error.to_string(),
+            format!("Request failed: POST {}/api/database/rows/table/1234/ (request id req-42) responded with status code 400 Bad Request - You don't have the required permission.", mock_url)
+        );
+        let context = error.request_context().unwrap();
+        assert_eq!(context.method, Some(reqwest::Method::POST));
+        assert_eq!(context.request_id.as_deref(), Some("req-42"));
+
+        mock.assert();
+        fields_mock.assert();
+        create_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_permission_errors() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        let create_mock = server
+            .mock("POST", "/api/database/rows/table/1234/")
+            .with_status(401)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"error": "ERROR_NO_PERMISSION_TO_TABLE", "detail": "The token does not have permissions to the table."}"#)
+            .create();
+        let get_mock = server
+            .mock("GET", "/api/database/rows/table/1234/1/")
+            .with_status(403)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"error": "ERROR_USER_NOT_IN_GROUP", "detail": "The user doesn't belong to the workspace."}"#)
+            .create();
+        let delete_mock = server
+            .mock("DELETE", "/api/database/rows/table/1234/1/")
+            .with_status(401)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"error": "ERROR_TOKEN_DOES_NOT_EXIST", "detail": "The token does not exist."}"#,
+            )
+            .create();
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+
+        let result = baserow
+            .table_by_id(TableId(1234))
+            .create_one(HashMap::new(), None)
+            .await;
+        let error = result.unwrap_err();
+        assert!(matches!(
+            error,
+            BaserowError::TokenScope {
+                permission: "create",
+                ..
+            }
+        ));
+        assert_eq!(error.error_code(), Some("ERROR_NO_PERMISSION_TO_TABLE"));
+
+        let result = baserow
+            .table_by_id(TableId(1234))
+            .get_one::<HashMap<String, Value>>(RowId(1), None)
+            .await;
+        assert!(matches!(result, Err(BaserowError::Forbidden { .. })));
+
+        let result = baserow.table_by_id(TableId(1234)).delete(RowId(1)).await;
+        let error = result.unwrap_err();
+        assert!(matches!(error, BaserowError::Unauthorized { .. }));
+        assert_eq!(error.error_code(), Some("ERROR_TOKEN_DOES_NOT_EXIST"));
+
+        create_mock.assert();
+        get_mock.assert();
+        delete_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_missing_state() {
+        let result = BaserowTable::default()
+            .get_one::<HashMap<String, Value>>(RowId(1), None)
+            .await;
+        assert!(matches!(
+            result,
+            Err(BaserowError::MissingState("Table ID"))
+        ));
+
+        let result = BaserowTable::default()
+            .query()
+            .get::<HashMap<String, Value>>()
+            .await;
+        assert!(matches!(
+            result,
+            Err(BaserowError::MissingState("Baserow instance"))
+        ));
+
+        let configuration = ConfigBuilder::new()
+            .base_url("http://localhost")
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+        let result = baserow
+            .table_by_id(TableId(1234))
+            .query()
+            .size(0)
+            .get::<HashMap<String, Value>>()
+            .await;
+        assert!(matches!(result, Err(BaserowError::InvalidRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_query_too_large() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/api/database/rows/table/1234/")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(RowsResponse::from_rows(Vec::new()).to_json())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&server.url())
+            .api_key("test-token")
+            .max_url_length(200)
+            .try_build()
+            .unwrap();
+        let table = Baserow::with_configuration(configuration).table_by_id(TableId(1234));
+
+        let result = table
+            .clone()
+            .query()
+            .filter_by("field_1", Filter::Equal, "short")
+            .get::<HashMap<String, Value>>()
+            .await;
+        assert!(result.is_ok());
+
+        let result = table
+            .query()
+            .filter_by("field_1", Filter::Equal, &"long".repeat(50))
+            .get::<HashMap<String, Value>>()
+            .await;
+        assert!(matches!(
+            result,
+            Err(BaserowError::QueryTooLarge { length, limit: 200 }) if length > 200
+        ));
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_query_too_large_flattens_filter_tree() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/api/database/rows/table/1234/")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("filter__field_1__equal".into(), "a".into()),
+                mockito::Matcher::UrlEncoded("filter__field_2__equal".into(), "b".into()),
+                mockito::Matcher::UrlEncoded("filter_type".into(), "OR".into()),
+            ]))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(RowsResponse::from_rows(Vec::new()).to_json())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&server.url())
+            .api_key("test-token")
+            .max_url_length(200)
+            .try_build()
+            .unwrap();
+        let table = Baserow::with_configuration(configuration).table_by_id(TableId(1234));
+
+        // Flat trees are sent as separate filter parameters instead of JSON
+        let result = table
+            .clone()
+            .query()
+            .filter(field("field_1").eq("a").or(field("field_2").eq("b")))
+            .get::<HashMap<String, Value>>()
+            .await;
+        assert!(result.is_ok());
+
+        // Nested groups can only be sent as JSON
+        let result = table
+            .query()
+            .filter_by("field_3", Filter::Equal, "c")
+            .filter(field("field_1").eq("a").or(field("field_2").eq("b")))
+            .get::<HashMap<String, Value>>()
+            .await;
+        assert!(matches!(result, Err(BaserowError::QueryTooLarge { .. })));
+
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_flattened() {
+        let request = RowRequestBuilder::new()
+            .filter_by("A", Filter::Equal, "1")
+            .filter(field("B").eq("2").and(field("C").eq("3")))
+            .request;
+        let flattened = request.flattened().unwrap();
+        assert_eq!(flattened.filter_type, Some(FilterType::And));
+        assert_eq!(flattened.filter.unwrap().len(), 3);
+        assert_eq!(flattened.filter_tree, None);
+
+        // OR conditions can only be flattened on their own
+        let request = RowRequestBuilder::new()
+            .filter(field("B").eq("2").or(field("C").eq("3")))
+            .request;
+        assert_eq!(
+            request.flattened().unwrap().filter_type,
+            Some(FilterType::Or)
+        );
+        let request = RowRequestBuilder::new()
+            .filter_by("A", Filter::Equal, "1")
+            .filter(field("B").eq("2").or(field("C").eq("3")))
+            .request;
+        assert_eq!(request.flattened(), None);
+
+        assert_eq!(RowRequest::default().flattened(), None);
+    }
+
+    #[tokio::test]
+    async fn test_update_field_errors() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        let fields_mock = server
+            .mock("GET", "/api/database/fields/table/1234/")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"[
+                {"id": 1, "table_id": 1234, "name": "name", "order": 0, "type": "text", "primary": true, "read_only": false},
+                {"id": 2, "table_id": 1234, "name": "age", "order": 1, "type": "number", "primary": false, "read_only": false}
+            ]"#)
+            .create();
+
+        let update_mock = server
+            .mock("PATCH", "/api/database/rows/table/1234/1/")
+            .with_status(400)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{
+                "error": "ERROR_REQUEST_BODY_VALIDATION",
+                "detail": {
+                    "field_2": [{"error": "Ensure that there are no more than 3 digits in total.", "code": "max_digits"}],
+                    "field_9": [{"error": "Unknown field.", "code": "invalid"}]
+                }
+            }"#)
+            .create();
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+        let table = baserow.table_by_id(TableId(1234)).auto_map().await.unwrap();
+
+        let result = table
+            .update(
+                RowId(1),
+                HashMap::from([("age".to_string(), Value::from(1000))]),
+                None,
+            )
+            .await;
+        match result {
+            Err(BaserowError::InvalidFieldValues(fields)) => {
+                assert_eq!(
+                    fields,
+                    HashMap::from([
+                        (
+                            "age".to_string(),
+                            vec![
+                                "Ensure that there are no more than 3 digits in total.".to_string()
+                            ]
+                        ),
+                        ("field_9".to_string(), vec!["Unknown field.".to_string()]),
+                    ])
+                );
+            }
+            result => panic!("Expected invalid field values, got {:?}", result),
+        }
+
+        fields_mock.assert();
+        update_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_create_one_validates_before_request() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        let fields_mock = server
+            .mock("GET", "/api/database/fields/table/1234/")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"[
+                {"id": 1, "table_id": 1234, "name": "name", "order": 0, "type": "text", "primary": true, "read_only": false},
+                {"id": 2, "table_id": 1234, "name": "status", "order": 1, "type": "single_select", "primary": false, "read_only": false,
+                 "select_options": [{"id": 10, "value": "Open", "color": "blue"}]}
+            ]"#)
+            .expect(2)
+            .create();
+
+        let rows_mock = server
+            .mock("POST", "/api/database/rows/table/1234/")
+            .expect(0)
+            .create();
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+        let table = baserow
+            .table_by_id(TableId(1234))
+            .auto_map()
+            .await
+            .unwrap()
+            .validate_writes();
+
+        let row = HashMap::from([
+            ("name".to_string(), Value::Null),
+            ("status".to_string(), Value::String("Closed".to_string())),
+        ]);
+        match table.create_one(row, None).await {
+            Err(BaserowError::ValidationError(validation)) => {
+                assert_eq!(validation.errors.len(), 1)
+            }
+            result => panic!("Expected a validation error, got {:?}", result),
+        }
+
+        // Without validate_writes, Baserow is left to validate the row
+        let update_mock = server
+            .mock("PATCH", "/api/database/rows/table/1234/1/")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": 1, "field_1": "", "field_2": null}"#)
+            .create();
+        let table = baserow.table_by_id(TableId(1234)).auto_map().await.unwrap();
+        let row = HashMap::from([("status".to_string(), Value::String("Closed".to_string()))]);
+        assert!(table.update(RowId(1), row, None).await.is_ok());
+
+        fields_mock.assert();
+        rows_mock.assert();
+        update_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_include_metadata() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        let rows_mock = server
+            .mock("GET", "/api/database/rows/table/1234/")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "include".into(),
+                "metadata".into(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"count": 1, "next": null, "previous": null, "results": [
+                {"id": 1, "name": "John", "created_on": "2024-01-01T10:00:00Z", "updated_on": "2024-02-01T12:30:00Z"}
+            ]}"#)
+            .expect(2)
+            .create();
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+
+        let rows = baserow
+            .table_by_id(TableId(1234))
+            .query()
+            .include_metadata()
+            .get::<Row<TestUser>>()
+            .await
+            .unwrap();
+        assert_eq!(rows.results[0].fields.name, "John");
+        assert_eq!(
+            rows.results[0].metadata.updated_on.as_deref(),
+            Some("2024-02-01T12:30:00Z")
+        );
+
+        let rows = baserow
+            .table_by_id(TableId(1234))
+            .query()
+            .include_metadata()
+            .get::<HashMap<String, Value>>()
+            .await
+            .unwrap();
+        assert_eq!(
+            RowMetadata::from_row(&rows.results[0]),
+            RowMetadata {
+                created_on: Some("2024-01-01T10:00:00Z".to_string()),
+                updated_on: Some("2024-02-01T12:30:00Z".to_string()),
+            }
+        );
+
+        rows_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_stream() {
+        let mut server = mockito::Server::new_async().await;
+        let page_1 = server
+            .mock("GET", "/api/database/rows/table/1234/")
+            .match_query(mockito::Matcher::UrlEncoded("page".into(), "1".into()))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"count": 3, "next": "http://example.com/?page=2", "previous": null, "results": [{"id": 1}, {"id": 2}]}"#)
+            .create_async()
+            .await;
+        let page_2 = server
+            .mock("GET", "/api/database/rows/table/1234/")
+            .match_query(mockito::Matcher::UrlEncoded("page".into(), "2".into()))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"count": 3, "next": null, "previous": null, "results": [{"id": 3}]}"#)
+            .create_async()
+            .await;
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&server.url())
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let query = Baserow::with_configuration(configuration)
+            .table_by_id(TableId(1234))
+            .query()
+            .size(2)
+            .page(5);
+
+        let rows: Vec<HashMap<String, Value>> = query.stream().try_collect().await.unwrap();
+        let ids: Vec<&Value> = rows.iter().map(|row| &row["id"]).collect();
+        assert_eq!(ids, vec![&json!(1), &json!(2), &json!(3)]);
+
+        page_1.assert_async().await;
+        page_2.assert_async().await;
+    }
+
+    #[test]
+    fn test_fields_with_metadata() {
+        let table = BaserowTable::default().with_id(TableId(1234));
+        let request = RowRequest {
+            fields: Some(vec!["Name".to_string(), "Email".to_string()]),
+            include_metadata: true,
+            ..Default::default()
+        };
+
+        let params = table.query_params(&request).unwrap();
+        let include: Vec<&(String, String)> =
+            params.iter().filter(|(key, _)| key == "include").collect();
+        assert_eq!(
+            include,
+            vec![&("include".to_string(), "Name,Email,metadata".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_row_cache() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        let get_mock = server
+            .mock("GET", "/api/database/rows/table/1234/1/")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": 1, "Name": "Widget"}"#)
+            .expect(2)
+            .create();
+        let update_mock = server
+            .mock("PATCH", "/api/database/rows/table/1234/1/")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": 1, "Name": "Gadget"}"#)
+            .create();
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration)
+            .with_row_cache(10, std::time::Duration::from_secs(60));
+        let table = baserow.table_by_id(TableId(1234));
+
+        // The second lookup is served from the cache
+        for _ in 0..2 {
+            let row: HashMap<String, Value> = table.get_one(RowId(1), None).await.unwrap();
+            assert_eq!(row["Name"], "Widget");
+        }
+
+        // Updates replace the cached row with the updated one
+        let mut data = HashMap::new();
+        data.insert("Name".to_string(), Value::from("Gadget"));
+        table.update(RowId(1), data, None).await.unwrap();
+        let row: HashMap<String, Value> = table.get_one(RowId(1), None).await.unwrap();
+        assert_eq!(row["Name"], "Gadget");
+
+        // Handles with other credentials don't see the cached rows
+        let other = baserow
+            .with_database_token("other-token")
+            .table_by_id(TableId(1234));
+        let row: HashMap<String, Value> = other.get_one(RowId(1), None).await.unwrap();
+        assert_eq!(row["Name"], "Widget");
+
+        get_mock.assert();
+        update_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_get_many() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        let mut mocks = Vec::new();
+        for id in [1, 2] {
+            mocks.push(
+                server
+                    .mock(
+                        "GET",
+                        format!("/api/database/rows/table/1234/{}/", id).as_str(),
+                    )
+                    .with_status(200)
+                    .with_header("Content-Type", "application/json")
+                    .with_body(format!(r#"{{"id": {}}}"#, id))
+                    .create(),
+            );
+        }
+        mocks.push(
+            server
+                .mock("GET", "/api/database/rows/table/1234/3/")
+                .with_status(404)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"{"error": "ERROR_ROW_DOES_NOT_EXIST", "detail": "The row 3 does not exist."}"#)
+                .create(),
+        );
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+
+        let rows = baserow
+            .table_by_id(TableId(1234))
+            .get_many::<HashMap<String, Value>>(&[RowId(2), RowId(3), RowId(1)], 2, None)
+            .await;
+
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].as_ref().unwrap()["id"], 2);
+        assert!(matches!(
+            rows[1],
+            Err(BaserowError::RowNotFound {
+                row_id: RowId(3),
+                ..
+            })
+        ));
+        assert_eq!(rows[2].as_ref().unwrap()["id"], 1);
+
+        for mock in mocks {
+            mock.assert();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_table_defaults() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        let query_mock = server
+            .mock("GET", "/api/database/rows/table/1234/")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("user_field_names".into(), "true".into()),
+                mockito::Matcher::UrlEncoded("size".into(), "200".into()),
+            ]))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"count": 0, "next": null, "previous": null, "results": []}"#)
+            .create();
+        let create_mock = server
+            .mock("POST", "/api/database/rows/table/1234/")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "user_field_names".into(),
+                "true".into(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": 1, "Name": "A"}"#)
+            .create();
+        let get_mock = server
+            .mock("GET", "/api/database/rows/table/1234/1/")
+            .match_query(mockito::Matcher::Missing)
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": 1, "field_1": "A"}"#)
+            .create();
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let table = Baserow::with_configuration(configuration)
+            .table_by_id(TableId(1234))
+            .with_user_field_names(true)
+            .with_default_page_size(200);
+
+        table
+            .clone()
+            .query()
+            .get::<HashMap<String, Value>>()
+            .await
+            .unwrap();
+        let row = HashMap::from([("Name".to_string(), Value::from("A"))]);
+        table.create_one(row, None).await.unwrap();
+        // Arguments given per call take precedence
+        table
+            .get_one::<HashMap<String, Value>>(RowId(1), Some(false))
+            .await
+            .unwrap();
+
+        query_mock.assert();
+        create_mock.assert();
+        get_mock.assert();
+    }
+
+    #[test]
+    fn test_row_request_serialization() {
+        let request = RowRequestBuilder::new()
+            .view(ViewId(5678))
+            .filter_by("Status", Filter::SingleSelectEqual, "Open")
+            .order_by("Name", OrderDirection::Desc)
+            .size(50)
+            .request;
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["view"], json!({"table": 5678}));
+        assert_eq!(
+            json["filter"][0]["filter"],
+            Filter::SingleSelectEqual.as_str()
+        );
+        assert_eq!(json["order"], json!([["Name", "desc"]]));
+        assert_eq!(serde_json::from_value::<RowRequest>(json).unwrap(), request);
+
+        // Requests persisted with the order as map or a view ID can still be read
+        let legacy: RowRequest =
+            serde_json::from_value(json!({"view_id": 5678, "order": {"Name": "desc"}})).unwrap();
+        assert_eq!(legacy.view, request.view);
+        assert_eq!(legacy.order, request.order);
+
+        let partial: RowRequest = serde_json::from_str(r#"{"page": 3}"#).unwrap();
+        assert_eq!(partial.page, Some(3));
+        assert_eq!(partial.page_size, RowRequest::default().page_size);
+    }
+
+    #[test]
+    fn test_to_query_params() {
+        let mut mapper = TableMapper::new();
+        mapper.map_fields(
+            serde_json::from_value(json!([
+                {"id": 1, "table_id": 1234, "name": "Name", "order": 0, "type": "text", "primary": true, "read_only": false},
+                {"id": 2, "table_id": 1234, "name": "Age", "order": 1, "type": "number", "primary": false, "read_only": false}
+            ]))
+            .unwrap(),
+        );
+        let mut table = BaserowTable::default().with_id(TableId(1234));
+        table.mapper = Some(Arc::new(mapper));
+
+        let query = table
+            .clone()
+            .query()
+            .view(ViewId(7))
+            .order_by("Name", OrderDirection::Asc)
+            .order_by("Age", OrderDirection::Desc)
+            .filter_by("Age", Filter::HigherThan, "18")
+            .filter_by("Unknown", Filter::Empty, "")
+            .include_metadata();
+        let params = query.to_query_params().unwrap();
+        let params: Vec<(&str, &str)> = params
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect();
+        assert_eq!(
+            params,
+            vec![
+                ("view_id", "7"),
+                ("order_by", "field_1,-field_2"),
+                ("filter__field_2__higher_than", "18"),
+                ("filter__Unknown__empty", ""),
+                ("size", "100"),
+                ("page", "1"),
+                ("include", "metadata"),
+            ]
+        );
+
+        let params = query
+            .clone()
+            .filter_type(FilterType::Or)
+            .to_query_params()
+            .unwrap();
+        assert_eq!(
+            params[2..5],
+            [
+                ("filter__field_2__higher_than".to_string(), "18".to_string()),
+                ("filter__Unknown__empty".to_string(), String::new()),
+                ("filter_type".to_string(), "OR".to_string()),
+            ]
+        );
+
+        let params = query.user_field_names(true).to_query_params().unwrap();
+        assert!(params.contains(&("order_by".to_string(), "Name,-Age".to_string())));
+        assert!(params.contains(&("user_field_names".to_string(), "true".to_string())));
+
+        let params = table
+            .query()
+            .filter_by("Name", Filter::Equal, "Ada")
+            .filter(field("Age").gt(18).or(field("field_3").empty()))
+            .to_query_params()
+            .unwrap();
+        assert!(!params.iter().any(|(key, _)| key.starts_with("filter__")));
+        let filters = params.iter().find(|(key, _)| key == "filters").unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(&filters.1).unwrap(),
+            json!({
+                "filter_type": "AND",
+                "filters": [{"field": 1, "type": "equal", "value": "Ada"}],
+                "groups": [{
+                    "filter_type": "OR",
+                    "filters": [
+                        {"field": 2, "type": "higher_than", "value": "18"},
+                        {"field": 3, "type": "empty", "value": ""},
+                    ],
+                    "groups": [],
+                }],
+            })
+        );
+
+        let error = RowRequestBuilder::new().size(0).to_query_params();
+        assert!(matches!(error, Err(BaserowError::InvalidRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_view_sources() {
+        use crate::{
+            api::view::ViewSource,
+            fixtures::{fields_json, row},
+            TableField,
+        };
+
+        let mut server = mockito::Server::new_async().await;
+        let fields_mock = server
+            .mock("GET", "/api/database/fields/table/1234/")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(fields_json(&[
+                TableField::text("Name", 1).as_primary(),
+                TableField::number("Age", 2, 0),
+            ]))
+            .create_async()
+            .await;
+        let rows = RowsResponse::from_rows(vec![row(
+            1,
+            [("field_1", json!("Ada")), ("field_2", json!("36"))],
+        )])
+        .to_json();
+        let grid_mock = server
+            .mock("GET", "/api/database/views/grid/7/")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("filter__field_2__higher_than".into(), "18".into()),
+                mockito::Matcher::UrlEncoded("size".into(), "100".into()),
+                mockito::Matcher::UrlEncoded("page".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(&rows)
+            .create_async()
+            .await;
+        let gallery_mock = server
+            .mock(
+                "GET",
+                "/api/database/views/gallery/shared-slug/public/rows/",
+            )
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("limit".into(), "20".into()),
+                mockito::Matcher::UrlEncoded("offset".into(), "40".into()),
+            ]))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(&rows)
+            .create_async()
+            .await;
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&server.url())
+            .access_token("test-jwt")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+        let table = baserow.table_by_id(TableId(1234)).auto_map().await.unwrap();
+
+        // Rows of views are keyed by field IDs and converted with the mapping
+        let grid = table
+            .clone()
+            .query()
+            .view(ViewSource::grid(ViewId(7)))
+            .user_field_names(true)
+            .filter_by("Age", Filter::HigherThan, "18")
+            .get::<HashMap<String, Value>>()
+            .await
+            .unwrap();
+        assert_eq!(grid.results[0]["Name"], "Ada");
+
+        let gallery = table
+            .clone()
+            .query()
+            .view(ViewSource::public_gallery("shared-slug"))
+            .field_names(FieldNamesMode::Ids)
+            .size(20)
+            .page(3)
+            .get::<HashMap<String, Value>>()
+            .await
+            .unwrap();
+        assert_eq!(gallery.results[0]["field_1"], "Ada");
+
+        // Without a mapping, names cannot be resolved for views
+        let error = baserow
+            .table_by_id(TableId(1234))
+            .query()
+            .view(ViewSource::grid(ViewId(7)))
+            .user_field_names(true)
+            .get::<HashMap<String, Value>>()
+            .await
+            .unwrap_err();
+        assert!(matches!(error, BaserowError::MissingState("Table mapper")));
+
+        fields_mock.assert_async().await;
+        grid_mock.assert_async().await;
+        gallery_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_row_ids() {
+        use crate::{
+            api::view::ViewSource,
+            fixtures::{fields_json, row},
+            TableField,
+        };
+
+        let mut server = mockito::Server::new_async().await;
+        let fields_mock = server
+            .mock("GET", "/api/database/fields/table/1234/")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(fields_json(&[
+                TableField::text("Name", 1).as_primary(),
+                TableField::number("Age", 2, 0),
+            ]))
+            .create_async()
+            .await;
+        let ids: Vec<RowId> = (1..=2000).map(RowId).collect();
+        let rows = vec![
+            row(1, [("field_1", json!("Ada"))]),
+            row(2, [("field_1", json!("Grace"))]),
+        ];
+        let grid_mock = server
+            .mock("POST", "/api/database/views/grid/7/")
+            .match_query(mockito::Matcher::Missing)
+            .match_body(mockito::Matcher::Json(
+                json!({"row_ids": ids, "field_ids": [1]}),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(json!(rows).to_string())
+            .create_async()
+            .await;
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&server.url())
+            .access_token("test-jwt")
+            .max_url_length(200)
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+        let table = baserow.table_by_id(TableId(1234)).auto_map().await.unwrap();
+
+        let query = table
+            .clone()
+            .query()
+            .view(ViewSource::grid(ViewId(7)))
+            .row_ids(&ids)
+            .fields(&["Name"]);
+        let response = query.get::<HashMap<String, Value>>().await.unwrap();
+        assert_eq!(response.count, Some(2));
+        assert_eq!(response.next, None);
+        assert_eq!(response.results[1]["Name"], "Grace");
+
+        // Only grid views accept row IDs, and only unfiltered
+        for query in [
+            table.clone().query().row_ids(&ids),
+            table
+                .clone()
+                .query()
+                .view(ViewSource::gallery(ViewId(8)))
+                .row_ids(&ids),
+            query.clone().filter_by("Age", Filter::HigherThan, "18"),
+            query.clone().page(2),
+        ] {
+            let error = query.get::<HashMap<String, Value>>().await.unwrap_err();
+            assert!(matches!(error, BaserowError::InvalidRequest(_)));
+        }
+
+        fields_mock.assert_async().await;
+        grid_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_reusable_query() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        let body = r#"{"count": 0, "next": null, "previous": null, "results": []}"#;
+        let mut mocks = Vec::new();
+        for (table_id, page) in [(1234, 1), (1234, 2), (5678, 1)] {
+            mocks.push(
+                server
+                    .mock(
+                        "GET",
+                        format!("/api/database/rows/table/{}/", table_id).as_str(),
+                    )
+                    .match_query(mockito::Matcher::AllOf(vec![
+                        mockito::Matcher::UrlEncoded("filter__Status__equal".into(), "Open".into()),
+                        mockito::Matcher::UrlEncoded("page".into(), page.to_string()),
+                    ]))
+                    .with_status(200)
+                    .with_header("Content-Type", "application/json")
+                    .with_body(body)
+                    .expect(1)
+                    .create(),
+            );
+        }
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+        let table = baserow.table_by_id(TableId(1234));
+
+        let open = table
+            .clone()
+            .query()
+            .filter_by("Status", Filter::Equal, "Open");
+        open.get::<HashMap<String, Value>>().await.unwrap();
+        open.clone()
+            .page(2)
+            .get::<HashMap<String, Value>>()
+            .await
+            .unwrap();
+        open.get_from::<HashMap<String, Value>>(&baserow.table_by_id(TableId(5678)))
+            .await
+            .unwrap();
+
+        let unbound = RowRequestBuilder::new().filter_by("Status", Filter::Equal, "Open");
+        assert!(matches!(
+            unbound.get::<HashMap<String, Value>>().await,
+            Err(BaserowError::MissingState("Table"))
+        ));
+        assert!(matches!(
+            unbound
+                .get_from::<HashMap<String, Value>>(&BaserowTable::default())
+                .await,
+            Err(BaserowError::MissingState("Baserow instance"))
+        ));
+
+        for mock in mocks {
+            mock.assert();
+        }
+    }
+
     #[tokio::test]
     async fn test_struct_deserialization_with_both_options() {
         let mut server = mockito::Server::new_async().await;
@@ -942,16 +3263,42 @@ mod tests {
             )
             .create();
 
+        // Mock the single row endpoint for both tests
+        let row_mock_auto_map = server
+            .mock("GET", "/api/database/rows/table/1234/1/")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": 1, "field_1": "John"}"#)
+            .expect(1)
+            .create();
+        let row_mock_user_names = server
+            .mock("GET", "/api/database/rows/table/1234/1/")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "user_field_names".into(),
+                "true".into(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": 1, "name": "John"}"#)
+            .expect(1)
+            .create();
+
         let configuration = ConfigBuilder::new()
             .base_url(&mock_url)
             .api_key("test-token")
-            .build();
+            .try_build()
+            .unwrap();
         let baserow = Baserow::with_configuration(configuration);
-        let table = baserow.table_by_id(1234);
+        let table = baserow.table_by_id(TableId(1234));
 
         // Test auto_map deserialization
         let mapped_table = table.clone().auto_map().await.unwrap();
-        let auto_map_result = mapped_table.query().get::<TestUser>().await.unwrap();
+        let auto_map_result = mapped_table
+            .clone()
+            .query()
+            .get::<TestUser>()
+            .await
+            .unwrap();
 
         assert_eq!(
             auto_map_result.results[0],
@@ -962,6 +3309,7 @@ mod tests {
 
         // Test user_field_names deserialization
         let user_names_result = table
+            .clone()
             .query()
             .user_field_names(true)
             .get::<TestUser>()
@@ -975,9 +3323,160 @@ mod tests {
             }
         );
 
+        // Single rows deserialize the same way
+        let john = TestUser {
+            name: "John".to_string(),
+        };
+        assert_eq!(
+            mapped_table
+                .get_one::<TestUser>(RowId(1), None)
+                .await
+                .unwrap(),
+            john
+        );
+        assert_eq!(
+            table
+                .get_one::<TestUser>(RowId(1), Some(true))
+                .await
+                .unwrap(),
+            john
+        );
+
         // Verify the mocks were called the expected number of times
         fields_mock.assert();
         rows_mock_auto_map.assert();
         rows_mock_user_names.assert();
+        row_mock_auto_map.assert();
+        row_mock_user_names.assert();
+    }
+
+    mockall::mock! {
+        Client {}
+
+        #[async_trait]
+        impl BaserowClient for Client {
+            async fn token_auth(&self) -> Result<Box<dyn BaserowClient>, crate::error::TokenAuthError>;
+            async fn refresh_token(&self) -> Result<(), crate::error::TokenAuthError>;
+            async fn reauthenticate(&self) -> Result<(), crate::error::TokenAuthError>;
+            async fn table_fields(&self, table_id: TableId) -> Result<Vec<crate::TableField>, BaserowError>;
+            fn table_by_id(&self, id: TableId) -> BaserowTable;
+            async fn get_table(&self, id: TableId) -> Result<BaserowTable, BaserowError>;
+            async fn upload_file(
+                &self,
+                file: std::fs::File,
+                filename: String,
+            ) -> Result<crate::api::file::File, crate::error::FileUploadError>;
+            async fn upload_file_with_options(
+                &self,
+                file: std::fs::File,
+                filename: String,
+                options: crate::api::file::UploadFileOptions,
+            ) -> Result<crate::api::file::File, crate::error::FileUploadError>;
+            async fn upload_file_via_url(&self, url: &str) -> Result<crate::api::file::File, crate::error::FileUploadError>;
+            async fn upload_file_via_url_with_options(
+                &self,
+                url: &str,
+                options: crate::api::file::UploadViaUrlOptions,
+            ) -> Result<crate::api::file::File, crate::error::FileUploadError>;
+            async fn auth_header(&self) -> crate::api::authentication::AuthHeader;
+            fn get_configuration(&self) -> crate::Configuration;
+            fn get_client(&self) -> reqwest::Client;
+        }
+    }
+
+    mockall::mock! {
+        Table {}
+
+        #[async_trait]
+        impl BaserowTableOperations for Table {
+            async fn auto_map(self) -> Result<BaserowTable, BaserowError>;
+            fn query(self) -> RowRequestBuilder;
+            async fn refresh(self) -> Result<BaserowTable, BaserowError>;
+            async fn get_rows(&self, baserow: Baserow, request: RowRequest) -> Result<RowsResponse, BaserowError>;
+            async fn get_row(
+                &self,
+                id: RowId,
+                user_field_names: Option<bool>,
+            ) -> Result<HashMap<String, Value>, BaserowError>;
+            async fn create_one(
+                &self,
+                data: HashMap<String, Value>,
+                user_field_names: Option<bool>,
+            ) -> Result<HashMap<String, Value>, BaserowError>;
+            async fn update(
+                &self,
+                id: RowId,
+                data: HashMap<String, Value>,
+                user_field_names: Option<bool>,
+            ) -> Result<HashMap<String, Value>, BaserowError>;
+            async fn delete(&self, id: RowId) -> Result<(), BaserowError>;
+            async fn create_many(
+                &self,
+                rows: Vec<HashMap<String, Value>>,
+                user_field_names: Option<bool>,
+            ) -> Result<BatchResult<HashMap<String, Value>>, BaserowError>;
+            async fn update_many(
+                &self,
+                rows: Vec<(RowId, HashMap<String, Value>)>,
+                user_field_names: Option<bool>,
+            ) -> Result<BatchResult<HashMap<String, Value>>, BaserowError>;
+            async fn delete_many(&self, ids: Vec<RowId>) -> Result<BatchResult<RowId>, BaserowError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mock_traits() {
+        #[cfg(feature = "codegen")]
+        {
+            let mut client = MockClient::new();
+            client
+                .expect_table_fields()
+                .withf(|table_id| *table_id == TableId(1234))
+                .returning(|_| Ok(Vec::new()));
+            let code = crate::codegen::generate_table_struct(
+                &client,
+                TableId(1234),
+                "Row",
+                FieldNamesMode::UserNames,
+            )
+            .await
+            .unwrap();
+            assert!(code.contains("pub struct Row {"));
+        }
+
+        let mut table = MockTable::new();
+        table
+            .expect_get_row()
+            .withf(|id, _| *id == RowId(1))
+            .returning(|_, _| Ok(HashMap::from([("name".to_string(), Value::from("Ada"))])));
+        table.expect_get_rows().returning(|_, _| {
+            Ok(RowsResponse {
+                count: Some(1),
+                next: None,
+                previous: None,
+                results: vec![HashMap::from([("name".to_string(), Value::from("Grace"))])],
+            })
+        });
+
+        // Typed access works on mocks, also behind a trait object
+        let table: &dyn BaserowTableOperations = &table;
+        let user: TestUser = table.get_one(RowId(1), None).await.unwrap();
+        assert_eq!(user.name, "Ada");
+
+        let config = ConfigBuilder::new()
+            .base_url("http://localhost")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(config);
+        let users = table
+            .get::<TestUser>(baserow, RowRequest::default())
+            .await
+            .unwrap();
+        assert_eq!(
+            users.results,
+            vec![TestUser {
+                name: "Grace".into()
+            }]
+        );
     }
 }
@@ -0,0 +1,445 @@
+use async_trait::async_trait;
+use reqwest::{Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, instrument, warn};
+
+use crate::{
+    api::{
+        authentication::{TokenAuthErrorResponse, TokenVerifyRequest},
+        client::BaserowClient,
+    },
+    error::{TokenAuthError, UserAccountError},
+    secret::SecretString,
+};
+
+/// Request body for sending a password reset email
+#[derive(Serialize, Debug)]
+pub struct SendPasswordResetEmailRequest {
+    pub email: String,
+    pub base_url: String,
+}
+
+/// Request body for resetting a password with a token from a reset email
+#[derive(Serialize, Debug)]
+pub struct ResetPasswordRequest {
+    pub token: SecretString,
+    pub password: SecretString,
+}
+
+/// Request body for changing the password of the authenticated user
+#[derive(Serialize, Debug)]
+pub struct ChangePasswordRequest {
+    pub old_password: SecretString,
+    pub new_password: SecretString,
+}
+
+/// Request body for updating the account settings of the authenticated user
+///
+/// Only the fields that are set are sent to Baserow.
+///
+/// # Example
+/// ```
+/// use baserow_rs::api::user::UpdateAccountRequest;
+///
+/// let request = UpdateAccountRequest::new().first_name("Ada").language("de");
+/// ```
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct UpdateAccountRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_notification_frequency: Option<String>,
+}
+
+impl UpdateAccountRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the first name of the user
+    pub fn first_name(mut self, first_name: &str) -> Self {
+        self.first_name = Some(first_name.to_string());
+        self
+    }
+
+    /// Set the interface language of the user, e.g. `en` or `de`
+    pub fn language(mut self, language: &str) -> Self {
+        self.language = Some(language.to_string());
+        self
+    }
+
+    /// Set how often notification emails are sent: `instant`, `daily`, `weekly` or `never`
+    pub fn email_notification_frequency(mut self, frequency: &str) -> Self {
+        self.email_notification_frequency = Some(frequency.to_string());
+        self
+    }
+}
+
+/// Account settings of the authenticated user as returned by Baserow
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Account {
+    pub first_name: String,
+    pub language: String,
+    pub email_notification_frequency: Option<String>,
+}
+
+/// Converts an unsuccessful response into the matching account error
+pub(crate) async fn account_error(resp: Response) -> UserAccountError {
+    let status = resp.status();
+    let error_text = match resp.text().await {
+        Ok(text) => text,
+        Err(e) => return UserAccountError::NetworkError(e),
+    };
+
+    let error = match serde_json::from_str::<TokenAuthErrorResponse>(&error_text) {
+        Ok(response) => match response.error.as_str() {
+            "BAD_TOKEN_SIGNATURE" | "EXPIRED_TOKEN_SIGNATURE" => UserAccountError::InvalidToken,
+            "ERROR_INVALID_OLD_PASSWORD" => UserAccountError::InvalidOldPassword,
+            _ => UserAccountError::RequestFailed(status, error_text),
+        },
+        Err(_) => UserAccountError::RequestFailed(status, error_text),
+    };
+    error.log();
+    error
+}
+
+/// Trait defining the account management operations available on a Baserow client
+///
+/// The trait is implemented for every [`BaserowClient`], including the client
+/// returned by `token_auth()`.
+#[async_trait]
+pub trait BaserowUserOperations: BaserowClient {
+    /// Sends an email with a password reset link to the given address
+    ///
+    /// Baserow responds successfully even if no account exists for the address.
+    ///
+    /// # Arguments
+    /// * `email` - The email address of the account
+    /// * `base_url` - The frontend URL the reset link points to, e.g. `https://baserow.example.com/reset-password`
+    #[instrument(skip(self, email), err)]
+    async fn send_password_reset_email(
+        &self,
+        email: &str,
+        base_url: &str,
+    ) -> Result<(), UserAccountError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url("api/user/send-reset-password-email/");
+
+        let req = client.post(url).json(&SendPasswordResetEmailRequest {
+            email: email.to_string(),
+            base_url: base_url.to_string(),
+        });
+
+        debug!("Sending password reset email request");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK | StatusCode::NO_CONTENT => {
+                info!("Password reset email requested");
+                Ok(())
+            }
+            _ => Err(account_error(resp).await),
+        }
+    }
+
+    /// Sets a new password using the token from a password reset email
+    ///
+    /// # Arguments
+    /// * `token` - The token contained in the reset link
+    /// * `password` - The new password
+    #[instrument(skip(self, token, password), err)]
+    async fn reset_password(&self, token: &str, password: &str) -> Result<(), UserAccountError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url("api/user/reset-password/");
+
+        let req = client.post(url).json(&ResetPasswordRequest {
+            token: token.into(),
+            password: password.into(),
+        });
+
+        debug!("Sending password reset request");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK | StatusCode::NO_CONTENT => {
+                info!("Password reset successful");
+                Ok(())
+            }
+            _ => Err(account_error(resp).await),
+        }
+    }
+
+    /// Checks whether a refresh token is still valid
+    ///
+    /// This is a cheap way to find out whether a stored session can be reused
+    /// before starting work, without performing a full login. Baserow's
+    /// `token-verify` endpoint only accepts refresh tokens; it says nothing
+    /// about the access token, which may have expired while the refresh token
+    /// is still valid. Use `refresh_token()` to obtain a fresh access token.
+    ///
+    /// # Arguments
+    /// * `refresh_token` - The refresh token obtained by `token_auth()`
+    ///
+    /// # Returns
+    /// `true` if the token can still be used, `false` if it is invalid or expired
+    #[instrument(skip(self, refresh_token), err)]
+    async fn verify_refresh_token(&self, refresh_token: &str) -> Result<bool, TokenAuthError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url("api/user/token-verify/");
+
+        let req = client.post(url).json(&TokenVerifyRequest {
+            refresh_token: refresh_token.into(),
+        });
+
+        debug!("Sending refresh token verification request");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                info!("Refresh token is valid");
+                Ok(true)
+            }
+            StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED => {
+                warn!("Refresh token is invalid or expired");
+                Ok(false)
+            }
+            _status => {
+                let error = TokenAuthError::AuthenticationFailed(resp.text().await?);
+                error.log();
+                Err(error)
+            }
+        }
+    }
+
+    /// Updates the account settings of the authenticated user
+    ///
+    /// Requires JWT authentication via `token_auth()`. The user returned by the
+    /// client's configuration reflects the changes afterwards.
+    #[instrument(skip(self, request), err)]
+    async fn update_account(
+        &self,
+        request: UpdateAccountRequest,
+    ) -> Result<Account, UserAccountError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url("api/user/account/");
+
+        let auth = self.auth_header().await;
+        if auth.jwt().is_none() {
+            let error = UserAccountError::MissingCredentials("JWT");
+            error.log();
+            return Err(error);
+        }
+        let req = auth.apply(client.patch(url)).json(&request);
+
+        debug!("Sending account update request");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let account: Account = resp.json().await?;
+                info!("Account update successful");
+                self.apply_account(&account);
+                Ok(account)
+            }
+            _ => Err(account_error(resp).await),
+        }
+    }
+
+    /// Changes the password of the authenticated user
+    ///
+    /// Requires JWT authentication via `token_auth()`.
+    ///
+    /// # Arguments
+    /// * `old_password` - The current password
+    /// * `new_password` - The new password
+    #[instrument(skip(self, old_password, new_password), err)]
+    async fn change_password(
+        &self,
+        old_password: &str,
+        new_password: &str,
+    ) -> Result<(), UserAccountError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url("api/user/change-password/");
+
+        let auth = self.auth_header().await;
+        if auth.jwt().is_none() {
+            let error = UserAccountError::MissingCredentials("JWT");
+            error.log();
+            return Err(error);
+        }
+        let req = auth.apply(client.post(url)).json(&ChangePasswordRequest {
+            old_password: old_password.into(),
+            new_password: new_password.into(),
+        });
+
+        debug!("Sending change password request");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK | StatusCode::NO_CONTENT => {
+                info!("Password changed successfully");
+                Ok(())
+            }
+            _ => Err(account_error(resp).await),
+        }
+    }
+}
+
+impl<T: BaserowClient + ?Sized> BaserowUserOperations for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Baserow, ConfigBuilder};
+
+    #[tokio::test]
+    async fn test_send_password_reset_email() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        let mock = server
+            .mock("POST", "/api/user/send-reset-password-email/")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "email": "user@example.com",
+                "base_url": "https://baserow.example.com/reset-password"
+            })))
+            .with_status(204)
+            .create();
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+
+        let result = baserow
+            .send_password_reset_email(
+                "user@example.com",
+                "https://baserow.example.com/reset-password",
+            )
+            .await;
+        assert!(result.is_ok());
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_change_password_with_wrong_old_password() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        let mock = server
+            .mock("POST", "/api/user/change-password/")
+            .match_header("Authorization", "JWT jwt-token")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "old_password": "wrong",
+                "new_password": "new-password"
+            })))
+            .with_status(400)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"error": "ERROR_INVALID_OLD_PASSWORD", "detail": "The entered old password is incorrect."}"#)
+            .create();
+
+        let mut configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .try_build()
+            .unwrap();
+        configuration.jwt = Some("jwt-token".into());
+        let baserow = Baserow::with_configuration(configuration);
+
+        let result = baserow.change_password("wrong", "new-password").await;
+        assert!(matches!(result, Err(UserAccountError::InvalidOldPassword)));
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_change_password_requires_jwt() {
+        let configuration = ConfigBuilder::new()
+            .base_url("https://api.baserow.io")
+            .api_key("123")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+
+        let result = baserow.change_password("old", "new").await;
+        assert!(matches!(
+            result,
+            Err(UserAccountError::MissingCredentials("JWT"))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_verify_refresh_token() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        let valid_mock = server
+            .mock("POST", "/api/user/token-verify/")
+            .match_body(mockito::Matcher::Json(
+                serde_json::json!({"refresh_token": "valid"}),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"user": {"first_name": "Ada", "username": "ada@example.com", "language": "en"}}"#)
+            .create();
+
+        let invalid_mock = server
+            .mock("POST", "/api/user/token-verify/")
+            .match_body(mockito::Matcher::Json(
+                serde_json::json!({"refresh_token": "expired"}),
+            ))
+            .with_status(401)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"error": "ERROR_INVALID_REFRESH_TOKEN"}"#)
+            .create();
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+
+        assert!(baserow.verify_refresh_token("valid").await.unwrap());
+        assert!(!baserow.verify_refresh_token("expired").await.unwrap());
+
+        valid_mock.assert();
+        invalid_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_reset_password_with_expired_token() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        let mock = server
+            .mock("POST", "/api/user/reset-password/")
+            .with_status(400)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"error": "EXPIRED_TOKEN_SIGNATURE", "detail": "The provided token is expired."}"#)
+            .create();
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+
+        let result = baserow.reset_password("token", "new-password").await;
+        assert!(matches!(result, Err(UserAccountError::InvalidToken)));
+
+        mock.assert();
+    }
+}
@@ -0,0 +1,138 @@
+//! Views as source of row queries
+//!
+//! Rows can be queried through a view in two ways. The rows endpoint of the
+//! table applies the filters and sorts of a view given by its ID, which works
+//! with database tokens. The endpoints of the views themselves additionally
+//! serve shared views publicly, without authentication, but require a JWT
+//! otherwise. Both apply the filters and sorts of the view on the server.
+//!
+//! The endpoints of views always key fields by their IDs. To get rows keyed
+//! by field names, query a table set up via `auto_map()`, whose mapping then
+//! converts the rows.
+//!
+//! # Example
+//! ```no_run
+//! use baserow_rs::{api::view::ViewSource, prelude::*};
+//! use serde_json::Value;
+//! use std::collections::HashMap;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let config = ConfigBuilder::new()
+//!         .base_url("https://api.baserow.io")
+//!         .api_key("your-api-key")
+//!         .try_build()
+//!         .expect("invalid configuration");
+//!     let baserow = Baserow::with_configuration(config);
+//!     let table = baserow.table_by_id(TableId(1234)).auto_map().await.unwrap();
+//!
+//!     // Filtered and sorted like view 5678, via the rows endpoint of the table
+//!     let rows = table.clone().query().view(ViewId(5678)).get::<HashMap<String, Value>>().await;
+//!
+//!     // The rows of a publicly shared gallery view
+//!     let rows = table
+//!         .query()
+//!         .view(ViewSource::public_gallery("w6ZuRKzx5KvhN2yfdm4jhbjXQmb2bC0mbrQVuabgS-M"))
+//!         .get::<HashMap<String, Value>>()
+//!         .await;
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::ids::ViewId;
+
+/// The types of views whose rows can be listed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ViewType {
+    /// Rows in a spreadsheet-like grid
+    Grid,
+    /// Rows as cards
+    Gallery,
+}
+
+impl ViewType {
+    /// Returns the type as used in the URLs of the API, e.g. `grid`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ViewType::Grid => "grid",
+            ViewType::Gallery => "gallery",
+        }
+    }
+}
+
+/// The view a row query reads from, see the [module documentation](self)
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ViewSource {
+    /// The rows endpoint of the table, applying the filters and sorts of the view
+    Table(ViewId),
+    /// The rows endpoint of the view, which requires a JWT
+    View { view_type: ViewType, id: ViewId },
+    /// The public rows endpoint of a shared view, identified by its slug
+    Public { view_type: ViewType, slug: String },
+}
+
+impl ViewSource {
+    /// Reads from the rows endpoint of a grid view
+    pub fn grid(id: ViewId) -> Self {
+        Self::View {
+            view_type: ViewType::Grid,
+            id,
+        }
+    }
+
+    /// Reads from the rows endpoint of a gallery view
+    pub fn gallery(id: ViewId) -> Self {
+        Self::View {
+            view_type: ViewType::Gallery,
+            id,
+        }
+    }
+
+    /// Reads from the public rows endpoint of a shared grid view
+    pub fn public_grid(slug: &str) -> Self {
+        Self::Public {
+            view_type: ViewType::Grid,
+            slug: slug.to_string(),
+        }
+    }
+
+    /// Reads from the public rows endpoint of a shared gallery view
+    pub fn public_gallery(slug: &str) -> Self {
+        Self::Public {
+            view_type: ViewType::Gallery,
+            slug: slug.to_string(),
+        }
+    }
+
+    /// Returns the type of the view, unless it is read via the table
+    pub fn view_type(&self) -> Option<ViewType> {
+        match self {
+            Self::Table(_) => None,
+            Self::View { view_type, .. } | Self::Public { view_type, .. } => Some(*view_type),
+        }
+    }
+
+    /// Returns the path of the endpoint of the view, or `None` if it is read via the table
+    pub(crate) fn path(&self) -> Option<String> {
+        match self {
+            Self::Table(_) => None,
+            Self::View { view_type, id } => {
+                Some(format!("api/database/views/{}/{}/", view_type.as_str(), id))
+            }
+            Self::Public { view_type, slug } => Some(format!(
+                "api/database/views/{}/{}/public/rows/",
+                view_type.as_str(),
+                slug
+            )),
+        }
+    }
+}
+
+impl From<ViewId> for ViewSource {
+    fn from(id: ViewId) -> Self {
+        Self::Table(id)
+    }
+}
@@ -0,0 +1,1137 @@
+use std::{collections::HashMap, fmt};
+
+use async_trait::async_trait;
+use reqwest::{header::HeaderMap, Method, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use tracing::{debug, info, instrument};
+
+use crate::{
+    api::client::BaserowClient,
+    error::{BaserowError, WebhookReceiveError},
+    ids::{FieldId, RowId, TableId, ViewId},
+    TableField,
+};
+
+/// Event types a Baserow webhook can be triggered by
+///
+/// Used both when registering webhooks and when parsing the payloads Baserow
+/// sends to webhook receivers, so event strings are defined in one place.
+/// Event types added in later Baserow versions, like `rows.enter_view`, are
+/// kept as `Unknown` so webhooks and payloads using them can still be read.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WebhookEvent {
+    /// One or more rows were created
+    RowsCreated,
+    /// One or more rows were updated
+    RowsUpdated,
+    /// One or more rows were deleted
+    RowsDeleted,
+    /// A field was created
+    FieldCreated,
+    /// A field was updated
+    FieldUpdated,
+    /// A field was deleted
+    FieldDeleted,
+    /// A view was created
+    ViewCreated,
+    /// A view was updated
+    ViewUpdated,
+    /// A view was deleted
+    ViewDeleted,
+    /// An event type without a variant of its own, by its string
+    Unknown(String),
+}
+
+impl WebhookEvent {
+    /// Converts the event to its string representation for API requests
+    pub fn as_str(&self) -> &str {
+        match self {
+            WebhookEvent::RowsCreated => "rows.created",
+            WebhookEvent::RowsUpdated => "rows.updated",
+            WebhookEvent::RowsDeleted => "rows.deleted",
+            WebhookEvent::FieldCreated => "field.created",
+            WebhookEvent::FieldUpdated => "field.updated",
+            WebhookEvent::FieldDeleted => "field.deleted",
+            WebhookEvent::ViewCreated => "view.created",
+            WebhookEvent::ViewUpdated => "view.updated",
+            WebhookEvent::ViewDeleted => "view.deleted",
+            WebhookEvent::Unknown(event) => event,
+        }
+    }
+}
+
+impl From<&str> for WebhookEvent {
+    fn from(event: &str) -> Self {
+        match event {
+            "rows.created" => WebhookEvent::RowsCreated,
+            "rows.updated" => WebhookEvent::RowsUpdated,
+            "rows.deleted" => WebhookEvent::RowsDeleted,
+            "field.created" => WebhookEvent::FieldCreated,
+            "field.updated" => WebhookEvent::FieldUpdated,
+            "field.deleted" => WebhookEvent::FieldDeleted,
+            "view.created" => WebhookEvent::ViewCreated,
+            "view.updated" => WebhookEvent::ViewUpdated,
+            "view.deleted" => WebhookEvent::ViewDeleted,
+            event => WebhookEvent::Unknown(event.to_string()),
+        }
+    }
+}
+
+impl Serialize for WebhookEvent {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for WebhookEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(|event| WebhookEvent::from(event.as_str()))
+    }
+}
+
+impl fmt::Display for WebhookEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Payload Baserow sends to webhook receivers
+///
+/// Which of the optional fields are populated depends on `event_type`:
+/// row events carry `items` (and `old_items` for updates) or `row_ids` for deletions,
+/// field and view events carry the affected field/view or its ID.
+///
+/// Rows are deserialized into `T`, which defaults to a plain map of field keys to values.
+///
+/// # Example
+/// ```
+/// use baserow_rs::api::webhook::{WebhookEvent, WebhookPayload};
+///
+/// let body = br#"{
+///     "table_id": 1,
+///     "database_id": 2,
+///     "workspace_id": 3,
+///     "webhook_id": 4,
+///     "event_id": "8f5b7d0e-3c9a-4f43-9c43-3d5a0d1a2b3c",
+///     "event_type": "rows.created",
+///     "items": [{"id": 1, "order": "1.00000000000000000000", "Name": "Alice"}]
+/// }"#;
+///
+/// let payload: WebhookPayload = serde_json::from_slice(body).unwrap();
+/// assert_eq!(payload.event_type, WebhookEvent::RowsCreated);
+/// assert_eq!(payload.items.len(), 1);
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(bound(deserialize = "T: serde::de::DeserializeOwned"))]
+pub struct WebhookPayload<T = HashMap<String, Value>> {
+    /// The table the event occurred in
+    pub table_id: TableId,
+    /// The database containing the table
+    pub database_id: Option<u64>,
+    /// The workspace containing the database
+    pub workspace_id: Option<u64>,
+    /// The webhook that triggered the call
+    pub webhook_id: u64,
+    /// Unique identifier of the event
+    pub event_id: String,
+    /// The type of event that occurred
+    pub event_type: WebhookEvent,
+    /// The created rows, or the new values of the updated rows
+    #[serde(default)]
+    pub items: Vec<T>,
+    /// The values of the updated rows before the update
+    #[serde(default)]
+    pub old_items: Vec<T>,
+    /// The IDs of the deleted rows
+    #[serde(default)]
+    pub row_ids: Vec<RowId>,
+    /// The created or updated field
+    pub field: Option<TableField>,
+    /// The ID of the deleted field
+    pub field_id: Option<FieldId>,
+    /// The created or updated view
+    pub view: Option<Value>,
+    /// The ID of the deleted view
+    pub view_id: Option<ViewId>,
+}
+
+/// Validates and parses incoming webhook calls
+///
+/// The receiver knows the webhooks registered on Baserow and only accepts calls
+/// that use the registered request method, carry all registered headers (e.g. a
+/// shared secret) with matching values, concern the table the webhook was
+/// registered on and report an event the webhook was registered for. The
+/// headers are checked before the payload is parsed.
+///
+/// # Example
+/// ```no_run
+/// use baserow_rs::api::webhook::{BaserowWebhookOperations, WebhookReceiver};
+/// use baserow_rs::{api::client::BaserowClient, Baserow, ConfigBuilder, TableId};
+/// use reqwest::{header::HeaderMap, Method};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let config = ConfigBuilder::new()
+///         .base_url("https://api.baserow.io")
+///         .email("user@example.com")
+///         .password("password")
+///         .try_build()
+///         .expect("invalid configuration");
+///     let client = Baserow::with_configuration(config).token_auth().await?;
+///
+///     let mut receiver = WebhookReceiver::new();
+///     for webhook in client.list_webhooks(TableId(1234)).await? {
+///         receiver.register(TableId(1234), &webhook);
+///     }
+///
+///     // In the HTTP handler of the receiving service
+///     let headers = HeaderMap::new();
+///     let body = br#"{"table_id": 1234, "webhook_id": 7, "event_id": "1", "event_type": "rows.deleted", "row_ids": [1]}"#;
+///     let payload = receiver.receive::<serde_json::Value>(&Method::POST, &headers, body)?;
+///     println!("Deleted rows: {:?}", payload.row_ids);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct WebhookReceiver {
+    webhooks: HashMap<u64, (TableId, Webhook)>,
+}
+
+/// The parts of a webhook payload needed to validate a call
+#[derive(Deserialize)]
+struct WebhookEnvelope {
+    table_id: TableId,
+    webhook_id: u64,
+    event_type: WebhookEvent,
+}
+
+impl WebhookReceiver {
+    /// Creates a receiver without any registered webhooks
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a webhook of the given table whose calls should be accepted
+    pub fn register(&mut self, table_id: TableId, webhook: &Webhook) -> &mut Self {
+        self.webhooks
+            .insert(webhook.id, (table_id, webhook.clone()));
+        self
+    }
+
+    /// Validates an incoming webhook call and parses its payload
+    ///
+    /// # Arguments
+    /// * `method` - The HTTP method of the incoming request
+    /// * `headers` - The headers of the incoming request
+    /// * `body` - The raw body of the incoming request
+    ///
+    /// # Returns
+    /// The parsed payload if the call matches a registered webhook
+    #[instrument(skip(self, headers, body), fields(body_size = body.len()), err)]
+    pub fn receive<T>(
+        &self,
+        method: &Method,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Result<WebhookPayload<T>, WebhookReceiveError>
+    where
+        T: DeserializeOwned,
+    {
+        let result = self.validate(method, headers, body);
+        if let Err(e) = &result {
+            e.log();
+        }
+        result
+    }
+
+    fn validate<T>(
+        &self,
+        method: &Method,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Result<WebhookPayload<T>, WebhookReceiveError>
+    where
+        T: DeserializeOwned,
+    {
+        let envelope: WebhookEnvelope = serde_json::from_slice(body)?;
+
+        let (table_id, webhook) = self
+            .webhooks
+            .get(&envelope.webhook_id)
+            .ok_or(WebhookReceiveError::UnknownWebhook(envelope.webhook_id))?;
+
+        if method.as_str() != webhook.request_method.as_str() {
+            return Err(WebhookReceiveError::UnexpectedMethod(method.clone()));
+        }
+
+        for (name, expected) in &webhook.headers {
+            let matches = headers
+                .get(name.as_str())
+                .is_some_and(|value| constant_time_eq(value.as_bytes(), expected.as_bytes()));
+            if !matches {
+                return Err(WebhookReceiveError::InvalidHeader(name.clone()));
+            }
+        }
+
+        if envelope.table_id != *table_id {
+            return Err(WebhookReceiveError::UnexpectedTable(envelope.table_id));
+        }
+
+        if !webhook.include_all_events && !webhook.events.contains(&envelope.event_type) {
+            return Err(WebhookReceiveError::UnexpectedEvent(envelope.event_type));
+        }
+
+        let payload: WebhookPayload<T> = serde_json::from_slice(body)?;
+
+        debug!(
+            webhook_id = payload.webhook_id,
+            event_type = %payload.event_type,
+            "Accepted webhook call"
+        );
+        Ok(payload)
+    }
+}
+
+/// Compares two byte slices without short-circuiting on the first difference
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// HTTP method Baserow uses when calling a webhook endpoint
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum WebhookRequestMethod {
+    Get,
+    #[default]
+    Post,
+    Patch,
+    Put,
+    Delete,
+}
+
+impl WebhookRequestMethod {
+    /// Converts the method to its string representation for API requests
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookRequestMethod::Get => "GET",
+            WebhookRequestMethod::Post => "POST",
+            WebhookRequestMethod::Patch => "PATCH",
+            WebhookRequestMethod::Put => "PUT",
+            WebhookRequestMethod::Delete => "DELETE",
+        }
+    }
+}
+
+/// Request body for triggering a test call of a webhook
+///
+/// Baserow sends a sample payload for the given event type to `url` and reports
+/// back the full request/response exchange, without persisting a webhook.
+#[derive(Clone, Debug, Serialize)]
+pub struct WebhookTestCallRequest {
+    /// The URL the sample payload is sent to
+    pub url: String,
+    /// The HTTP method used for the call
+    pub request_method: WebhookRequestMethod,
+    /// Additional headers sent along with the call
+    pub headers: HashMap<String, String>,
+    /// Whether the sample payload uses field names instead of field IDs
+    pub use_user_field_names: bool,
+    /// The event type the sample payload is generated for
+    pub event_type: WebhookEvent,
+}
+
+impl WebhookTestCallRequest {
+    /// Creates a test call request sending a POST for the given event type
+    pub fn new(url: &str, event_type: WebhookEvent) -> Self {
+        Self {
+            url: url.to_string(),
+            request_method: WebhookRequestMethod::default(),
+            headers: HashMap::new(),
+            use_user_field_names: false,
+            event_type,
+        }
+    }
+}
+
+/// Result of a webhook test call
+///
+/// Contains the raw HTTP exchange as Baserow recorded it.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WebhookTestCallResponse {
+    /// The raw request as it was sent to the webhook endpoint
+    pub request: String,
+    /// The raw response received from the webhook endpoint, if any
+    pub response: Option<String>,
+    /// The status code returned by the webhook endpoint, if it was reachable
+    pub status_code: Option<u16>,
+    /// Whether the webhook endpoint could not be reached at all
+    pub is_unreachable: bool,
+}
+
+/// A single delivery attempt of a webhook
+///
+/// Baserow keeps a log of the most recent calls for every webhook, which can be
+/// used to detect failing integrations.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WebhookCall {
+    pub id: u64,
+    /// Unique identifier of the event that triggered the call
+    pub event_id: String,
+    /// The type of event that triggered the call
+    pub event_type: WebhookEvent,
+    /// When the call was made
+    pub called_time: Option<String>,
+    /// The URL that was called
+    pub called_url: String,
+    /// The raw request that was sent
+    pub request: Option<String>,
+    /// The raw response that was received, if any
+    pub response: Option<String>,
+    /// The status code of the response, if the endpoint was reachable
+    pub response_status: Option<u16>,
+    /// Error message if the call could not be completed
+    pub error: Option<String>,
+}
+
+impl WebhookCall {
+    /// Returns whether the call failed, either with an error or a non-2xx response
+    pub fn is_failure(&self) -> bool {
+        self.error.is_some()
+            || !self
+                .response_status
+                .is_some_and(|s| (200..300).contains(&s))
+    }
+}
+
+/// Number of consecutive failed triggers after which Baserow deactivates a webhook
+///
+/// This mirrors the default of Baserow's `BASEROW_WEBHOOKS_MAX_CONSECUTIVE_TRIGGER_FAILURES`
+/// setting. Self-hosted instances may configure a different value.
+pub const DEFAULT_MAX_CONSECUTIVE_TRIGGER_FAILURES: u32 = 8;
+
+/// A webhook registered on a Baserow table
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Webhook {
+    pub id: u64,
+    /// Human-readable name of the webhook
+    pub name: String,
+    /// The URL Baserow calls when an event occurs
+    pub url: String,
+    /// The HTTP method used for the call
+    pub request_method: WebhookRequestMethod,
+    /// Additional headers sent along with every call
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    /// Whether the webhook is triggered by all events
+    pub include_all_events: bool,
+    /// The events the webhook is triggered by if `include_all_events` is false
+    #[serde(default)]
+    pub events: Vec<WebhookEvent>,
+    /// Whether payloads use field names instead of field IDs
+    pub use_user_field_names: bool,
+    /// Whether the webhook is currently active
+    pub active: bool,
+    /// Number of consecutive failed triggers, reset by a successful call
+    #[serde(default)]
+    pub failed_triggers: u32,
+    /// The most recent delivery attempts
+    #[serde(default)]
+    pub calls: Vec<WebhookCall>,
+    pub created_on: Option<String>,
+    pub updated_on: Option<String>,
+}
+
+impl Webhook {
+    /// Returns the most recent failed call, if any
+    pub fn last_failure(&self) -> Option<&WebhookCall> {
+        self.calls
+            .iter()
+            .filter(|call| call.is_failure())
+            .max_by(|a, b| a.called_time.cmp(&b.called_time))
+    }
+
+    /// Returns how many more consecutive failures are tolerated before deactivation
+    ///
+    /// # Arguments
+    /// * `max_consecutive_failures` - The deactivation threshold of the Baserow instance,
+    ///   usually [`DEFAULT_MAX_CONSECUTIVE_TRIGGER_FAILURES`]
+    pub fn remaining_failures(&self, max_consecutive_failures: u32) -> u32 {
+        max_consecutive_failures.saturating_sub(self.failed_triggers)
+    }
+
+    /// Returns whether the webhook is active but will be deactivated on its next failure
+    pub fn is_about_to_be_deactivated(&self, max_consecutive_failures: u32) -> bool {
+        self.active && self.remaining_failures(max_consecutive_failures) <= 1
+    }
+}
+
+/// Request body for updating an existing webhook
+///
+/// Only the fields that are set are sent to Baserow. Setting `active` to true
+/// re-enables a webhook Baserow deactivated after too many failures.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdateWebhookRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_method: Option<WebhookRequestMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_all_events: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub events: Option<Vec<WebhookEvent>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_user_field_names: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+}
+
+/// Request body for registering a new webhook on a table
+///
+/// # Example
+/// ```
+/// use baserow_rs::api::webhook::{CreateWebhookRequest, WebhookEvent};
+///
+/// let request = CreateWebhookRequest::new("Sync", "https://example.com/hook")
+///     .event(WebhookEvent::RowsCreated)
+///     .event(WebhookEvent::RowsUpdated)
+///     .header("X-Webhook-Secret", "s3cr3t")
+///     .use_user_field_names(true);
+/// ```
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateWebhookRequest {
+    /// Human-readable name of the webhook
+    pub name: String,
+    /// The URL Baserow calls when an event occurs
+    pub url: String,
+    /// The HTTP method used for the call
+    pub request_method: WebhookRequestMethod,
+    /// Additional headers sent along with every call, e.g. a shared secret
+    pub headers: HashMap<String, String>,
+    /// Whether the webhook is triggered by all events
+    pub include_all_events: bool,
+    /// The events the webhook is triggered by if `include_all_events` is false
+    pub events: Vec<WebhookEvent>,
+    /// Whether payloads use field names instead of field IDs
+    pub use_user_field_names: bool,
+}
+
+impl CreateWebhookRequest {
+    /// Creates a request for a webhook that is called via POST for all events
+    pub fn new(name: &str, url: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            url: url.to_string(),
+            request_method: WebhookRequestMethod::default(),
+            headers: HashMap::new(),
+            include_all_events: true,
+            events: vec![],
+            use_user_field_names: false,
+        }
+    }
+
+    /// Set the HTTP method used for calling the webhook
+    pub fn request_method(mut self, method: WebhookRequestMethod) -> Self {
+        self.request_method = method;
+        self
+    }
+
+    /// Add a header sent along with every call
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Replace all headers sent along with every call
+    pub fn headers(mut self, headers: HashMap<String, String>) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    /// Restrict the webhook to the given event
+    ///
+    /// Adding an event disables `include_all_events`.
+    pub fn event(mut self, event: WebhookEvent) -> Self {
+        self.include_all_events = false;
+        self.events.push(event);
+        self
+    }
+
+    /// Set whether payloads use field names instead of field IDs
+    pub fn use_user_field_names(mut self, enabled: bool) -> Self {
+        self.use_user_field_names = enabled;
+        self
+    }
+}
+
+/// Trait defining the webhook operations available on a Baserow client
+///
+/// Webhook endpoints only accept JWT authentication, so the client has to be
+/// authenticated via `token_auth()` first. The trait is implemented for every
+/// [`BaserowClient`], including the client returned by `token_auth()`.
+#[async_trait]
+pub trait BaserowWebhookOperations: BaserowClient {
+    /// Lists all webhooks registered on a table
+    ///
+    /// # Arguments
+    /// * `table_id` - The table to list the webhooks for
+    ///
+    /// # Returns
+    /// The webhooks including their most recent calls
+    #[instrument(skip(self), err)]
+    async fn list_webhooks(&self, table_id: TableId) -> Result<Vec<Webhook>, BaserowError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url(&format!("api/database/webhooks/table/{}/", table_id));
+
+        let req = self.auth_header().await.apply_jwt(client.get(url))?;
+
+        debug!("Sending request to list webhooks");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let webhooks: Vec<Webhook> = resp.json().await?;
+                info!(
+                    webhook_count = webhooks.len(),
+                    "Successfully retrieved webhooks"
+                );
+                Ok(webhooks)
+            }
+            _ => Err(BaserowError::from_response(resp).await),
+        }
+    }
+
+    /// Registers a new webhook on a table
+    ///
+    /// # Arguments
+    /// * `table_id` - The table the webhook is registered on
+    /// * `request` - The webhook configuration
+    ///
+    /// # Returns
+    /// The created webhook
+    #[instrument(skip(self, request), fields(url = %request.url), err)]
+    async fn create_webhook(
+        &self,
+        table_id: TableId,
+        request: CreateWebhookRequest,
+    ) -> Result<Webhook, BaserowError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url(&format!("api/database/webhooks/table/{}/", table_id));
+
+        let req = self
+            .auth_header()
+            .await
+            .apply_jwt(client.post(url))?
+            .json(&request);
+
+        debug!("Sending request to create webhook");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let webhook: Webhook = resp.json().await?;
+                info!(webhook_id = webhook.id, "Successfully created webhook");
+                Ok(webhook)
+            }
+            _ => Err(BaserowError::from_response(resp).await),
+        }
+    }
+
+    /// Retrieves a single webhook by its ID
+    ///
+    /// # Arguments
+    /// * `webhook_id` - The unique identifier of the webhook
+    #[instrument(skip(self), err)]
+    async fn get_webhook(&self, webhook_id: u64) -> Result<Webhook, BaserowError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url(&format!("api/database/webhooks/{}/", webhook_id));
+
+        let req = self.auth_header().await.apply_jwt(client.get(url))?;
+
+        debug!("Sending request to fetch webhook");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK => Ok(resp.json().await?),
+            _ => Err(BaserowError::from_response(resp).await),
+        }
+    }
+
+    /// Updates an existing webhook
+    ///
+    /// # Arguments
+    /// * `webhook_id` - The unique identifier of the webhook
+    /// * `request` - The fields to change
+    ///
+    /// # Returns
+    /// The updated webhook
+    #[instrument(skip(self, request), err)]
+    async fn update_webhook(
+        &self,
+        webhook_id: u64,
+        request: UpdateWebhookRequest,
+    ) -> Result<Webhook, BaserowError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url(&format!("api/database/webhooks/{}/", webhook_id));
+
+        let req = self
+            .auth_header()
+            .await
+            .apply_jwt(client.patch(url))?
+            .json(&request);
+
+        debug!("Sending request to update webhook");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let webhook: Webhook = resp.json().await?;
+                info!(active = webhook.active, "Successfully updated webhook");
+                Ok(webhook)
+            }
+            _ => Err(BaserowError::from_response(resp).await),
+        }
+    }
+
+    /// Lists the most recent delivery attempts of a webhook
+    ///
+    /// # Arguments
+    /// * `webhook_id` - The unique identifier of the webhook
+    ///
+    /// # Returns
+    /// The recorded calls including status codes, response bodies and timestamps
+    #[instrument(skip(self), err)]
+    async fn list_webhook_calls(&self, webhook_id: u64) -> Result<Vec<WebhookCall>, BaserowError> {
+        let webhook = self.get_webhook(webhook_id).await?;
+        info!(call_count = webhook.calls.len(), "Retrieved webhook calls");
+        Ok(webhook.calls)
+    }
+
+    /// Sends a sample payload for the given event type to a webhook endpoint
+    ///
+    /// # Arguments
+    /// * `table_id` - The table the sample payload is generated for
+    /// * `request` - The webhook endpoint and event type to test
+    ///
+    /// # Returns
+    /// The request/response exchange recorded by Baserow
+    #[instrument(skip(self, request), fields(url = %request.url, event_type = %request.event_type), err)]
+    async fn test_webhook_call(
+        &self,
+        table_id: TableId,
+        request: WebhookTestCallRequest,
+    ) -> Result<WebhookTestCallResponse, BaserowError> {
+        let configuration = self.configuration();
+        let client = self.get_client();
+
+        let url = configuration.api_url(&format!(
+            "api/database/webhooks/table/{}/test-call/",
+            table_id
+        ));
+
+        let req = self
+            .auth_header()
+            .await
+            .apply_jwt(client.post(url))?
+            .json(&request);
+
+        debug!("Sending webhook test call request");
+        let resp = self.trace_request(&client, req.build()?).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let response: WebhookTestCallResponse = resp.json().await?;
+                info!(
+                    status_code = ?response.status_code,
+                    is_unreachable = response.is_unreachable,
+                    "Webhook test call completed"
+                );
+                Ok(response)
+            }
+            _ => Err(BaserowError::from_response(resp).await),
+        }
+    }
+}
+
+impl<T: BaserowClient + ?Sized> BaserowWebhookOperations for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Baserow, ConfigBuilder};
+
+    #[test]
+    fn test_webhook_event_serialization() {
+        assert_eq!(
+            serde_json::to_value(WebhookEvent::RowsCreated).unwrap(),
+            "rows.created"
+        );
+        assert_eq!(WebhookEvent::FieldDeleted.to_string(), "field.deleted");
+
+        let event: WebhookEvent = serde_json::from_str(r#""view.updated""#).unwrap();
+        assert_eq!(event, WebhookEvent::ViewUpdated);
+
+        // Event types of later Baserow versions are kept as they are
+        let event: WebhookEvent = serde_json::from_str(r#""rows.enter_view""#).unwrap();
+        assert_eq!(event, WebhookEvent::Unknown("rows.enter_view".to_string()));
+        assert_eq!(serde_json::to_value(&event).unwrap(), "rows.enter_view");
+    }
+
+    #[test]
+    fn test_webhook_payload_deserialization() {
+        #[derive(Debug, Deserialize)]
+        struct Person {
+            id: u64,
+            name: String,
+        }
+
+        let body = br#"{
+            "table_id": 1234,
+            "database_id": 1,
+            "workspace_id": 1,
+            "webhook_id": 7,
+            "event_id": "8f5b7d0e-3c9a-4f43-9c43-3d5a0d1a2b3c",
+            "event_type": "rows.updated",
+            "items": [{"id": 1, "name": "Alice Smith"}],
+            "old_items": [{"id": 1, "name": "Alice"}]
+        }"#;
+
+        let payload: WebhookPayload<Person> = serde_json::from_slice(body).unwrap();
+        assert_eq!(payload.table_id, TableId(1234));
+        assert_eq!(payload.event_type, WebhookEvent::RowsUpdated);
+        assert_eq!(payload.items[0].id, 1);
+        assert_eq!(payload.items[0].name, "Alice Smith");
+        assert_eq!(payload.old_items[0].name, "Alice");
+        assert!(payload.row_ids.is_empty());
+
+        let body = br#"{
+            "table_id": 1234,
+            "webhook_id": 7,
+            "event_id": "0b8f1c4e-5d7e-4a3b-8c2d-1e0f9a8b7c6d",
+            "event_type": "rows.deleted",
+            "row_ids": [1, 2]
+        }"#;
+
+        let payload: WebhookPayload = serde_json::from_slice(body).unwrap();
+        assert_eq!(payload.event_type, WebhookEvent::RowsDeleted);
+        assert_eq!(payload.row_ids, vec![RowId(1), RowId(2)]);
+        assert!(payload.items.is_empty());
+    }
+
+    fn registered_webhook() -> Webhook {
+        serde_json::from_value(serde_json::json!({
+            "id": 7,
+            "name": "Sync",
+            "url": "https://example.com/hook",
+            "request_method": "POST",
+            "headers": {"X-Webhook-Secret": "s3cr3t"},
+            "include_all_events": false,
+            "events": ["rows.created"],
+            "use_user_field_names": true,
+            "active": true
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_webhook_receiver() {
+        let mut receiver = WebhookReceiver::new();
+        receiver.register(TableId(1234), &registered_webhook());
+
+        let body = br#"{"table_id": 1234, "webhook_id": 7, "event_id": "1", "event_type": "rows.created", "items": [{"id": 1}]}"#;
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Webhook-Secret", "s3cr3t".parse().unwrap());
+
+        let payload = receiver
+            .receive::<Value>(&Method::POST, &headers, body)
+            .unwrap();
+        assert_eq!(payload.items[0]["id"], 1);
+
+        // Wrong method
+        let result = receiver.receive::<Value>(&Method::PUT, &headers, body);
+        assert!(matches!(
+            result,
+            Err(WebhookReceiveError::UnexpectedMethod(_))
+        ));
+
+        // Missing secret
+        let result = receiver.receive::<Value>(&Method::POST, &HeaderMap::new(), body);
+        assert!(matches!(result, Err(WebhookReceiveError::InvalidHeader(_))));
+
+        // Wrong secret
+        let mut wrong_headers = HeaderMap::new();
+        wrong_headers.insert("X-Webhook-Secret", "guess".parse().unwrap());
+        let result = receiver.receive::<Value>(&Method::POST, &wrong_headers, body);
+        assert!(matches!(result, Err(WebhookReceiveError::InvalidHeader(_))));
+
+        // Secret is checked before the payload is parsed
+        let result = receiver.receive::<Value>(
+            &Method::POST,
+            &wrong_headers,
+            br#"{"table_id": 1234, "webhook_id": 7, "event_id": "1", "event_type": "rows.created", "items": "invalid"}"#,
+        );
+        assert!(matches!(result, Err(WebhookReceiveError::InvalidHeader(_))));
+
+        // Payload for another table
+        let body = br#"{"table_id": 4321, "webhook_id": 7, "event_id": "2", "event_type": "rows.created", "items": [{"id": 1}]}"#;
+        let result = receiver.receive::<Value>(&Method::POST, &headers, body);
+        assert!(matches!(
+            result,
+            Err(WebhookReceiveError::UnexpectedTable(TableId(4321)))
+        ));
+
+        // Event the webhook is not registered for
+        let body = br#"{"table_id": 1234, "webhook_id": 7, "event_id": "2", "event_type": "rows.deleted", "row_ids": [1]}"#;
+        let result = receiver.receive::<Value>(&Method::POST, &headers, body);
+        assert!(matches!(
+            result,
+            Err(WebhookReceiveError::UnexpectedEvent(
+                WebhookEvent::RowsDeleted
+            ))
+        ));
+
+        // Unknown webhook
+        let body = br#"{"table_id": 1234, "webhook_id": 8, "event_id": "3", "event_type": "rows.created"}"#;
+        let result = receiver.receive::<Value>(&Method::POST, &headers, body);
+        assert!(matches!(
+            result,
+            Err(WebhookReceiveError::UnknownWebhook(8))
+        ));
+    }
+
+    #[test]
+    fn test_webhook_failure_info() {
+        let webhook: Webhook = serde_json::from_value(serde_json::json!({
+            "id": 7,
+            "name": "Sync",
+            "url": "https://example.com/hook",
+            "request_method": "POST",
+            "include_all_events": true,
+            "use_user_field_names": false,
+            "active": true,
+            "failed_triggers": 7,
+            "calls": [
+                {"id": 1, "event_id": "a", "event_type": "rows.created", "called_time": "2024-01-01T00:00:00Z",
+                 "called_url": "https://example.com/hook", "response_status": 500, "error": null},
+                {"id": 2, "event_id": "b", "event_type": "rows.created", "called_time": "2024-01-02T00:00:00Z",
+                 "called_url": "https://example.com/hook", "response_status": null, "error": "Connection refused"},
+                {"id": 3, "event_id": "c", "event_type": "rows.created", "called_time": "2023-12-31T00:00:00Z",
+                 "called_url": "https://example.com/hook", "response_status": 200, "error": null}
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(webhook.last_failure().unwrap().id, 2);
+        assert_eq!(
+            webhook.remaining_failures(DEFAULT_MAX_CONSECUTIVE_TRIGGER_FAILURES),
+            1
+        );
+        assert!(webhook.is_about_to_be_deactivated(DEFAULT_MAX_CONSECUTIVE_TRIGGER_FAILURES));
+        assert!(!webhook.is_about_to_be_deactivated(20));
+    }
+
+    #[tokio::test]
+    async fn test_webhook_test_call() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        let auth_mock = server
+            .mock("POST", "/api/user/token-auth/")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+  "user": {"first_name": "string", "username": "user@example.com", "language": "en"},
+  "token": "jwt-token",
+  "access_token": "jwt-token",
+  "refresh_token": "refresh-token"
+}"#,
+            )
+            .create();
+
+        let mock = server
+            .mock("POST", "/api/database/webhooks/table/1234/test-call/")
+            .match_header("Authorization", "JWT jwt-token")
+            .match_body(mockito::Matcher::PartialJsonString(
+                r#"{"url": "https://example.com/hook", "request_method": "POST", "event_type": "rows.created"}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+  "request": "POST /hook\nContent-Type: application/json\n\n{}",
+  "response": "HTTP/1.1 200 OK\n\nok",
+  "status_code": 200,
+  "is_unreachable": false
+}"#,
+            )
+            .create();
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .email("test@example.com")
+            .password("password")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+        let client = baserow.token_auth().await.unwrap();
+
+        let result = client
+            .test_webhook_call(
+                TableId(1234),
+                WebhookTestCallRequest::new("https://example.com/hook", WebhookEvent::RowsCreated),
+            )
+            .await;
+        assert!(result.is_ok());
+
+        let response = result.unwrap();
+        assert_eq!(response.status_code, Some(200));
+        assert!(!response.is_unreachable);
+
+        auth_mock.assert();
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_create_webhook() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        let mock = server
+            .mock("POST", "/api/database/webhooks/table/1234/")
+            .match_header("Authorization", "JWT jwt-token")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "name": "Sync",
+                "url": "https://example.com/hook",
+                "request_method": "POST",
+                "headers": {"X-Webhook-Secret": "s3cr3t"},
+                "include_all_events": false,
+                "events": ["rows.created"],
+                "use_user_field_names": true
+            })))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+  "id": 7,
+  "name": "Sync",
+  "url": "https://example.com/hook",
+  "request_method": "POST",
+  "headers": {"X-Webhook-Secret": "s3cr3t"},
+  "include_all_events": false,
+  "events": ["rows.created"],
+  "use_user_field_names": true,
+  "active": true,
+  "calls": []
+}"#,
+            )
+            .create();
+
+        let mut configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .try_build()
+            .unwrap();
+        configuration.jwt = Some("jwt-token".into());
+        let baserow = Baserow::with_configuration(configuration);
+
+        let request = CreateWebhookRequest::new("Sync", "https://example.com/hook")
+            .event(WebhookEvent::RowsCreated)
+            .header("X-Webhook-Secret", "s3cr3t")
+            .use_user_field_names(true);
+        let webhook = baserow
+            .create_webhook(TableId(1234), request)
+            .await
+            .unwrap();
+
+        assert_eq!(webhook.id, 7);
+        assert_eq!(webhook.headers["X-Webhook-Secret"], "s3cr3t");
+        assert!(webhook.use_user_field_names);
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_list_webhook_calls() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_url = server.url();
+
+        let mock = server
+            .mock("GET", "/api/database/webhooks/7/")
+            .match_header("Authorization", "JWT jwt-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+  "id": 7,
+  "name": "Sync",
+  "url": "https://example.com/hook",
+  "request_method": "POST",
+  "headers": {"X-Secret": "s3cr3t"},
+  "include_all_events": false,
+  "events": ["rows.created", "rows.updated"],
+  "use_user_field_names": true,
+  "active": true,
+  "calls": [
+    {
+      "id": 1,
+      "event_id": "8f5b7d0e-3c9a-4f43-9c43-3d5a0d1a2b3c",
+      "event_type": "rows.created",
+      "called_time": "2024-01-01T00:00:00Z",
+      "called_url": "https://example.com/hook",
+      "request": "POST /hook",
+      "response": "HTTP/1.1 500 Internal Server Error",
+      "response_status": 500,
+      "error": null
+    }
+  ],
+  "created_on": "2024-01-01T00:00:00Z",
+  "updated_on": "2024-01-01T00:00:00Z"
+}"#,
+            )
+            .create();
+
+        let mut configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .try_build()
+            .unwrap();
+        configuration.jwt = Some("jwt-token".into());
+        let baserow = Baserow::with_configuration(configuration);
+
+        let calls = baserow.list_webhook_calls(7).await.unwrap();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].event_type, WebhookEvent::RowsCreated);
+        assert_eq!(calls[0].response_status, Some(500));
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_webhook_test_call_requires_jwt() {
+        let configuration = ConfigBuilder::new()
+            .base_url("https://api.baserow.io")
+            .api_key("123")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration);
+
+        let result = baserow
+            .test_webhook_call(
+                TableId(1234),
+                WebhookTestCallRequest::new("https://example.com/hook", WebhookEvent::RowsCreated),
+            )
+            .await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,320 @@
+//! A blocking client for the Baserow API
+//!
+//! Wraps the async client and runs its requests on a runtime of its own, so
+//! scripts and build tools can talk to Baserow without setting up tokio.
+//! Requires the `blocking` feature.
+//!
+//! The blocking client must not be used from within an async runtime, as
+//! blocking a runtime's thread panics. Use the async client there instead.
+//!
+//! # Example
+//! ```no_run
+//! use baserow_rs::{blocking::Baserow, ConfigBuilder, RowId, TableId};
+//! use serde_json::Value;
+//! use std::collections::HashMap;
+//!
+//! let config = ConfigBuilder::new()
+//!     .base_url("https://api.baserow.io")
+//!     .api_key("your-api-key")
+//!     .try_build()
+//!     .expect("invalid configuration");
+//! let baserow = Baserow::with_configuration(config).unwrap();
+//!
+//! let table = baserow.table_by_id(TableId(1234)).auto_map().unwrap();
+//! let row: HashMap<String, Value> = table.get_one(RowId(1), None).unwrap();
+//! println!("{:?}", row);
+//! ```
+
+use std::{collections::HashMap, fs::File, sync::Arc};
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tokio::runtime::Runtime;
+
+use crate::{
+    api::{
+        batch::BatchResult,
+        client::BaserowClient,
+        file::File as BaserowFile,
+        table_operations::{RowRequest, TypedRowsResponse},
+    },
+    error::{BaserowError, FileUploadError, TokenAuthError},
+    ids::{RowId, TableId},
+    BaserowTable, BaserowTableOperations, Configuration, FieldNamesMode, TableField,
+    TypedTableOperations,
+};
+
+/// Blocking counterpart of [`crate::Baserow`]
+///
+/// Clones share the runtime and the configuration.
+#[derive(Clone)]
+pub struct Baserow {
+    client: Arc<dyn BaserowClient>,
+    runtime: Arc<Runtime>,
+}
+
+impl Baserow {
+    /// Creates a blocking client with the given configuration
+    ///
+    /// Fails if the runtime the requests are run on cannot be created.
+    pub fn with_configuration(configuration: Configuration) -> std::io::Result<Self> {
+        Self::new(crate::Baserow::with_configuration(configuration))
+    }
+
+    /// Creates a blocking client wrapping an async client
+    pub fn new(client: impl BaserowClient + 'static) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        Ok(Self {
+            client: Arc::new(client),
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Authenticates with the configured email and password, see `BaserowClient::token_auth`
+    pub fn token_auth(&self) -> Result<Self, TokenAuthError> {
+        let client = self.runtime.block_on(self.client.token_auth())?;
+        Ok(Self {
+            client: Arc::from(client),
+            runtime: self.runtime.clone(),
+        })
+    }
+
+    /// Retrieves all fields of a table
+    pub fn table_fields(&self, table_id: TableId) -> Result<Vec<TableField>, BaserowError> {
+        self.runtime.block_on(self.client.table_fields(table_id))
+    }
+
+    /// Returns a table by its ID
+    pub fn table_by_id(&self, id: TableId) -> Table {
+        Table {
+            table: self.client.table_by_id(id),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves a table including its name, order and database
+    pub fn get_table(&self, id: TableId) -> Result<Table, BaserowError> {
+        let table = self.runtime.block_on(self.client.get_table(id))?;
+        Ok(Table {
+            table,
+            runtime: self.runtime.clone(),
+        })
+    }
+
+    /// Uploads a file to Baserow
+    pub fn upload_file(
+        &self,
+        file: File,
+        filename: String,
+    ) -> Result<BaserowFile, FileUploadError> {
+        self.runtime
+            .block_on(self.client.upload_file(file, filename))
+    }
+
+    /// Uploads a file to Baserow via URL
+    pub fn upload_file_via_url(&self, url: &str) -> Result<BaserowFile, FileUploadError> {
+        self.runtime.block_on(self.client.upload_file_via_url(url))
+    }
+
+    /// Get the underlying configuration
+    pub fn get_configuration(&self) -> Configuration {
+        self.client.get_configuration()
+    }
+}
+
+/// Blocking counterpart of [`BaserowTable`], see [`BaserowTableOperations`]
+#[derive(Clone)]
+pub struct Table {
+    table: BaserowTable,
+    runtime: Arc<Runtime>,
+}
+
+impl Table {
+    /// Maps the table fields, see `BaserowTableOperations::auto_map`
+    pub fn auto_map(self) -> Result<Self, BaserowError> {
+        let table = self.runtime.block_on(self.table.auto_map())?;
+        Ok(Self {
+            table,
+            runtime: self.runtime,
+        })
+    }
+
+    /// Fetches the name, order and database of the table, see `BaserowTableOperations::refresh`
+    pub fn refresh(self) -> Result<Self, BaserowError> {
+        let table = self.runtime.block_on(self.table.refresh())?;
+        Ok(Self {
+            table,
+            runtime: self.runtime,
+        })
+    }
+
+    /// Sets how rows are keyed by default, see `BaserowTable::with_field_names`
+    pub fn with_field_names(self, mode: FieldNamesMode) -> Self {
+        Self {
+            table: self.table.with_field_names(mode),
+            runtime: self.runtime,
+        }
+    }
+
+    /// Sets whether calls use field names by default, see `BaserowTable::with_user_field_names`
+    pub fn with_user_field_names(self, enabled: bool) -> Self {
+        Self {
+            table: self.table.with_user_field_names(enabled),
+            runtime: self.runtime,
+        }
+    }
+
+    /// Queries rows of the table
+    ///
+    /// # Example
+    /// ```no_run
+    /// use baserow_rs::{api::table_operations::RowRequest, blocking::Baserow, ConfigBuilder, TableId};
+    /// use serde_json::Value;
+    /// use std::collections::HashMap;
+    ///
+    /// let config = ConfigBuilder::new()
+    ///     .base_url("https://api.baserow.io")
+    ///     .api_key("your-api-key")
+    ///     .try_build()
+    ///     .expect("invalid configuration");
+    /// let baserow = Baserow::with_configuration(config).unwrap();
+    ///
+    /// let rows = baserow
+    ///     .table_by_id(TableId(1234))
+    ///     .get::<HashMap<String, Value>>(RowRequest::default())
+    ///     .unwrap();
+    /// println!("{} rows", rows.count.unwrap_or_default());
+    /// ```
+    pub fn get<T>(&self, request: RowRequest) -> Result<TypedRowsResponse<T>, BaserowError>
+    where
+        T: DeserializeOwned + 'static,
+    {
+        let baserow = self
+            .table
+            .baserow
+            .clone()
+            .ok_or(BaserowError::MissingState("Baserow instance"))?;
+        self.runtime.block_on(self.table.get(baserow, request))
+    }
+
+    /// Retrieves a single record by ID
+    pub fn get_one<T>(&self, id: RowId, user_field_names: Option<bool>) -> Result<T, BaserowError>
+    where
+        T: DeserializeOwned + 'static,
+    {
+        self.runtime
+            .block_on(self.table.get_one(id, user_field_names))
+    }
+
+    /// Retrieves many records by ID, see `TypedTableOperations::get_many`
+    pub fn get_many<T>(
+        &self,
+        ids: &[RowId],
+        concurrency: usize,
+        user_field_names: Option<bool>,
+    ) -> Vec<Result<T, BaserowError>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        self.runtime
+            .block_on(self.table.get_many(ids, concurrency, user_field_names))
+    }
+
+    /// Creates a single record
+    pub fn create_one(
+        &self,
+        data: HashMap<String, Value>,
+        user_field_names: Option<bool>,
+    ) -> Result<HashMap<String, Value>, BaserowError> {
+        self.runtime
+            .block_on(self.table.create_one(data, user_field_names))
+    }
+
+    /// Updates a single record
+    pub fn update(
+        &self,
+        id: RowId,
+        data: HashMap<String, Value>,
+        user_field_names: Option<bool>,
+    ) -> Result<HashMap<String, Value>, BaserowError> {
+        self.runtime
+            .block_on(self.table.update(id, data, user_field_names))
+    }
+
+    /// Deletes a single record
+    pub fn delete(&self, id: RowId) -> Result<(), BaserowError> {
+        self.runtime.block_on(self.table.delete(id))
+    }
+
+    /// Creates many records in chunks, see `BaserowTableOperations::create_many`
+    pub fn create_many(
+        &self,
+        rows: Vec<HashMap<String, Value>>,
+        user_field_names: Option<bool>,
+    ) -> Result<BatchResult<HashMap<String, Value>>, BaserowError> {
+        self.runtime
+            .block_on(self.table.create_many(rows, user_field_names))
+    }
+
+    /// Updates many records in chunks, see `BaserowTableOperations::update_many`
+    pub fn update_many(
+        &self,
+        rows: Vec<(RowId, HashMap<String, Value>)>,
+        user_field_names: Option<bool>,
+    ) -> Result<BatchResult<HashMap<String, Value>>, BaserowError> {
+        self.runtime
+            .block_on(self.table.update_many(rows, user_field_names))
+    }
+
+    /// Deletes many records in chunks, see `BaserowTableOperations::delete_many`
+    pub fn delete_many(&self, ids: Vec<RowId>) -> Result<BatchResult<RowId>, BaserowError> {
+        self.runtime.block_on(self.table.delete_many(ids))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConfigBuilder;
+
+    #[test]
+    fn test_blocking_client() {
+        let mut server = mockito::Server::new();
+        let mock_url = server.url();
+
+        let row_mock = server
+            .mock("GET", "/api/database/rows/table/1234/1/")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": 1, "Name": "Widget"}"#)
+            .create();
+        let rows_mock = server
+            .mock("GET", "/api/database/rows/table/1234/")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"count": 1, "next": null, "previous": null, "results": [{"id": 1}]}"#)
+            .create();
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&mock_url)
+            .api_key("123")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(configuration).unwrap();
+        let table = baserow.table_by_id(TableId(1234));
+
+        let row: HashMap<String, Value> = table.get_one(RowId(1), None).unwrap();
+        assert_eq!(row["Name"], "Widget");
+
+        let rows = table
+            .get::<HashMap<String, Value>>(RowRequest::default())
+            .unwrap();
+        assert_eq!(rows.count, Some(1));
+
+        row_mock.assert();
+        rows_mock.assert();
+    }
+}
@@ -0,0 +1,224 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use bytes::Bytes;
+
+use crate::ids::{RowId, TableId};
+
+/// Identifies a cached row: table ID, row ID and the requested field names mode
+type RowKey = (TableId, RowId, Option<bool>);
+
+/// Counts the invalidations of a row, so responses requested before a change
+/// are not cached after it
+pub(crate) type Generation = u64;
+
+struct Entry {
+    body: Bytes,
+    expires: Instant,
+    /// When the entry was last used, as position in the usage order
+    used: u64,
+}
+
+#[derive(Default)]
+struct State {
+    entries: HashMap<RowKey, Entry>,
+    /// The cached rows ordered by their last use, least recent first
+    usage: BTreeMap<u64, RowKey>,
+    next_use: u64,
+    /// The generation of every row invalidated so far
+    generations: HashMap<(TableId, RowId), Generation>,
+}
+
+impl State {
+    fn touch(&mut self, key: RowKey) -> u64 {
+        let used = self.next_use;
+        self.next_use += 1;
+        self.usage.insert(used, key);
+        used
+    }
+
+    fn remove(&mut self, key: &RowKey) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.usage.remove(&entry.used);
+        }
+    }
+}
+
+/// Least recently used cache of `get_one` responses, see `Baserow::with_row_cache`
+pub(crate) struct RowCache {
+    capacity: usize,
+    ttl: Duration,
+    state: Mutex<State>,
+}
+
+impl RowCache {
+    pub(crate) fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity,
+            ttl,
+            state: Mutex::default(),
+        }
+    }
+
+    /// Creates an empty cache with the same capacity and time to live
+    pub(crate) fn emptied(&self) -> Self {
+        Self::new(self.capacity, self.ttl)
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the cached response body of a row, unless it expired
+    pub(crate) fn get(
+        &self,
+        table_id: TableId,
+        row_id: RowId,
+        user_field_names: Option<bool>,
+    ) -> Option<Bytes> {
+        let key = (table_id, row_id, user_field_names);
+        let mut state = self.state();
+        let entry = state.entries.get(&key)?;
+        if entry.expires <= Instant::now() {
+            state.remove(&key);
+            return None;
+        }
+
+        let (body, previous_use) = (entry.body.clone(), entry.used);
+        state.usage.remove(&previous_use);
+        let used = state.touch(key);
+        if let Some(entry) = state.entries.get_mut(&key) {
+            entry.used = used;
+        }
+        Some(body)
+    }
+
+    /// Returns the current generation of a row, to be passed to `insert`
+    pub(crate) fn generation(&self, table_id: TableId, row_id: RowId) -> Generation {
+        self.state()
+            .generations
+            .get(&(table_id, row_id))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Caches the response body of a row, evicting the least recently used rows if full
+    ///
+    /// The body is dropped if the row was invalidated since `generation` was
+    /// taken, as it may predate the change.
+    pub(crate) fn insert(
+        &self,
+        table_id: TableId,
+        row_id: RowId,
+        user_field_names: Option<bool>,
+        body: Bytes,
+        generation: Generation,
+    ) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let key = (table_id, row_id, user_field_names);
+        let mut state = self.state();
+        let current = state.generations.get(&(table_id, row_id)).copied();
+        if current.unwrap_or_default() != generation {
+            return;
+        }
+        state.remove(&key);
+        while state.entries.len() >= self.capacity {
+            let Some((_, oldest)) = state.usage.pop_first() else {
+                break;
+            };
+            state.entries.remove(&oldest);
+        }
+
+        let used = state.touch(key);
+        state.entries.insert(
+            key,
+            Entry {
+                body,
+                expires: Instant::now() + self.ttl,
+                used,
+            },
+        );
+    }
+
+    /// Removes a row from the cache in all field names modes
+    ///
+    /// Responses requested before the invalidation are no longer cached.
+    pub(crate) fn invalidate(&self, table_id: TableId, row_id: RowId) {
+        let mut state = self.state();
+        *state.generations.entry((table_id, row_id)).or_default() += 1;
+        for user_field_names in [None, Some(false), Some(true)] {
+            state.remove(&(table_id, row_id, user_field_names));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_row_cache() {
+        let cache = RowCache::new(2, Duration::from_secs(60));
+        cache.insert(TableId(1), RowId(1), None, Bytes::from_static(b"1"), 0);
+        cache.insert(
+            TableId(1),
+            RowId(2),
+            Some(true),
+            Bytes::from_static(b"2"),
+            0,
+        );
+
+        // Using row 1 makes row 2 the least recently used one
+        assert_eq!(
+            cache.get(TableId(1), RowId(1), None),
+            Some(Bytes::from_static(b"1"))
+        );
+        assert_eq!(cache.get(TableId(1), RowId(1), Some(true)), None);
+        cache.insert(TableId(1), RowId(3), None, Bytes::from_static(b"3"), 0);
+        assert_eq!(cache.get(TableId(1), RowId(2), Some(true)), None);
+        assert!(cache.get(TableId(1), RowId(3), None).is_some());
+
+        cache.invalidate(TableId(1), RowId(1));
+        assert_eq!(cache.get(TableId(1), RowId(1), None), None);
+
+        let cache = RowCache::new(2, Duration::ZERO);
+        cache.insert(TableId(1), RowId(1), None, Bytes::from_static(b"1"), 0);
+        assert_eq!(cache.get(TableId(1), RowId(1), None), None);
+    }
+
+    #[test]
+    fn test_stale_rows_are_not_cached() {
+        let cache = RowCache::new(2, Duration::from_secs(60));
+
+        // A read started before a change completes after it
+        let generation = cache.generation(TableId(1), RowId(1));
+        cache.invalidate(TableId(1), RowId(1));
+        cache.insert(
+            TableId(1),
+            RowId(1),
+            None,
+            Bytes::from_static(b"old"),
+            generation,
+        );
+        assert_eq!(cache.get(TableId(1), RowId(1), None), None);
+
+        let generation = cache.generation(TableId(1), RowId(1));
+        cache.insert(
+            TableId(1),
+            RowId(1),
+            None,
+            Bytes::from_static(b"new"),
+            generation,
+        );
+        assert_eq!(
+            cache.get(TableId(1), RowId(1), None),
+            Some(Bytes::from_static(b"new"))
+        );
+    }
+}
@@ -0,0 +1,934 @@
+//! A feed of the row changes of a table
+//!
+//! [`ChangeFeed`] receives changes over Baserow's WebSocket API when the
+//! `realtime` feature is enabled and the server is reachable that way. When
+//! it is not, e.g. with a configured proxy or with database tokens, the feed
+//! polls the table like [`BaserowTable::watch`] instead,
+//! fetching the rows modified since the last poll and the IDs of all rows.
+//! Both yield the same stream of [`Change`]s.
+//!
+//! For periodic syncs, [`BaserowTable::changes_since`] returns the rows
+//! changed since a [`Checkpoint`] instead.
+//!
+//! Requires the `changes` feature.
+//!
+//! # Example
+//! ```no_run
+//! use baserow_rs::{
+//!     api::client::BaserowClient, changes::{Change, ChangeFeed}, Baserow, ConfigBuilder, TableId,
+//! };
+//! use futures_util::StreamExt;
+//! use std::time::Duration;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let config = ConfigBuilder::new()
+//!         .base_url("https://api.baserow.io")
+//!         .api_key("your-api-key")
+//!         .try_build()
+//!         .expect("invalid configuration");
+//!     let baserow = Baserow::with_configuration(config);
+//!
+//!     let table = baserow.table_by_id(TableId(1234));
+//!     let mut feed = ChangeFeed::connect(table, Duration::from_secs(30)).await.unwrap();
+//!     while let Some(change) = feed.next().await {
+//!         match change {
+//!             Ok(Change::Created { id, .. }) => println!("Row {} was created", id),
+//!             Ok(Change::Updated { id, .. }) => println!("Row {} was updated", id),
+//!             Ok(Change::Deleted { id }) => println!("Row {} was deleted", id),
+//!             Ok(Change::Resync) => println!("Changes were missed, reloading"),
+//!             Err(e) => println!("Polling failed: {}", e),
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap, HashMap, HashSet},
+    fs,
+    hash::{Hash, Hasher},
+    io,
+    path::Path,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures_util::Stream;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::{sync::mpsc, task::JoinHandle, time::MissedTickBehavior};
+use tracing::{debug, instrument, warn};
+
+use crate::{
+    api::table_operations::{row_id, RowMetadata, RowRequest},
+    error::BaserowError,
+    filter::{exact_date, Filter, FilterTriple},
+    ids::RowId,
+    BaserowTable, FieldNamesMode, TableField,
+};
+
+/// A change of a row
+#[derive(Clone, Debug, PartialEq)]
+pub enum Change {
+    /// A row was created
+    Created {
+        id: RowId,
+        row: HashMap<String, Value>,
+    },
+    /// A row was updated
+    Updated {
+        id: RowId,
+        row: HashMap<String, Value>,
+    },
+    /// A row was deleted
+    Deleted { id: RowId },
+    /// Changes may have been missed, so reload the rows of the table to catch up
+    Resync,
+}
+
+/// A stream of the row changes of a table
+///
+/// Polling errors are yielded as well, polling continues afterwards. The
+/// feed stops once dropped.
+pub struct ChangeFeed {
+    changes: mpsc::UnboundedReceiver<Result<Change, BaserowError>>,
+    realtime: Arc<AtomicBool>,
+    task: JoinHandle<()>,
+}
+
+impl ChangeFeed {
+    /// Starts a feed of the changes of a table
+    ///
+    /// Tries the WebSocket API first if the `realtime` feature is enabled and
+    /// falls back to polling every `poll_interval` otherwise, see
+    /// [`BaserowTable::watch`] for how polls detect changes. A feed whose
+    /// WebSocket connection is lost for good yields [`Change::Resync`] and
+    /// continues polling.
+    ///
+    /// Fails with `InvalidRequest` if the poll interval is zero, even if the
+    /// WebSocket API is used, as the feed may fall back to polling later.
+    #[instrument(skip(table), fields(table_id = ?table.id), err)]
+    pub async fn connect(
+        table: BaserowTable,
+        poll_interval: Duration,
+    ) -> Result<Self, BaserowError> {
+        table.id.ok_or(BaserowError::MissingState("Table ID"))?;
+        table
+            .baserow
+            .as_ref()
+            .ok_or(BaserowError::MissingState("Baserow instance"))?;
+        validate_interval(poll_interval)?;
+
+        let (sender, changes) = mpsc::unbounded_channel();
+        let realtime = Arc::new(AtomicBool::new(false));
+
+        #[cfg(feature = "realtime")]
+        let task = {
+            let subscription = websocket::subscribe(&table).await;
+            realtime.store(subscription.is_some(), Ordering::Relaxed);
+            let realtime = realtime.clone();
+            tokio::spawn(async move {
+                if let Some(subscription) = subscription {
+                    if !websocket::forward(subscription, &table, &sender).await {
+                        return;
+                    }
+                    warn!("Realtime connection lost, falling back to polling");
+                    realtime.store(false, Ordering::Relaxed);
+                    if sender.send(Ok(Change::Resync)).is_err() {
+                        return;
+                    }
+                }
+                poll(Poller::new(table), poll_interval, &sender).await;
+            })
+        };
+        #[cfg(not(feature = "realtime"))]
+        let task =
+            tokio::spawn(async move { poll(Poller::new(table), poll_interval, &sender).await });
+
+        Ok(Self {
+            changes,
+            realtime,
+            task,
+        })
+    }
+
+    /// Returns whether the changes are currently received over the WebSocket API
+    pub fn is_realtime(&self) -> bool {
+        self.realtime.load(Ordering::Relaxed)
+    }
+}
+
+impl Stream for ChangeFeed {
+    type Item = Result<Change, BaserowError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.changes.poll_recv(cx)
+    }
+}
+
+impl Drop for ChangeFeed {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(feature = "realtime")]
+mod websocket {
+    use futures_util::StreamExt;
+    use tokio::sync::mpsc;
+    use tracing::warn;
+
+    use super::{row_id, Change};
+    use crate::{
+        error::BaserowError,
+        realtime::{EventKind, EventRow, RealtimeClient, RealtimeEvent, Subscription},
+        BaserowTable,
+    };
+
+    /// Subscribes to the table over the WebSocket API, if possible
+    pub(super) async fn subscribe(table: &BaserowTable) -> Option<Subscription> {
+        let baserow = table.baserow.clone()?;
+        let table_id = table.id?;
+        match RealtimeClient::connect(baserow).await {
+            Ok(client) => client.subscribe_table(table_id).ok(),
+            Err(e) => {
+                warn!(error = %e, "Realtime connection unavailable, polling for changes");
+                None
+            }
+        }
+    }
+
+    /// Forwards the row events of a subscription as changes
+    ///
+    /// Returns whether the subscription ended while the feed is still used.
+    pub(super) async fn forward(
+        mut subscription: Subscription,
+        table: &BaserowTable,
+        sender: &mpsc::UnboundedSender<Result<Change, BaserowError>>,
+    ) -> bool {
+        while let Some(event) = subscription.next().await {
+            for change in changes(event, table) {
+                if sender.send(Ok(change)).is_err() {
+                    return false;
+                }
+            }
+        }
+        !sender.is_closed()
+    }
+
+    fn changes(event: RealtimeEvent, table: &BaserowTable) -> Vec<Change> {
+        // Rows are sent with field IDs, so use field names like the table operations do
+        let row = |row: EventRow| match &table.mapper {
+            Some(mapper) => mapper.convert_to_field_names(row),
+            None => row,
+        };
+        match event.kind {
+            EventKind::RowsCreated { rows, .. } => rows
+                .into_iter()
+                .filter_map(|r| {
+                    Some(Change::Created {
+                        id: row_id(&r)?,
+                        row: row(r),
+                    })
+                })
+                .collect(),
+            EventKind::RowsUpdated { rows, .. } => rows
+                .into_iter()
+                .filter_map(|r| {
+                    Some(Change::Updated {
+                        id: row_id(&r)?,
+                        row: row(r),
+                    })
+                })
+                .collect(),
+            EventKind::RowsDeleted { row_ids, .. } => row_ids
+                .into_iter()
+                .map(|id| Change::Deleted { id })
+                .collect(),
+            EventKind::Reconnected => vec![Change::Resync],
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// The state of a row a poll compares to detect updates
+#[derive(PartialEq)]
+struct RowState {
+    updated_on: Option<String>,
+    /// Catches changes without a new `updated_on`, e.g. of formula fields or
+    /// on Baserow versions not reporting row metadata
+    checksum: u64,
+}
+
+impl RowState {
+    fn of(row: &HashMap<String, Value>) -> Self {
+        // Hash the values in a fixed order, as the order of a HashMap is random
+        let mut hasher = DefaultHasher::new();
+        for (key, value) in row.iter().collect::<BTreeMap<_, _>>() {
+            key.hash(&mut hasher);
+            value.to_string().hash(&mut hasher);
+        }
+        Self {
+            updated_on: RowMetadata::from_row(row).updated_on,
+            checksum: hasher.finish(),
+        }
+    }
+}
+
+impl BaserowTable {
+    /// Watches the rows of the table for changes by polling them every `interval`
+    ///
+    /// The first poll fetches all rows to record their state. Later polls
+    /// only fetch the rows modified on or after the day of the newest known
+    /// modification, via the last modified field of the table, and the IDs
+    /// of all rows to detect deletions. Tables without a last modified field
+    /// are fetched in full on every poll. Rows are compared by their last
+    /// modification and a checksum of their values, so changes are detected
+    /// on any Baserow version. Unlike [`ChangeFeed::connect`], the WebSocket
+    /// API is never used.
+    ///
+    /// Fails with `InvalidRequest` if the interval is zero.
+    ///
+    /// Must be called from within a tokio runtime, as polling runs in a
+    /// spawned task.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use baserow_rs::{api::client::BaserowClient, changes::Change, Baserow, ConfigBuilder, TableId};
+    /// use futures_util::StreamExt;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let config = ConfigBuilder::new()
+    ///         .base_url("https://api.baserow.io")
+    ///         .api_key("your-api-key")
+    ///         .try_build()
+    ///         .expect("invalid configuration");
+    ///     let baserow = Baserow::with_configuration(config);
+    ///
+    ///     let mut changes = baserow
+    ///         .table_by_id(TableId(1234))
+    ///         .watch(Duration::from_secs(60))
+    ///         .unwrap();
+    ///     while let Some(Ok(change)) = changes.next().await {
+    ///         if let Change::Deleted { id } = change {
+    ///             println!("Row {} was deleted", id);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn watch(&self, interval: Duration) -> Result<ChangeFeed, BaserowError> {
+        self.id.ok_or(BaserowError::MissingState("Table ID"))?;
+        self.baserow
+            .as_ref()
+            .ok_or(BaserowError::MissingState("Baserow instance"))?;
+        validate_interval(interval)?;
+
+        let (sender, changes) = mpsc::unbounded_channel();
+        let poller = Poller::new(self.clone());
+        let task = tokio::spawn(async move { poll(poller, interval, &sender).await });
+        Ok(ChangeFeed {
+            changes,
+            realtime: Arc::new(AtomicBool::new(false)),
+            task,
+        })
+    }
+}
+
+/// The point in time up to which the changes of a table were processed
+///
+/// # Example
+/// ```no_run
+/// use baserow_rs::changes::Checkpoint;
+///
+/// let checkpoint = Checkpoint::load("customers.checkpoint")
+///     .unwrap()
+///     .unwrap_or_else(|| Checkpoint::new("1970-01-01T00:00:00Z"));
+/// checkpoint.save("customers.checkpoint").unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// The last modification of the newest processed row, as ISO 8601 timestamp in UTC
+    pub timestamp: String,
+}
+
+impl Checkpoint {
+    pub fn new(timestamp: impl Into<String>) -> Self {
+        Self {
+            timestamp: timestamp.into(),
+        }
+    }
+
+    /// Reads a checkpoint written by `save`, or `None` if the file does not exist
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Option<Self>> {
+        match fs::read(path) {
+            Ok(contents) => Ok(Some(serde_json::from_slice(&contents)?)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Writes the checkpoint to a file, replacing it if it exists
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, serde_json::to_vec(self)?)
+    }
+}
+
+/// The rows changed since a checkpoint, see [`BaserowTable::changes_since`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChangedRows {
+    /// The created and updated rows, including their metadata
+    pub rows: Vec<HashMap<String, Value>>,
+    /// The checkpoint to pass to the next call
+    pub checkpoint: Checkpoint,
+}
+
+/// Returns a key ordering ISO 8601 timestamps in UTC chronologically
+///
+/// Baserow omits the fractional seconds of timestamps on whole seconds, so
+/// they cannot be compared as strings.
+fn timestamp_key(timestamp: &str) -> Option<(&str, u32)> {
+    let timestamp = timestamp
+        .strip_suffix('Z')
+        .or_else(|| timestamp.strip_suffix("+00:00"))?;
+    let (seconds, fraction) = timestamp.split_once('.').unwrap_or((timestamp, ""));
+    let micros = format!("{:0<6}", fraction).get(..6)?.parse().ok()?;
+    Some((seconds, micros))
+}
+
+impl BaserowTable {
+    /// Returns the rows created or updated after the checkpoint
+    ///
+    /// If the table has a last modified field, only rows modified on or
+    /// after the day of the checkpoint are fetched, otherwise all rows are.
+    /// Rows are compared by their `updated_on` metadata either way. Deleted
+    /// rows are not reported, use [`ChangeFeed`] or `sync` to catch those.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use baserow_rs::{api::client::BaserowClient, changes::Checkpoint, Baserow, ConfigBuilder, TableId};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let config = ConfigBuilder::new()
+    ///         .base_url("https://api.baserow.io")
+    ///         .api_key("your-api-key")
+    ///         .try_build()
+    ///         .expect("invalid configuration");
+    ///     let table = Baserow::with_configuration(config).table_by_id(TableId(1234));
+    ///
+    ///     let checkpoint = Checkpoint::load("table.checkpoint")
+    ///         .unwrap()
+    ///         .unwrap_or_else(|| Checkpoint::new("1970-01-01T00:00:00Z"));
+    ///     let changes = table.changes_since(&checkpoint).await.unwrap();
+    ///     println!("{} rows changed", changes.rows.len());
+    ///     changes.checkpoint.save("table.checkpoint").unwrap();
+    /// }
+    /// ```
+    #[instrument(skip(self), fields(table_id = ?self.id), err)]
+    pub async fn changes_since(&self, since: &Checkpoint) -> Result<ChangedRows, BaserowError> {
+        let since_key = timestamp_key(&since.timestamp).ok_or_else(|| {
+            BaserowError::InvalidRequest(format!(
+                "Invalid checkpoint timestamp {}",
+                since.timestamp
+            ))
+        })?;
+
+        let fields = self.fields().await?;
+
+        let mut request = RowRequest {
+            page_size: Some(200),
+            include_metadata: true,
+            ..Default::default()
+        };
+        match fields.iter().find(|field| field.r#type == "last_modified") {
+            Some(field) => {
+                request.filter = Some(vec![self.modified_on_or_after(field, &since.timestamp)])
+            }
+            None => debug!("Table has no last modified field, fetching all rows"),
+        }
+
+        let mut checkpoint = since.clone();
+        let mut rows = Vec::new();
+        for row in self.all_rows(request).await? {
+            let Some(updated_on) = RowMetadata::from_row(&row).updated_on else {
+                continue;
+            };
+            let Some(key) = timestamp_key(&updated_on) else {
+                warn!(
+                    updated_on,
+                    "Ignoring row with invalid modification timestamp"
+                );
+                continue;
+            };
+            if key <= since_key {
+                continue;
+            }
+            if timestamp_key(&checkpoint.timestamp).is_none_or(|newest| key > newest) {
+                checkpoint = Checkpoint::new(updated_on);
+            }
+            rows.push(row);
+        }
+
+        debug!(row_count = rows.len(), "Fetched changed rows");
+        Ok(ChangedRows { rows, checkpoint })
+    }
+
+    /// Returns the condition matching the rows modified on or after the day of a timestamp
+    fn modified_on_or_after(&self, last_modified: &TableField, timestamp: &str) -> FilterTriple {
+        let day = timestamp.get(..10).unwrap_or_default();
+        let field_key = match self.field_names() {
+            FieldNamesMode::UserNames => last_modified.name.clone(),
+            _ => format!("field_{}", last_modified.id),
+        };
+        FilterTriple {
+            field: field_key,
+            filter: Filter::DateIsOnOrAfter,
+            value: exact_date(day),
+        }
+    }
+}
+
+/// Detects row changes by comparing the rows of a table between polls
+///
+/// The first poll fetches all rows to record their state. Later polls fetch
+/// the rows modified on or after the day of the newest known modification,
+/// using the last modified field of the table, and the IDs of all rows to
+/// detect deletions. Tables without a last modified field, or rows without
+/// metadata, are fetched in full on every poll.
+struct Poller {
+    table: BaserowTable,
+    /// The last modified field of the table, once looked up
+    last_modified: Option<Option<TableField>>,
+    /// The newest modification of a row seen so far
+    newest: Option<String>,
+    /// The state of every row as of the last poll
+    snapshot: Option<HashMap<RowId, RowState>>,
+}
+
+impl Poller {
+    fn new(table: BaserowTable) -> Self {
+        Self {
+            table,
+            last_modified: None,
+            newest: None,
+            snapshot: None,
+        }
+    }
+
+    /// Fetches the rows of the table matching the filter with their metadata
+    async fn rows(
+        &self,
+        filter: Option<FilterTriple>,
+    ) -> Result<Vec<HashMap<String, Value>>, BaserowError> {
+        let request = RowRequest {
+            filter: filter.map(|filter| vec![filter]),
+            page_size: Some(200),
+            include_metadata: true,
+            ..Default::default()
+        };
+        self.table.all_rows(request).await
+    }
+
+    /// Fetches the IDs of all rows of the table, without their values
+    async fn ids(&self) -> Result<HashSet<RowId>, BaserowError> {
+        let request = RowRequest {
+            fields: Some(vec!["id".to_string()]),
+            page_size: Some(200),
+            ..Default::default()
+        };
+        Ok(self
+            .table
+            .all_rows(request)
+            .await?
+            .iter()
+            .filter_map(row_id)
+            .collect())
+    }
+
+    /// Returns the filter for the rows modified since the last poll, if the table allows one
+    async fn modified_filter(&mut self) -> Result<Option<FilterTriple>, BaserowError> {
+        let Some(newest) = &self.newest else {
+            return Ok(None);
+        };
+        if self.last_modified.is_none() {
+            let fields = self.table.fields().await?;
+            let field = fields
+                .into_iter()
+                .find(|field| field.r#type == "last_modified");
+            if field.is_none() {
+                debug!("Table has no last modified field, polling all rows");
+            }
+            self.last_modified = Some(field);
+        }
+        Ok(self
+            .last_modified
+            .as_ref()
+            .and_then(Option::as_ref)
+            .map(|field| self.table.modified_on_or_after(field, newest)))
+    }
+
+    /// Returns the changes since the last poll
+    ///
+    /// The first poll only records the current rows.
+    async fn poll(&mut self) -> Result<Vec<Change>, BaserowError> {
+        let Some(mut snapshot) = self.snapshot.take() else {
+            let rows = self.rows(None).await?;
+            let mut snapshot = HashMap::with_capacity(rows.len());
+            for row in rows {
+                if let Some(id) = row_id(&row) {
+                    self.observe(&row);
+                    snapshot.insert(id, RowState::of(&row));
+                }
+            }
+            self.snapshot = Some(snapshot);
+            return Ok(Vec::new());
+        };
+        // Keep the snapshot of the last successful poll if this one fails
+        let changes = self.changes(&mut snapshot).await;
+        self.snapshot = Some(snapshot);
+        changes
+    }
+
+    async fn changes(
+        &mut self,
+        snapshot: &mut HashMap<RowId, RowState>,
+    ) -> Result<Vec<Change>, BaserowError> {
+        let filter = self.modified_filter().await?;
+        let filtered = filter.is_some();
+        let rows = self.rows(filter).await?;
+        // Without a filter, the rows tell which rows still exist
+        let ids = match filtered {
+            true => self.ids().await?,
+            false => rows.iter().filter_map(row_id).collect(),
+        };
+
+        let mut changes = Vec::new();
+        for row in rows {
+            let Some(id) = row_id(&row) else {
+                continue;
+            };
+            self.observe(&row);
+            let state = RowState::of(&row);
+            match snapshot.get(&id) {
+                None => changes.push(Change::Created { id, row }),
+                Some(previous) if *previous != state => changes.push(Change::Updated { id, row }),
+                _ => {}
+            }
+            snapshot.insert(id, state);
+        }
+
+        let mut deleted: Vec<RowId> = snapshot
+            .keys()
+            .filter(|id| !ids.contains(id))
+            .copied()
+            .collect();
+        deleted.sort_unstable();
+        for id in &deleted {
+            snapshot.remove(id);
+        }
+        changes.extend(deleted.into_iter().map(|id| Change::Deleted { id }));
+        Ok(changes)
+    }
+
+    /// Records the modification of a row if it is the newest seen so far
+    fn observe(&mut self, row: &HashMap<String, Value>) {
+        let Some(updated_on) = RowMetadata::from_row(row).updated_on else {
+            return;
+        };
+        let Some(key) = timestamp_key(&updated_on) else {
+            return;
+        };
+        if self
+            .newest
+            .as_deref()
+            .and_then(timestamp_key)
+            .is_none_or(|newest| key > newest)
+        {
+            self.newest = Some(updated_on);
+        }
+    }
+}
+
+/// Rejects a zero poll interval, which polls could not be scheduled with
+fn validate_interval(interval: Duration) -> Result<(), BaserowError> {
+    if interval.is_zero() {
+        let error = BaserowError::InvalidRequest("Poll interval must not be zero".to_string());
+        error.log();
+        return Err(error);
+    }
+    Ok(())
+}
+
+/// Polls for changes until the feed is dropped
+async fn poll(
+    mut poller: Poller,
+    interval: Duration,
+    sender: &mpsc::UnboundedSender<Result<Change, BaserowError>>,
+) {
+    let mut ticks = tokio::time::interval(interval);
+    ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    loop {
+        ticks.tick().await;
+        if sender.is_closed() {
+            return;
+        }
+        match poller.poll().await {
+            Ok(changes) => {
+                debug!(changes = changes.len(), "Polled table for changes");
+                for change in changes {
+                    if sender.send(Ok(change)).is_err() {
+                        return;
+                    }
+                }
+            }
+            Err(e) => {
+                warn!(error = %e, "Polling table for changes failed");
+                if sender.send(Err(e)).is_err() {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{api::client::BaserowClient, Baserow, ConfigBuilder, TableId};
+    use futures_util::StreamExt;
+    use serde_json::json;
+
+    #[test]
+    fn test_timestamp_key() {
+        let whole = timestamp_key("2024-01-02T10:00:00Z").unwrap();
+        let fraction = timestamp_key("2024-01-02T10:00:00.5Z").unwrap();
+        assert!(whole < fraction);
+        assert!(fraction < timestamp_key("2024-01-02T10:00:01+00:00").unwrap());
+        assert_eq!(timestamp_key("2024-01-02T10:00:00+02:00"), None);
+    }
+
+    #[test]
+    fn test_checkpoint_persistence() {
+        let path = std::env::temp_dir().join(format!("baserow-checkpoint-{}", std::process::id()));
+        assert_eq!(Checkpoint::load(&path).unwrap(), None);
+
+        let checkpoint = Checkpoint::new("2024-01-02T10:00:00Z");
+        checkpoint.save(&path).unwrap();
+        assert_eq!(Checkpoint::load(&path).unwrap(), Some(checkpoint));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_changes_since() {
+        let mut server = mockito::Server::new_async().await;
+
+        let fields_mock = server
+            .mock("GET", "/api/database/fields/table/1/")
+            .with_body(
+                json!([
+                    {"id": 1, "table_id": 1, "name": "Name", "order": 0, "type": "text", "primary": true, "read_only": false},
+                    {"id": 3, "table_id": 1, "name": "Modified", "order": 1, "type": "last_modified", "primary": false, "read_only": true}
+                ])
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let rows_mock = server
+            .mock("GET", "/api/database/rows/table/1/")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded(
+                    "filter__field_3__date_is_on_or_after".into(),
+                    "UTC?2024-01-02?exact_date".into(),
+                ),
+                mockito::Matcher::UrlEncoded("include".into(), "metadata".into()),
+            ]))
+            .with_body(
+                json!({
+                    "count": 3,
+                    "next": null,
+                    "previous": null,
+                    "results": [
+                        {"id": 1, "updated_on": "2024-01-02T09:00:00Z", "Name": "A"},
+                        {"id": 2, "updated_on": "2024-01-02T10:00:00.250000Z", "Name": "B"},
+                        {"id": 3, "updated_on": "2024-01-02T10:00:00.5Z", "Name": "C"}
+                    ]
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&server.url())
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let table = Baserow::with_configuration(configuration).table_by_id(TableId(1));
+
+        let changes = table
+            .changes_since(&Checkpoint::new("2024-01-02T10:00:00Z"))
+            .await
+            .unwrap();
+        let ids: Vec<_> = changes.rows.iter().filter_map(row_id).collect();
+        assert_eq!(ids, vec![RowId(2), RowId(3)]);
+        assert_eq!(changes.checkpoint.timestamp, "2024-01-02T10:00:00.5Z");
+
+        fields_mock.assert_async().await;
+        rows_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_watch_without_metadata() {
+        let mut server = mockito::Server::new_async().await;
+        let rows =
+            |rows: Value| json!({"count": 1, "next": null, "previous": null, "results": rows});
+
+        let initial = server
+            .mock("GET", "/api/database/rows/table/1/")
+            .match_query(mockito::Matcher::Any)
+            .with_body(rows(json!([{"id": 1, "Name": "A", "Tags": [1, 2]}])).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+        let changed = server
+            .mock("GET", "/api/database/rows/table/1/")
+            .match_query(mockito::Matcher::Any)
+            .with_body(rows(json!([{"id": 1, "Name": "A", "Tags": [2]}])).to_string())
+            .expect_at_least(1)
+            .create_async()
+            .await;
+
+        let config = ConfigBuilder::new()
+            .base_url(&server.url())
+            .api_key("database-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(config);
+        let mut changes = baserow
+            .table_by_id(TableId(1))
+            .watch(Duration::from_millis(10))
+            .unwrap();
+
+        // The row is reported once, as the unchanged state of later polls is not an update
+        let change = changes.next().await.unwrap().unwrap();
+        assert!(
+            matches!(change, Change::Updated { id: RowId(1), ref row } if row["Tags"] == json!([2]))
+        );
+        let next = tokio::time::timeout(Duration::from_millis(50), changes.next()).await;
+        assert!(next.is_err());
+
+        initial.assert_async().await;
+        changed.assert_async().await;
+
+        assert!(matches!(
+            BaserowTable::default().watch(Duration::from_secs(1)),
+            Err(BaserowError::MissingState("Table ID"))
+        ));
+        assert!(matches!(
+            baserow.table_by_id(TableId(1)).watch(Duration::ZERO),
+            Err(BaserowError::InvalidRequest(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_polling_change_feed() {
+        let mut server = mockito::Server::new_async().await;
+        let rows =
+            |rows: Value| json!({"count": 2, "next": null, "previous": null, "results": rows});
+
+        let fields = server
+            .mock("GET", "/api/database/fields/table/1/")
+            .with_body(
+                json!([
+                    {"id": 1, "table_id": 1, "name": "Name", "order": 0, "type": "text", "primary": true, "read_only": false},
+                    {"id": 3, "table_id": 1, "name": "Modified", "order": 1, "type": "last_modified", "primary": false, "read_only": true}
+                ])
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let initial = server
+            .mock("GET", "/api/database/rows/table/1/")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "include".into(),
+                "metadata".into(),
+            ))
+            .with_body(
+                rows(json!([
+                    {"id": 1, "updated_on": "2024-01-01T00:00:00Z", "Name": "A"},
+                    {"id": 2, "updated_on": "2024-01-01T00:00:00Z", "Name": "B"}
+                ]))
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        // Later polls only fetch the rows modified since the newest known modification
+        let modified = server
+            .mock("GET", "/api/database/rows/table/1/")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex(
+                    "filter__field_3__date_is_on_or_after=UTC%3F2024-01-0[12]%3Fexact_date".into(),
+                ),
+                mockito::Matcher::UrlEncoded("include".into(), "metadata".into()),
+            ]))
+            .with_body(
+                rows(json!([
+                    {"id": 1, "updated_on": "2024-01-02T00:00:00Z", "Name": "A2"},
+                    {"id": 3, "updated_on": "2024-01-02T00:00:00Z", "Name": "C"}
+                ]))
+                .to_string(),
+            )
+            .expect_at_least(1)
+            .create_async()
+            .await;
+        let ids = server
+            .mock("GET", "/api/database/rows/table/1/")
+            .match_query(mockito::Matcher::UrlEncoded("include".into(), "id".into()))
+            .with_body(rows(json!([{"id": 1}, {"id": 3}])).to_string())
+            .expect_at_least(1)
+            .create_async()
+            .await;
+
+        let config = ConfigBuilder::new()
+            .base_url(&server.url())
+            .api_key("database-token")
+            .try_build()
+            .unwrap();
+        let table = Baserow::with_configuration(config).table_by_id(TableId(1));
+        assert!(matches!(
+            ChangeFeed::connect(table.clone(), Duration::ZERO).await,
+            Err(BaserowError::InvalidRequest(_))
+        ));
+        let mut feed = ChangeFeed::connect(table, Duration::from_millis(10))
+            .await
+            .unwrap();
+        assert!(!feed.is_realtime());
+
+        let change = feed.next().await.unwrap().unwrap();
+        assert!(matches!(change, Change::Updated { id: RowId(1), ref row } if row["Name"] == "A2"));
+        let change = feed.next().await.unwrap().unwrap();
+        assert!(matches!(change, Change::Created { id: RowId(3), .. }));
+        assert_eq!(
+            feed.next().await.unwrap().unwrap(),
+            Change::Deleted { id: RowId(2) }
+        );
+
+        let next = tokio::time::timeout(Duration::from_millis(50), feed.next()).await;
+        assert!(next.is_err());
+
+        drop(feed);
+        fields.assert_async().await;
+        initial.assert_async().await;
+        modified.assert_async().await;
+        ids.assert_async().await;
+    }
+}
@@ -0,0 +1,258 @@
+//! Generation of Rust structs from table schemas
+//!
+//! [`generate_struct`] emits a struct with a field per table field, typed
+//! after the field type and renamed to the key rows use, so typed models
+//! don't have to be kept in sync with the table by hand. Read-only fields,
+//! like formulas, are skipped when serializing, so the struct can be written
+//! back as is.
+//!
+//! Requires the `codegen` feature.
+//!
+//! # Example
+//!
+//! Generating the models in a build script, with the `blocking` feature
+//! enabled in the build dependencies:
+//! ```no_run
+//! # #[cfg(feature = "blocking")]
+//! # fn main() {
+//! use baserow_rs::{blocking::Baserow, codegen::generate_struct, ConfigBuilder, FieldNamesMode, TableId};
+//! use std::{env, fs, path::Path};
+//!
+//! let config = ConfigBuilder::new()
+//!     .base_url("https://api.baserow.io")
+//!     .api_key(&env::var("BASEROW_TOKEN").unwrap())
+//!     .try_build()
+//!     .expect("invalid configuration");
+//! let baserow = Baserow::with_configuration(config).unwrap();
+//!
+//! let fields = baserow.table_fields(TableId(1234)).unwrap();
+//! let code = generate_struct("Customer", &fields, FieldNamesMode::UserNames);
+//! fs::write(Path::new(&env::var("OUT_DIR").unwrap()).join("customer.rs"), code).unwrap();
+//! # }
+//! # #[cfg(not(feature = "blocking"))]
+//! # fn main() {}
+//! ```
+//!
+//! The generated file is then included with
+//! `include!(concat!(env!("OUT_DIR"), "/customer.rs"));`.
+
+use std::{collections::HashSet, fmt::Write};
+
+use tracing::instrument;
+
+use crate::{
+    api::client::BaserowClient, error::BaserowError, ids::TableId,
+    value::formula_result_field_type, FieldNamesMode, TableField,
+};
+
+/// Rust keywords, which cannot be used as field names without `r#`
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use",
+    "where", "while", "abstract", "become", "box", "do", "final", "gen", "macro", "override",
+    "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
+
+/// The Rust type of a field and the serde attributes it needs
+struct FieldType {
+    rust_type: &'static str,
+    with: Option<&'static str>,
+}
+
+impl FieldType {
+    fn new(rust_type: &'static str) -> Self {
+        Self {
+            rust_type,
+            with: None,
+        }
+    }
+
+    fn with(rust_type: &'static str, with: &'static str) -> Self {
+        Self {
+            rust_type,
+            with: Some(with),
+        }
+    }
+
+    /// Maps a field type, as reported in `TableField::type`, to a Rust type
+    fn of(field_type: &str) -> Self {
+        match field_type {
+            "text" | "long_text" | "phone_number" | "url" | "email" | "uuid" | "date"
+            | "created_on" | "last_modified" => Self::new("Option<String>"),
+            "number" | "count" => Self::with("Option<f64>", "baserow_rs::value::number::option"),
+            "rating" => Self::new("u8"),
+            "autonumber" => Self::new("Option<u64>"),
+            "boolean" => Self::new("bool"),
+            "single_select" => Self::new("Option<baserow_rs::value::SelectOption>"),
+            "multiple_select" => Self::new("Vec<baserow_rs::value::SelectOption>"),
+            "link_row" => Self::new("Vec<baserow_rs::value::LinkedRow>"),
+            "file" => Self::new("Vec<baserow_rs::api::file::BaserowFileRef>"),
+            "created_by" | "last_modified_by" => {
+                Self::new("Option<baserow_rs::value::Collaborator>")
+            }
+            "multiple_collaborators" => Self::new("Vec<baserow_rs::value::Collaborator>"),
+            "duration" => Self::with(
+                "Option<std::time::Duration>",
+                "baserow_rs::value::duration_seconds::option",
+            ),
+            _ => Self::new("serde_json::Value"),
+        }
+    }
+
+    /// Maps a field to a Rust type, taking the result type of formulas into account
+    fn of_field(field: &TableField) -> Self {
+        match field.formula_type.as_deref() {
+            Some("array") => Self::new("Vec<serde_json::Value>"),
+            Some(formula_type) => Self::of(formula_result_field_type(formula_type)),
+            None => Self::of(&field.r#type),
+        }
+    }
+}
+
+/// Converts a field name into a snake case identifier
+fn identifier(name: &str) -> String {
+    let mut ident = String::new();
+    for c in name.chars() {
+        if c.is_alphanumeric() {
+            ident.extend(c.to_lowercase());
+        } else if !ident.is_empty() && !ident.ends_with('_') {
+            ident.push('_');
+        }
+    }
+    let ident = ident.trim_end_matches('_');
+
+    match ident {
+        "" => "field".to_string(),
+        ident if ident.starts_with(|c: char| c.is_ascii_digit()) => format!("field_{}", ident),
+        "self" | "super" | "crate" => format!("{}_", ident),
+        ident if KEYWORDS.contains(&ident) => format!("r#{}", ident),
+        ident => ident.to_string(),
+    }
+}
+
+/// Generates the source of a struct representing the rows of a table
+///
+/// The keys of the fields depend on how the rows are read: field names in
+/// `UserNames` and `Mapped` mode, `field_<id>` keys in `Ids` mode. Fields
+/// are typed after their field type, using the typed values of
+/// [`crate::value`] where available and `serde_json::Value` otherwise.
+pub fn generate_struct(name: &str, fields: &[TableField], mode: FieldNamesMode) -> String {
+    let mut fields: Vec<_> = fields.iter().collect();
+    fields.sort_by_key(|field| (field.order, field.id));
+
+    let mut code = String::new();
+    let _ = writeln!(code, "/// A row of the table, generated from its schema");
+    let _ = writeln!(
+        code,
+        "#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]"
+    );
+    let _ = writeln!(code, "pub struct {} {{", name);
+    let _ = writeln!(code, "    #[serde(default, skip_serializing)]");
+    let _ = writeln!(code, "    pub id: baserow_rs::RowId,");
+
+    let mut used = HashSet::from(["id".to_string()]);
+    for field in fields {
+        let mut ident = identifier(&field.name);
+        if !used.insert(ident.clone()) {
+            ident = format!("{}_{}", ident.trim_start_matches("r#"), field.id);
+            used.insert(ident.clone());
+        }
+        let key = match mode {
+            FieldNamesMode::Ids => format!("field_{}", field.id),
+            FieldNamesMode::UserNames | FieldNamesMode::Mapped => field.name.clone(),
+        };
+        let field_type = FieldType::of_field(field);
+
+        let mut attributes = vec![format!("rename = {:?}", key), "default".to_string()];
+        if let Some(with) = field_type.with {
+            attributes.push(format!("with = {:?}", with));
+        }
+        if field.read_only {
+            attributes.push("skip_serializing".to_string());
+        }
+
+        if let Some(description) = field.description.as_deref().filter(|d| !d.is_empty()) {
+            for line in description.lines() {
+                let _ = writeln!(code, "    /// {}", line.trim_end());
+            }
+        }
+        let _ = writeln!(code, "    #[serde({})]", attributes.join(", "));
+        let _ = writeln!(code, "    pub {}: {},", ident, field_type.rust_type);
+    }
+    let _ = writeln!(code, "}}");
+    code
+}
+
+/// Fetches the fields of a table and generates a struct for its rows, see [`generate_struct`]
+#[instrument(skip(client), err)]
+pub async fn generate_table_struct<C: BaserowClient + ?Sized>(
+    client: &C,
+    table_id: TableId,
+    name: &str,
+    mode: FieldNamesMode,
+) -> Result<String, BaserowError> {
+    let fields = client.table_fields(table_id).await?;
+    Ok(generate_struct(name, &fields, mode))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(id: u64, name: &str, r#type: &str, read_only: bool) -> TableField {
+        TableField {
+            primary: id == 1,
+            read_only,
+            ..TableField::new(name, id, r#type)
+        }
+    }
+
+    #[test]
+    fn test_identifier() {
+        assert_eq!(identifier("First Name"), "first_name");
+        assert_eq!(identifier("E-Mail (work)"), "e_mail_work");
+        assert_eq!(identifier("2nd Address"), "field_2nd_address");
+        assert_eq!(identifier("Type"), "r#type");
+        assert_eq!(identifier("Self"), "self_");
+        assert_eq!(identifier("?"), "field");
+    }
+
+    #[test]
+    fn test_generate_struct() {
+        let mut total = field(4, "Total", "formula", true);
+        total.formula_type = Some("number".to_string());
+        let fields = vec![
+            field(1, "Name", "text", false),
+            field(2, "Price", "number", false),
+            field(3, "Status", "single_select", false),
+            total,
+            field(5, "name", "boolean", false),
+        ];
+
+        let code = generate_struct("Product", &fields, FieldNamesMode::UserNames);
+        assert_eq!(
+            code,
+            r#"/// A row of the table, generated from its schema
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Product {
+    #[serde(default, skip_serializing)]
+    pub id: baserow_rs::RowId,
+    #[serde(rename = "Name", default)]
+    pub name: Option<String>,
+    #[serde(rename = "Price", default, with = "baserow_rs::value::number::option")]
+    pub price: Option<f64>,
+    #[serde(rename = "Status", default)]
+    pub status: Option<baserow_rs::value::SelectOption>,
+    #[serde(rename = "Total", default, with = "baserow_rs::value::number::option", skip_serializing)]
+    pub total: Option<f64>,
+    #[serde(rename = "name", default)]
+    pub name_5: bool,
+}
+"#
+        );
+
+        let code = generate_struct("Product", &fields[..1], FieldNamesMode::Ids);
+        assert!(code.contains(r#"#[serde(rename = "field_1", default)]"#));
+    }
+}
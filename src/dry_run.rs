@@ -0,0 +1,216 @@
+//! Dry runs of operations that change data
+//!
+//! A client set up via `Baserow::with_dry_run` sends reads as usual but
+//! doesn't send requests that change data. Instead, it logs and collects
+//! them, with their bodies as they would be sent, i.e. after field names were
+//! mapped to IDs, but with credentials like passwords redacted. Scripts like migrations can so be verified against the real
+//! data before they change it.
+//!
+//! Collected requests are answered with status 200 and the request body, so
+//! operations writing rows see the rows they sent, and deleting succeeds.
+//! Operations that depend on data generated by Baserow, like the IDs of
+//! created fields, fail to decode the answer.
+//!
+//! # Example
+//! ```no_run
+//! use baserow_rs::{prelude::*, api::client::BaserowClient};
+//! use serde_json::json;
+//! use std::collections::HashMap;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let config = ConfigBuilder::new()
+//!         .base_url("https://api.baserow.io")
+//!         .api_key("your-api-key")
+//!         .try_build()
+//!         .expect("invalid configuration");
+//!     let baserow = Baserow::with_configuration(config).with_dry_run();
+//!
+//!     let table = baserow.table_by_id(TableId(1234)).auto_map().await.unwrap();
+//!     let row = HashMap::from([("Status".to_string(), json!("Archived"))]);
+//!     table.update(RowId(1), row, None).await.unwrap();
+//!
+//!     for request in baserow.dry_run().unwrap().requests() {
+//!         println!("{}", request);
+//!     }
+//! }
+//! ```
+
+use std::{
+    fmt,
+    sync::{Mutex, PoisonError},
+};
+
+use reqwest::{header::CONTENT_TYPE, Method, Request, Response, ResponseBuilderExt, StatusCode};
+use serde::Serialize;
+use serde_json::Value;
+use tracing::info;
+
+use crate::secret::redact;
+
+/// Endpoints that are sent although they use POST, as they don't change data
+const READ_ONLY_ENDPOINTS: &[&str] = &["/api/user/token-auth/", "/api/user/token-refresh/"];
+
+/// A request that a dry run didn't send
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PlannedRequest {
+    /// The HTTP method, e.g. `PATCH`
+    pub method: String,
+    /// The URL including the query
+    pub url: String,
+    /// The body, if it was JSON, with credentials like passwords redacted
+    pub body: Option<Value>,
+}
+
+impl fmt::Display for PlannedRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method, self.url)?;
+        if let Some(body) = &self.body {
+            write!(f, " {}", body)?;
+        }
+        Ok(())
+    }
+}
+
+/// The requests collected by a dry run, see the [module documentation](self)
+#[derive(Debug, Default)]
+pub struct DryRun {
+    requests: Mutex<Vec<PlannedRequest>>,
+}
+
+impl DryRun {
+    /// Returns the requests that were not sent, in the order they were made
+    pub fn requests(&self) -> Vec<PlannedRequest> {
+        self.requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Returns the requests that were not sent and forgets them
+    pub fn take_requests(&self) -> Vec<PlannedRequest> {
+        std::mem::take(&mut *self.requests.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Returns whether a request is sent despite the dry run
+    pub(crate) fn sends(&self, request: &Request) -> bool {
+        matches!(
+            *request.method(),
+            Method::GET | Method::HEAD | Method::OPTIONS
+        ) || READ_ONLY_ENDPOINTS.contains(&request.url().path())
+    }
+
+    /// Collects a request and answers it with its own body
+    pub(crate) fn collect(&self, request: Request) -> Response {
+        let body = request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .map(<[u8]>::to_vec)
+            .unwrap_or_default();
+        let planned = PlannedRequest {
+            method: request.method().to_string(),
+            url: request.url().to_string(),
+            body: serde_json::from_slice(&body).ok().map(|mut json| {
+                redact(&mut json);
+                json
+            }),
+        };
+        info!(request = %planned, "Dry run, not sending request");
+        self.requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(planned);
+
+        let url = request.url().clone();
+        http::Response::builder()
+            .status(StatusCode::OK)
+            .header(CONTENT_TYPE, "application/json")
+            .url(url)
+            .body(body)
+            .map(Response::from)
+            .unwrap_or_else(|_| http::Response::new(Vec::new()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{client::BaserowClient, user::BaserowUserOperations},
+        fixtures::fields_json,
+        Baserow, BaserowTableOperations, ConfigBuilder, RowId, TableField, TableId,
+    };
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[tokio::test]
+    async fn test_dry_run() {
+        let mut server = mockito::Server::new_async().await;
+        let fields_mock = server
+            .mock("GET", "/api/database/fields/table/1234/")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(fields_json(&[TableField::text("Name", 1).as_primary()]))
+            .create_async()
+            .await;
+        let writes_mock = server
+            .mock("PATCH", mockito::Matcher::Any)
+            .expect(0)
+            .create_async()
+            .await;
+
+        let config = ConfigBuilder::new()
+            .base_url(&server.url())
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(config).with_dry_run();
+        let table = baserow.table_by_id(TableId(1234)).auto_map().await.unwrap();
+
+        let data = HashMap::from([("Name".to_string(), json!("Ada"))]);
+        let updated = table.update(RowId(1), data, None).await.unwrap();
+        assert_eq!(updated["Name"], "Ada");
+        let deleted = table.delete_many(vec![RowId(2), RowId(3)]).await.unwrap();
+        assert_eq!(deleted.rows().count(), 2);
+
+        let requests = baserow.dry_run().unwrap().take_requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, "PATCH");
+        assert!(requests[0]
+            .url
+            .ends_with("/api/database/rows/table/1234/1/"));
+        // Sent with the mapped field names
+        assert_eq!(requests[0].body, Some(json!({"field_1": "Ada"})));
+        assert_eq!(
+            requests[1].to_string(),
+            format!(
+                "POST {}/api/database/rows/table/1234/batch-delete/ {}",
+                server.url(),
+                json!({"items": [2, 3]})
+            )
+        );
+        assert!(baserow.dry_run().unwrap().requests().is_empty());
+
+        fields_mock.assert_async().await;
+        writes_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_redacts_credentials() {
+        let config = ConfigBuilder::new()
+            .base_url("http://localhost")
+            .access_token("jwt-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(config).with_dry_run();
+        baserow
+            .change_password("old-secret", "new-secret")
+            .await
+            .unwrap();
+
+        let requests = baserow.dry_run().unwrap().requests();
+        assert_eq!(
+            requests[0].body,
+            Some(json!({"old_password": "[redacted]", "new_password": "[redacted]"}))
+        );
+    }
+}
@@ -1,5 +1,9 @@
+use std::collections::{BTreeMap, HashMap};
+
 use tracing::{error, warn};
 
+use crate::ids::{RowId, TableId};
+
 /// Errors that can occur during token-based authentication
 ///
 /// These errors represent failures that may occur when attempting to
@@ -15,7 +19,8 @@ use tracing::{error, warn};
 ///         .base_url("https://api.baserow.io")
 ///         .email("user@example.com")
 ///         .password("password")
-///         .build();
+///         .try_build()
+///         .expect("invalid configuration");
 ///
 ///     let baserow = Baserow::with_configuration(config);
 ///     match baserow.token_auth().await {
@@ -68,7 +73,8 @@ impl TokenAuthError {
 ///     let config = ConfigBuilder::new()
 ///         .base_url("https://api.baserow.io")
 ///         .api_key("your-api-key")
-///         .build();
+///         .try_build()
+///         .expect("invalid configuration");
 ///
 ///     let baserow = Baserow::with_configuration(config);
 ///     let file = File::open("image.jpg").unwrap();
@@ -94,6 +100,14 @@ pub enum FileUploadError {
     UnexpectedStatusCode(reqwest::StatusCode),
     #[error("File upload failed: Invalid URL provided - {0}")]
     InvalidURL(String),
+    #[error("File upload failed: URL is not allowed - {0}")]
+    DisallowedURL(String),
+    #[error("File upload failed: File is too large - {0}")]
+    FileTooLarge(String),
+    #[error("File upload failed: URL could not be reached - {0}")]
+    URLUnreachable(String),
+    #[error("File upload failed: Invalid file - {0}")]
+    InvalidFile(String),
 }
 
 impl FileUploadError {
@@ -114,6 +128,944 @@ impl FileUploadError {
             Self::InvalidURL(url) => {
                 warn!(error = %self, url = %url, "File upload failed due to invalid URL");
             }
+            Self::DisallowedURL(url) => {
+                warn!(error = %self, url = %url, "File upload failed due to disallowed URL");
+            }
+            Self::FileTooLarge(detail) => {
+                warn!(error = %self, details = %detail, "File upload failed due to file size");
+            }
+            Self::URLUnreachable(detail) => {
+                error!(error = %self, details = %detail, "File upload failed due to unreachable URL");
+            }
+            Self::InvalidFile(detail) => {
+                warn!(error = %self, details = %detail, "File upload failed due to invalid file");
+            }
+        }
+    }
+}
+
+/// Errors that can occur during file downloads
+///
+/// These errors represent failures when retrieving the content of a file
+/// uploaded to Baserow.
+///
+/// # Example
+/// ```no_run
+/// use baserow_rs::{ConfigBuilder, Baserow, error::FileDownloadError, api::{client::BaserowClient, file::BaserowFileOperations}};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let config = ConfigBuilder::new()
+///         .base_url("https://api.baserow.io")
+///         .api_key("your-api-key")
+///         .try_build()
+///         .expect("invalid configuration");
+///
+///     let baserow = Baserow::with_configuration(config);
+///     let file = baserow.upload_file_via_url("https://example.com/image.jpg").await.unwrap();
+///
+///     let mut content = Vec::new();
+///     match baserow.download_file(&file, &mut content).await {
+///         Ok(size) => println!("Downloaded {} bytes", size),
+///         Err(FileDownloadError::WriteError(e)) => println!("Failed to write file: {}", e),
+///         Err(e) => println!("Download failed: {}", e),
+///     }
+/// }
+/// ```
+#[derive(Debug, thiserror::Error)]
+pub enum FileDownloadError {
+    #[error("File download failed: Unable to write file - {0}")]
+    WriteError(#[from] std::io::Error),
+    #[error("File download failed: Network error - {0}")]
+    NetworkError(#[from] reqwest::Error),
+    #[error("File download failed: Server responded with unexpected status code {0}")]
+    UnexpectedStatusCode(reqwest::StatusCode),
+    #[error("File download failed: Invalid URL provided - {0}")]
+    InvalidURL(String),
+}
+
+impl FileDownloadError {
+    pub(crate) fn log(&self) {
+        match self {
+            Self::WriteError(e) => {
+                error!(error = %self, io_error = %e, "File download failed due to write error");
+            }
+            Self::NetworkError(e) => {
+                error!(error = %self, network_error = %e, "File download failed due to network error");
+            }
+            Self::UnexpectedStatusCode(status) => {
+                error!(error = %self, status_code = %status, "File download failed with unexpected status code");
+            }
+            Self::InvalidURL(url) => {
+                warn!(error = %self, url = %url, "File download failed due to invalid URL");
+            }
+        }
+    }
+}
+
+/// Errors that can occur during account management operations
+///
+/// These errors represent failures of operations on a user account, like
+/// resetting or changing its password.
+///
+/// # Example
+/// ```no_run
+/// use baserow_rs::{ConfigBuilder, Baserow, error::UserAccountError, api::user::BaserowUserOperations};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let config = ConfigBuilder::new()
+///         .base_url("https://api.baserow.io")
+///         .try_build()
+///         .expect("invalid configuration");
+///
+///     let baserow = Baserow::with_configuration(config);
+///     match baserow.reset_password("token-from-email", "new-password").await {
+///         Ok(()) => println!("Password changed"),
+///         Err(UserAccountError::InvalidToken) => println!("The reset link is invalid or expired"),
+///         Err(e) => println!("Password reset failed: {}", e),
+///     }
+/// }
+/// ```
+#[derive(Debug, thiserror::Error)]
+pub enum UserAccountError {
+    #[error("Account operation failed: Missing required {0} credentials")]
+    MissingCredentials(&'static str),
+    #[error("Account operation failed: The provided token is invalid or expired")]
+    InvalidToken,
+    #[error("Account operation failed: The old password is incorrect")]
+    InvalidOldPassword,
+    #[error("Account operation failed: Server responded with status code {0} - {1}")]
+    RequestFailed(reqwest::StatusCode, String),
+    #[error("Network error during account operation: {0}")]
+    NetworkError(#[from] reqwest::Error),
+}
+
+impl UserAccountError {
+    pub(crate) fn log(&self) {
+        match self {
+            Self::MissingCredentials(field) => {
+                warn!(error = %self, field = %field, "Account operation failed due to missing credentials");
+            }
+            Self::InvalidToken => {
+                warn!(error = %self, "Account operation failed due to an invalid token");
+            }
+            Self::InvalidOldPassword => {
+                warn!(error = %self, "Account operation failed due to an incorrect old password");
+            }
+            Self::RequestFailed(status, details) => {
+                error!(error = %self, status_code = %status, details = %details, "Account operation failed");
+            }
+            Self::NetworkError(e) => {
+                error!(error = %self, network_error = %e, "Account operation failed due to network error");
+            }
+        }
+    }
+}
+
+/// Errors that can occur when receiving webhook calls
+///
+/// These errors represent requests that don't match any registered webhook,
+/// either because of a wrong method, missing or wrong headers, a payload for
+/// another table, or an event the webhook was not registered for.
+///
+/// # Example
+/// ```no_run
+/// use baserow_rs::{api::webhook::WebhookReceiver, error::WebhookReceiveError};
+/// use reqwest::{header::HeaderMap, Method};
+///
+/// fn handle(receiver: &WebhookReceiver, headers: &HeaderMap, body: &[u8]) {
+///     match receiver.receive::<serde_json::Value>(&Method::POST, headers, body) {
+///         Ok(payload) => println!("Received {} event", payload.event_type),
+///         Err(WebhookReceiveError::InvalidHeader(name)) => {
+///             println!("Rejected call with invalid {} header", name)
+///         }
+///         Err(e) => println!("Rejected webhook call: {}", e),
+///     }
+/// }
+/// ```
+#[cfg(feature = "webhooks")]
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookReceiveError {
+    #[error("Webhook call rejected: Invalid payload - {0}")]
+    InvalidPayload(#[from] serde_json::Error),
+    #[error("Webhook call rejected: Unknown webhook {0}")]
+    UnknownWebhook(u64),
+    #[error("Webhook call rejected: Unexpected request method {0}")]
+    UnexpectedMethod(reqwest::Method),
+    #[error("Webhook call rejected: Missing or invalid {0} header")]
+    InvalidHeader(String),
+    #[error("Webhook call rejected: Webhook is not registered on table {0}")]
+    UnexpectedTable(crate::ids::TableId),
+    #[error("Webhook call rejected: Webhook is not registered for {0} events")]
+    UnexpectedEvent(crate::api::webhook::WebhookEvent),
+}
+
+#[cfg(feature = "webhooks")]
+impl WebhookReceiveError {
+    pub(crate) fn log(&self) {
+        match self {
+            Self::InvalidPayload(e) => {
+                warn!(error = %self, decode_error = %e, "Webhook call rejected due to invalid payload");
+            }
+            Self::UnknownWebhook(id) => {
+                warn!(error = %self, webhook_id = id, "Webhook call rejected due to unknown webhook");
+            }
+            Self::UnexpectedMethod(method) => {
+                warn!(error = %self, method = %method, "Webhook call rejected due to unexpected method");
+            }
+            Self::InvalidHeader(name) => {
+                warn!(error = %self, header = %name, "Webhook call rejected due to invalid header");
+            }
+            Self::UnexpectedTable(table_id) => {
+                warn!(error = %self, table_id = %table_id, "Webhook call rejected due to unexpected table");
+            }
+            Self::UnexpectedEvent(event) => {
+                warn!(error = %self, event_type = %event, "Webhook call rejected due to unexpected event");
+            }
+        }
+    }
+}
+
+/// Errors that can occur on a realtime connection
+///
+/// # Example
+/// ```no_run
+/// use baserow_rs::{error::RealtimeError, realtime::RealtimeClient, Baserow, ConfigBuilder};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let config = ConfigBuilder::new()
+///         .base_url("https://api.baserow.io")
+///         .email("test@example.com")
+///         .password("password")
+///         .try_build()
+///         .expect("invalid configuration");
+///
+///     let baserow = Baserow::with_configuration(config);
+///     match RealtimeClient::connect(baserow).await {
+///         Ok(realtime) => println!("Connected as {}", realtime.web_socket_id()),
+///         Err(RealtimeError::MissingCredentials(kind)) => println!("Log in to get a {} first", kind),
+///         Err(e) => println!("Connection failed: {}", e),
+///     }
+/// }
+/// ```
+#[cfg(feature = "realtime")]
+#[derive(Debug, thiserror::Error)]
+pub enum RealtimeError {
+    #[error("Realtime connection failed: Missing required {0} credentials")]
+    MissingCredentials(&'static str),
+    #[error("Realtime connection failed: The server rejected the credentials")]
+    AuthenticationFailed,
+    #[error("Realtime connection failed: Invalid URL {0}")]
+    InvalidUrl(String),
+    #[error("Realtime connection failed: The WebSocket API cannot be reached through a proxy")]
+    ProxyUnsupported,
+    #[error("Realtime connection failed: Invalid TLS settings - {0}")]
+    InvalidTls(String),
+    #[error("Realtime connection failed: Unexpected message - {0}")]
+    InvalidMessage(#[from] serde_json::Error),
+    #[error("Realtime connection failed: The connection is closed")]
+    Closed,
+    #[error("Network error on realtime connection: {0}")]
+    NetworkError(Box<tokio_tungstenite::tungstenite::Error>),
+}
+
+#[cfg(feature = "realtime")]
+impl From<tokio_tungstenite::tungstenite::Error> for RealtimeError {
+    fn from(error: tokio_tungstenite::tungstenite::Error) -> Self {
+        Self::NetworkError(Box::new(error))
+    }
+}
+
+#[cfg(feature = "realtime")]
+impl RealtimeError {
+    pub(crate) fn log(&self) {
+        match self {
+            Self::MissingCredentials(field) => {
+                warn!(error = %self, field = %field, "Realtime connection failed due to missing credentials");
+            }
+            Self::AuthenticationFailed => {
+                warn!(error = %self, "Realtime connection failed due to rejected credentials");
+            }
+            Self::InvalidUrl(url) => {
+                warn!(error = %self, url = %url, "Realtime connection failed due to invalid URL");
+            }
+            Self::ProxyUnsupported => {
+                warn!(error = %self, "Realtime connection failed due to configured proxy");
+            }
+            Self::InvalidTls(e) => {
+                error!(error = %self, tls_error = %e, "Realtime connection failed due to invalid TLS settings");
+            }
+            Self::InvalidMessage(e) => {
+                error!(error = %self, decode_error = %e, "Realtime connection failed due to unexpected message");
+            }
+            Self::Closed => {
+                warn!(error = %self, "Realtime connection is closed");
+            }
+            Self::NetworkError(e) => {
+                error!(error = %self, network_error = %e, "Realtime connection failed due to network error");
+            }
+        }
+    }
+}
+
+/// Errors that can occur when building a configuration
+///
+/// # Example
+/// ```
+/// use baserow_rs::{error::ConfigError, ConfigBuilder};
+///
+/// match ConfigBuilder::new().api_key("your-api-key").try_build() {
+///     Ok(config) => println!("Configuration is valid"),
+///     Err(ConfigError::MissingBaseUrl) => println!("Set a base URL first"),
+///     Err(e) => println!("Invalid configuration: {}", e),
+/// }
+/// ```
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("Invalid configuration: Missing base URL")]
+    MissingBaseUrl,
+    #[error("Invalid configuration: Base URL {url} is invalid - {reason}")]
+    InvalidBaseUrl { url: String, reason: String },
+    #[error("Invalid configuration: Proxy is invalid - {0}")]
+    InvalidProxy(String),
+    #[error("Invalid configuration: TLS settings are invalid - {0}")]
+    InvalidTls(String),
+    #[error("Invalid configuration: Environment variable {0} is not set")]
+    MissingVariable(&'static str),
+    #[error("Invalid configuration: Environment variable {name} is invalid - {reason}")]
+    InvalidVariable { name: &'static str, reason: String },
+    #[error("Invalid configuration: Unable to read {} - {source}", .path.display())]
+    ReadFile {
+        path: std::path::PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Invalid configuration: Unable to parse {} - {reason}", .path.display())]
+    ParseFile {
+        path: std::path::PathBuf,
+        reason: String,
+    },
+    #[error("Invalid configuration: Profile {0} does not exist")]
+    MissingProfile(String),
+}
+
+/// Errors that can occur when validating a value before writing it to a field
+///
+/// Validation is optional and happens on the client, before any request is
+/// sent. See `BaserowFieldValue::validate`.
+///
+/// # Example
+/// ```
+/// use baserow_rs::{error::FieldValueError, value::BaserowFieldValue, TableField};
+/// use serde_json::json;
+///
+/// let field: TableField = serde_json::from_value(json!({
+///     "id": 1, "table_id": 1, "name": "Stars", "order": 0, "type": "rating",
+///     "primary": false, "read_only": false, "description": null, "max_value": 5
+/// }))
+/// .unwrap();
+///
+/// match BaserowFieldValue::Rating(7).validate(&field) {
+///     Err(FieldValueError::RatingOutOfRange { max, .. }) => println!("At most {} stars", max),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Debug, thiserror::Error)]
+pub enum FieldValueError {
+    #[error("Invalid value for field {field}: Rating {value} exceeds the maximum of {max}")]
+    RatingOutOfRange { field: String, value: u64, max: u8 },
+    #[error("Invalid value for field {field}: {value} is not a valid URL")]
+    InvalidURL { field: String, value: String },
+    #[error("Invalid value for field {field}: {value} is not a valid email address")]
+    InvalidEmail { field: String, value: String },
+    #[error("Invalid value for field {0}: The field is read-only")]
+    ReadOnly(String),
+    #[error("Invalid value for field {field}: Select option {value} does not exist")]
+    UnknownSelectOption { field: String, value: String },
+    #[error("Invalid value for field {field}: {value} is not a valid number")]
+    InvalidNumber { field: String, value: String },
+    #[error("Invalid value for field {field}: Negative number {value} is not allowed")]
+    NegativeNumber { field: String, value: String },
+    #[error(
+        "Invalid value for field {field}: {value} has more than {decimal_places} decimal places"
+    )]
+    TooManyDecimalPlaces {
+        field: String,
+        value: String,
+        decimal_places: u32,
+    },
+    #[error("Invalid value for field {field}: {value} is not a valid date")]
+    InvalidDate { field: String, value: String },
+}
+
+impl FieldValueError {
+    pub(crate) fn log(&self) {
+        match self {
+            Self::RatingOutOfRange { field, value, max } => {
+                warn!(error = %self, field = %field, value = value, max = max, "Field value rejected due to rating out of range");
+            }
+            Self::InvalidURL { field, value } => {
+                warn!(error = %self, field = %field, value = %value, "Field value rejected due to invalid URL");
+            }
+            Self::InvalidEmail { field, value } => {
+                warn!(error = %self, field = %field, value = %value, "Field value rejected due to invalid email address");
+            }
+            Self::ReadOnly(field) => {
+                warn!(error = %self, field = %field, "Field value rejected due to read-only field");
+            }
+            Self::UnknownSelectOption { field, value } => {
+                warn!(error = %self, field = %field, value = %value, "Field value rejected due to unknown select option");
+            }
+            Self::InvalidNumber { field, value } => {
+                warn!(error = %self, field = %field, value = %value, "Field value rejected due to invalid number");
+            }
+            Self::NegativeNumber { field, value } => {
+                warn!(error = %self, field = %field, value = %value, "Field value rejected due to negative number");
+            }
+            Self::TooManyDecimalPlaces {
+                field,
+                value,
+                decimal_places,
+            } => {
+                warn!(error = %self, field = %field, value = %value, decimal_places = decimal_places, "Field value rejected due to too many decimal places");
+            }
+            Self::InvalidDate { field, value } => {
+                warn!(error = %self, field = %field, value = %value, "Field value rejected due to invalid date");
+            }
+        }
+    }
+}
+
+/// Errors found when validating a row before writing it to a table
+///
+/// Tables with `validate_writes()` enabled validate rows against the schema
+/// loaded by `auto_map()` before writes send any request. All invalid fields
+/// are reported at once.
+///
+/// # Example
+/// ```no_run
+/// use baserow_rs::{api::client::BaserowClient, error::BaserowError, Baserow, BaserowTableOperations, ConfigBuilder, TableId};
+/// use serde_json::json;
+/// use std::collections::HashMap;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let config = ConfigBuilder::new()
+///         .base_url("https://api.baserow.io")
+///         .api_key("your-api-key")
+///         .try_build()
+///         .expect("invalid configuration");
+///     let baserow = Baserow::with_configuration(config);
+///     let table = baserow
+///         .table_by_id(TableId(1234))
+///         .auto_map()
+///         .await
+///         .unwrap()
+///         .validate_writes();
+///
+///     let row = HashMap::from([("Status".to_string(), json!("Unknown"))]);
+///     match table.create_one(row, None).await {
+///         Ok(_) => println!("Row created"),
+///         Err(BaserowError::ValidationError(validation)) => {
+///             for error in &validation.errors {
+///                 println!("{}", error);
+///             }
+///         }
+///         Err(e) => println!("Request failed: {}", e),
+///     }
+/// }
+/// ```
+#[derive(Debug, thiserror::Error)]
+#[error("Validation failed: {}", .errors.iter().map(ToString::to_string).collect::<Vec<_>>().join("; "))]
+pub struct ValidationError {
+    /// The errors of all invalid fields
+    pub errors: Vec<FieldValueError>,
+}
+
+impl ValidationError {
+    pub(crate) fn log(&self) {
+        warn!(error = %self, error_count = self.errors.len(), "Row rejected due to invalid field values");
+    }
+}
+
+/// The error body Baserow responds with when a request fails
+///
+/// `error` is a machine-readable code like `ERROR_ROW_DOES_NOT_EXIST`,
+/// `detail` a human-readable message or, for invalid request bodies, an
+/// object describing the problems per field.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct ApiErrorResponse {
+    pub error: String,
+    #[serde(default)]
+    pub detail: serde_json::Value,
+}
+
+impl ApiErrorResponse {
+    /// Returns the detail as message, falling back to the error code if there is none
+    pub fn message(&self) -> String {
+        match &self.detail {
+            serde_json::Value::String(detail) => detail.clone(),
+            serde_json::Value::Null => self.error.clone(),
+            detail => detail.to_string(),
+        }
+    }
+
+    /// Returns the validation messages per field if the request body was rejected
+    ///
+    /// Baserow reports invalid request bodies with the code
+    /// `ERROR_REQUEST_BODY_VALIDATION` and a detail object mapping each
+    /// rejected field, e.g. `field_12`, to its errors. Errors of batch
+    /// requests are reported per item by `item_errors` instead.
+    pub fn field_errors(&self) -> Option<HashMap<String, Vec<String>>> {
+        if self.item_errors().is_some() {
+            return None;
+        }
+        match (self.error.as_str(), &self.detail) {
+            ("ERROR_REQUEST_BODY_VALIDATION", serde_json::Value::Object(fields)) => Some(
+                fields
+                    .iter()
+                    .map(|(field, errors)| {
+                        let mut messages = Vec::new();
+                        collect_messages(errors, &mut messages);
+                        (field.clone(), messages)
+                    })
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Returns the validation messages per field of each rejected item of a batch request
+    ///
+    /// The items are keyed by their position in the request. Baserow reports
+    /// them below `items` in the detail, either as list with an empty object
+    /// for every valid item or as object keyed by position.
+    pub fn item_errors(&self) -> Option<BTreeMap<usize, HashMap<String, Vec<String>>>> {
+        if self.error != "ERROR_REQUEST_BODY_VALIDATION" {
+            return None;
+        }
+        let items: Vec<(usize, &serde_json::Value)> = match self.detail.get("items")? {
+            serde_json::Value::Array(items) => items.iter().enumerate().collect(),
+            serde_json::Value::Object(items) => items
+                .iter()
+                .map(|(index, errors)| Some((index.parse().ok()?, errors)))
+                .collect::<Option<_>>()?,
+            _ => return None,
+        };
+
+        items
+            .into_iter()
+            .filter(|(_, errors)| errors.as_object().is_none_or(|errors| !errors.is_empty()))
+            .map(|(index, errors)| match errors {
+                // Errors of the list itself, like too many items, carry a message
+                serde_json::Value::Object(fields) if !fields.contains_key("error") => {
+                    let fields = fields
+                        .iter()
+                        .map(|(field, errors)| {
+                            let mut messages = Vec::new();
+                            collect_messages(errors, &mut messages);
+                            (field.clone(), messages)
+                        })
+                        .collect();
+                    Some((index, fields))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Collects the messages of a validation error detail, which may be nested
+fn collect_messages(detail: &serde_json::Value, messages: &mut Vec<String>) {
+    match detail {
+        serde_json::Value::String(message) => messages.push(message.clone()),
+        serde_json::Value::Array(details) => details
+            .iter()
+            .for_each(|detail| collect_messages(detail, messages)),
+        serde_json::Value::Object(detail) => match detail.get("error") {
+            Some(serde_json::Value::String(message)) => messages.push(message.clone()),
+            _ => detail
+                .values()
+                .for_each(|detail| collect_messages(detail, messages)),
+        },
+        _ => {}
+    }
+}
+
+/// The headers proxies and load balancers use to identify a request
+const REQUEST_ID_HEADERS: [&str; 3] = ["x-request-id", "x-correlation-id", "cf-ray"];
+
+/// Describes the request that failed, so errors can be traced without request logging
+///
+/// The URL is sanitized: query parameters, which may contain filter values,
+/// and credentials are removed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RequestContext {
+    /// The HTTP method of the request, if known
+    pub method: Option<reqwest::Method>,
+    /// The URL of the request without query and credentials
+    pub url: String,
+    /// The request ID the server or a proxy in front of it responded with
+    pub request_id: Option<String>,
+}
+
+impl RequestContext {
+    /// Captures the context of the request a response belongs to
+    pub(crate) fn from_response(resp: &reqwest::Response) -> Self {
+        let mut url = resp.url().clone();
+        url.set_query(None);
+        strip_credentials(&mut url);
+
+        Self {
+            method: resp.extensions().get::<reqwest::Method>().cloned(),
+            url: url.to_string(),
+            request_id: REQUEST_ID_HEADERS.iter().find_map(|name| {
+                resp.headers()
+                    .get(*name)
+                    .and_then(|value| value.to_str().ok())
+                    .map(str::to_string)
+            }),
+        }
+    }
+}
+
+/// Removes the user info and the fragment of a URL
+pub(crate) fn strip_credentials(url: &mut reqwest::Url) {
+    url.set_fragment(None);
+    let _ = url.set_username("");
+    let _ = url.set_password(None);
+}
+
+impl std::fmt::Display for RequestContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(method) = &self.method {
+            write!(f, "{} ", method)?;
+        }
+        write!(f, "{}", self.url)?;
+        if let Some(request_id) = &self.request_id {
+            write!(f, " (request id {})", request_id)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the database token permission a request needs, judging by its method and endpoint
+fn token_permission(context: &RequestContext) -> &'static str {
+    match context.method {
+        Some(reqwest::Method::GET) => "read",
+        Some(reqwest::Method::PATCH) => "update",
+        Some(reqwest::Method::DELETE) => "delete",
+        Some(reqwest::Method::POST) if context.url.ends_with("/batch-delete/") => "delete",
+        Some(reqwest::Method::POST) => "create",
+        _ => "required",
+    }
+}
+
+fn format_field_errors(fields: &HashMap<String, Vec<String>>) -> String {
+    let mut fields: Vec<_> = fields
+        .iter()
+        .map(|(field, messages)| format!("{}: {}", field, messages.join(", ")))
+        .collect();
+    fields.sort();
+    fields.join("; ")
+}
+
+/// Errors that can occur when working with tables, rows, fields and webhooks
+///
+/// # Example
+/// ```no_run
+/// use baserow_rs::{api::client::BaserowClient, error::BaserowError, Baserow, ConfigBuilder, RowId, TableId, TypedTableOperations};
+/// use serde_json::Value;
+/// use std::collections::HashMap;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let config = ConfigBuilder::new()
+///         .base_url("https://api.baserow.io")
+///         .api_key("your-api-key")
+///         .try_build()
+///         .expect("invalid configuration");
+///     let baserow = Baserow::with_configuration(config);
+///
+///     match baserow.table_by_id(TableId(1234)).get_one::<HashMap<String, Value>>(RowId(1), None).await {
+///         Ok(row) => println!("Found row: {:?}", row),
+///         Err(BaserowError::RowNotFound { row_id, .. }) => {
+///             println!("Row {} does not exist", row_id)
+///         }
+///         Err(e) => println!("Request failed: {}", e),
+///     }
+/// }
+/// ```
+#[derive(Debug, thiserror::Error)]
+pub enum BaserowError {
+    #[error("Request failed: Network error - {0}")]
+    NetworkError(#[from] reqwest::Error),
+    #[error("Request failed: {context} responded with status code {status} - {}", .response.as_ref().map(ApiErrorResponse::message).unwrap_or_else(|| .body.clone()))]
+    ApiError {
+        status: reqwest::StatusCode,
+        /// The request that failed
+        context: Box<RequestContext>,
+        /// The parsed error body, if Baserow responded with one
+        response: Option<ApiErrorResponse>,
+        /// The raw response body
+        body: String,
+    },
+    #[error("Request failed: {context} was rate limited, retry after {retry_after:?}")]
+    RateLimited {
+        /// The request that failed
+        context: Box<RequestContext>,
+        /// How long to wait before retrying, as requested by the `Retry-After` header
+        retry_after: Option<std::time::Duration>,
+    },
+    #[error("Request failed: {context} was not authenticated - {}", .response.as_ref().map(ApiErrorResponse::message).unwrap_or_else(|| "invalid or expired credentials".to_string()))]
+    Unauthorized {
+        /// The request that failed
+        context: Box<RequestContext>,
+        /// The parsed error body, if Baserow responded with one
+        response: Option<ApiErrorResponse>,
+    },
+    #[error("Request failed: {context} is forbidden - {}", .response.as_ref().map(ApiErrorResponse::message).unwrap_or_else(|| "missing permission".to_string()))]
+    Forbidden {
+        /// The request that failed
+        context: Box<RequestContext>,
+        /// The parsed error body, if Baserow responded with one
+        response: Option<ApiErrorResponse>,
+    },
+    #[error("Request failed: {context} is not permitted - the database token lacks the {permission} permission for this table")]
+    TokenScope {
+        /// The request that failed
+        context: Box<RequestContext>,
+        /// The permission the request needs, e.g. `create`
+        permission: &'static str,
+    },
+    #[error("Request failed: Table {0} does not exist")]
+    TableNotFound(TableId),
+    #[error("Request failed: Row {row_id} does not exist in table {table_id}")]
+    RowNotFound { table_id: TableId, row_id: RowId },
+    #[error("Request failed: Invalid field values - {}", format_field_errors(.0))]
+    InvalidFieldValues(HashMap<String, Vec<String>>),
+    #[error("Request failed: Unable to decode data - {0}")]
+    DecodeError(#[from] serde_json::Error),
+    #[error("Request failed: {0} is missing")]
+    MissingState(&'static str),
+    #[error("Request failed: Missing required credentials - {0}")]
+    MissingCredentials(&'static str),
+    #[error("Request failed: Invalid request - {0}")]
+    InvalidRequest(String),
+    #[error("Request failed: The URL is {length} characters long, exceeding the limit of {limit} - save the filters in a view and query it instead")]
+    QueryTooLarge {
+        /// The length of the URL including its query string
+        length: usize,
+        /// The maximum length, as configured by `ConfigBuilder::max_url_length`
+        limit: usize,
+    },
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+    #[error("Request failed: Unable to read or write data - {0}")]
+    IoError(#[from] std::io::Error),
+}
+
+impl BaserowError {
+    /// Converts an unsuccessful response into an API error
+    pub(crate) async fn from_response(resp: reqwest::Response) -> Self {
+        let error = Self::parse_response(resp).await;
+        error.log();
+        error
+    }
+
+    /// Converts an unsuccessful response of a table endpoint, mapping 404s to `TableNotFound`
+    pub(crate) async fn from_table_response(resp: reqwest::Response, table_id: TableId) -> Self {
+        let error = match Self::parse_response(resp).await {
+            e if e.is_not_found() => Self::TableNotFound(table_id),
+            e => e,
+        };
+        error.log();
+        error
+    }
+
+    /// Converts an unsuccessful response of a row endpoint, mapping 404s to `RowNotFound`
+    /// or `TableNotFound`, depending on what does not exist
+    pub(crate) async fn from_row_response(
+        resp: reqwest::Response,
+        table_id: TableId,
+        row_id: RowId,
+    ) -> Self {
+        let error = match Self::parse_response(resp).await {
+            e if e.is_not_found() && e.error_code() == Some("ERROR_TABLE_DOES_NOT_EXIST") => {
+                Self::TableNotFound(table_id)
+            }
+            e if e.is_not_found() => Self::RowNotFound { table_id, row_id },
+            e => e,
+        };
+        error.log();
+        error
+    }
+
+    /// Converts an unsuccessful response of a batch endpoint
+    ///
+    /// 404s are mapped to `TableNotFound`, unless Baserow reports rows of the
+    /// batch that do not exist.
+    pub(crate) async fn from_batch_response(resp: reqwest::Response, table_id: TableId) -> Self {
+        let error = match Self::parse_response(resp).await {
+            e if e.is_not_found() && e.error_code() != Some("ERROR_ROW_DOES_NOT_EXIST") => {
+                Self::TableNotFound(table_id)
+            }
+            e => e,
+        };
+        error.log();
+        error
+    }
+
+    /// Renames the fields of `InvalidFieldValues`, e.g. from `field_12` to the field name
+    pub(crate) fn map_field_names(self, rename: impl Fn(&str) -> Option<String>) -> Self {
+        match self {
+            Self::InvalidFieldValues(fields) => Self::InvalidFieldValues(
+                fields
+                    .into_iter()
+                    .map(|(field, messages)| (rename(&field).unwrap_or(field), messages))
+                    .collect(),
+            ),
+            e => e,
+        }
+    }
+
+    async fn parse_response(resp: reqwest::Response) -> Self {
+        let status = resp.status();
+        let context = Box::new(RequestContext::from_response(&resp));
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Self::RateLimited {
+                context,
+                retry_after: crate::api::client::retry_after(resp.headers()),
+            };
+        }
+        let body = match resp.text().await {
+            Ok(body) => body,
+            Err(e) => return Self::NetworkError(e),
+        };
+        let response: Option<ApiErrorResponse> = serde_json::from_str(&body).ok();
+        if response
+            .as_ref()
+            .is_some_and(|response| response.error == "ERROR_NO_PERMISSION_TO_TABLE")
+        {
+            let permission = token_permission(&context);
+            return Self::TokenScope {
+                context,
+                permission,
+            };
+        }
+        match response.as_ref().and_then(ApiErrorResponse::field_errors) {
+            Some(fields) if status == reqwest::StatusCode::BAD_REQUEST => {
+                Self::InvalidFieldValues(fields)
+            }
+            _ if status == reqwest::StatusCode::UNAUTHORIZED => {
+                Self::Unauthorized { context, response }
+            }
+            _ if status == reqwest::StatusCode::FORBIDDEN => Self::Forbidden { context, response },
+            _ => Self::ApiError {
+                status,
+                context,
+                response,
+                body,
+            },
+        }
+    }
+
+    fn is_not_found(&self) -> bool {
+        matches!(self, Self::ApiError { status, .. } if *status == reqwest::StatusCode::NOT_FOUND)
+    }
+
+    /// Returns the machine-readable error code Baserow responded with, e.g. `ERROR_ROW_DOES_NOT_EXIST`
+    pub fn error_code(&self) -> Option<&str> {
+        match self {
+            Self::ApiError {
+                response: Some(response),
+                ..
+            }
+            | Self::Unauthorized {
+                response: Some(response),
+                ..
+            }
+            | Self::Forbidden {
+                response: Some(response),
+                ..
+            } => Some(&response.error),
+            Self::TokenScope { .. } => Some("ERROR_NO_PERMISSION_TO_TABLE"),
+            _ => None,
+        }
+    }
+
+    /// Returns the request that failed, if the server responded
+    pub fn request_context(&self) -> Option<&RequestContext> {
+        match self {
+            Self::ApiError { context, .. }
+            | Self::RateLimited { context, .. }
+            | Self::Unauthorized { context, .. }
+            | Self::Forbidden { context, .. }
+            | Self::TokenScope { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn log(&self) {
+        match self {
+            Self::NetworkError(e) => {
+                error!(error = %self, network_error = %e, "Request failed due to network error");
+            }
+            Self::ApiError {
+                status,
+                context,
+                body,
+                ..
+            } => {
+                error!(error = %self, status_code = %status, error_code = ?self.error_code(), method = ?context.method, url = %context.url, request_id = ?context.request_id, body = %body, "Request failed with unexpected status code");
+            }
+            Self::RateLimited {
+                retry_after,
+                context,
+            } => {
+                warn!(error = %self, retry_after = ?retry_after, method = ?context.method, url = %context.url, request_id = ?context.request_id, "Request failed due to rate limiting");
+            }
+            Self::Unauthorized { context, .. } => {
+                warn!(error = %self, error_code = ?self.error_code(), method = ?context.method, url = %context.url, request_id = ?context.request_id, "Request failed due to invalid credentials");
+            }
+            Self::Forbidden { context, .. } => {
+                warn!(error = %self, error_code = ?self.error_code(), method = ?context.method, url = %context.url, request_id = ?context.request_id, "Request failed due to missing permission");
+            }
+            Self::TokenScope {
+                context,
+                permission,
+            } => {
+                warn!(error = %self, permission = %permission, method = ?context.method, url = %context.url, request_id = ?context.request_id, "Request failed due to database token permissions");
+            }
+            Self::TableNotFound(table_id) => {
+                warn!(error = %self, table_id = %table_id, "Request failed due to missing table");
+            }
+            Self::RowNotFound { table_id, row_id } => {
+                warn!(error = %self, table_id = %table_id, row_id = %row_id, "Request failed due to missing row");
+            }
+            Self::InvalidFieldValues(fields) => {
+                warn!(error = %self, fields = ?fields.keys().collect::<Vec<_>>(), "Request failed due to invalid field values");
+            }
+            Self::DecodeError(e) => {
+                error!(error = %self, decode_error = %e, "Request failed due to undecodable data");
+            }
+            Self::MissingState(what) => {
+                warn!(error = %self, missing = %what, "Request failed due to missing state");
+            }
+            Self::MissingCredentials(field) => {
+                warn!(error = %self, missing_field = %field, "Request failed due to missing credentials");
+            }
+            Self::InvalidRequest(reason) => {
+                warn!(error = %self, reason = %reason, "Request failed due to invalid request");
+            }
+            Self::QueryTooLarge { length, limit } => {
+                warn!(error = %self, length = %length, limit = %limit, "Request failed due to an over-long URL");
+            }
+            Self::ValidationError(e) => e.log(),
+            Self::IoError(e) => {
+                error!(error = %self, io_error = %e, "Request failed due to an I/O error");
+            }
         }
     }
 }
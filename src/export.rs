@@ -0,0 +1,294 @@
+//! Export of rows
+//!
+//! [`BaserowTable::export_jsonl`] writes all rows of a table as JSON Lines,
+//! one object per row keyed by field names, as a backup that
+//! [`BaserowTable::import_jsonl`] restores. With the `csv` feature,
+//! [`RowRequestBuilder::export_csv`] writes the results of a query as CSV
+//! for reporting.
+//!
+//! Rows are fetched and written a page at a time, so large tables can be
+//! exported without holding them in memory. Writers and readers are
+//! asynchronous, so files and sockets don't block the runtime.
+//!
+//! # Example
+//! ```no_run
+//! use baserow_rs::prelude::*;
+//! use tokio::{fs::File, io::BufReader};
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let config = ConfigBuilder::new()
+//!         .base_url("https://api.baserow.io")
+//!         .api_key("your-api-key")
+//!         .try_build()
+//!         .expect("invalid configuration");
+//!     let baserow = Baserow::with_configuration(config);
+//!
+//!     let file = File::create("customers.jsonl").await.unwrap();
+//!     let rows = baserow.table_by_id(TableId(1234)).export_jsonl(file).await.unwrap();
+//!     println!("Backed up {} rows", rows);
+//!
+//!     let file = BufReader::new(File::open("customers.jsonl").await.unwrap());
+//!     let result = baserow.table_by_id(TableId(5678)).import_jsonl(file).await.unwrap();
+//!     println!("Restored {} rows", result.rows().count());
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use futures_util::TryStreamExt;
+use serde_json::Value;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tracing::{debug, info, instrument};
+
+#[cfg(feature = "csv")]
+use crate::api::{client::BaserowClient, table_operations::RowRequestBuilder};
+use crate::{
+    api::table_operations::RowRequest, error::BaserowError, Baserow, BaserowTable, FieldNamesMode,
+};
+
+/// Fetches all pages of a request keyed by field names and writes them to `writer`
+///
+/// Each page is encoded by `encode` and written before the next page is
+/// fetched. The page of the request is ignored. Returns the number of rows.
+async fn export_rows<W: AsyncWrite + Unpin + Send>(
+    table: &BaserowTable,
+    baserow: &Baserow,
+    mut request: RowRequest,
+    writer: &mut W,
+    mut encode: impl FnMut(&[HashMap<String, Value>], &mut Vec<u8>) -> Result<(), BaserowError> + Send,
+) -> Result<usize, BaserowError> {
+    request.field_names = Some(FieldNamesMode::UserNames);
+
+    let mut pages = std::pin::pin!(table.pages(baserow, request));
+    let mut count = 0;
+    let mut buffer = Vec::new();
+    while let Some(rows) = pages.try_next().await? {
+        debug!(offset = count, rows = rows.len(), "Exporting page");
+        buffer.clear();
+        encode(&rows, &mut buffer)?;
+        writer.write_all(&buffer).await?;
+        count += rows.len();
+    }
+    writer.flush().await?;
+    Ok(count)
+}
+
+impl BaserowTable {
+    /// Writes all rows of the table as JSON Lines
+    ///
+    /// Each line holds a row as returned by Baserow, keyed by field names
+    /// regardless of the field names mode of the table, so the export can
+    /// be imported into a table with the same field names. Returns the
+    /// number of exported rows.
+    #[instrument(skip(self, writer), fields(table_id = ?self.id), err)]
+    pub async fn export_jsonl<W: AsyncWrite + Unpin + Send>(
+        &self,
+        mut writer: W,
+    ) -> Result<usize, BaserowError> {
+        let baserow = self
+            .baserow
+            .as_ref()
+            .ok_or(BaserowError::MissingState("Baserow instance"))?;
+        let request = RowRequest {
+            page_size: Some(200),
+            ..Default::default()
+        };
+
+        let count = export_rows(self, baserow, request, &mut writer, |rows, buffer| {
+            for row in rows {
+                serde_json::to_writer(&mut *buffer, row)?;
+                buffer.push(b'\n');
+            }
+            Ok(())
+        })
+        .await?;
+
+        info!(rows = count, "Exported rows to JSON Lines");
+        Ok(count)
+    }
+}
+
+#[cfg(feature = "csv")]
+/// Renders a cell as text
+///
+/// Select options, linked rows and collaborators are written as their
+/// names, files as their name followed by their URL. Multiple values are
+/// separated by commas.
+fn cell_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        Value::Bool(_) | Value::Number(_) => value.to_string(),
+        Value::Array(values) => values.iter().map(cell_text).collect::<Vec<_>>().join(", "),
+        Value::Object(object) => {
+            if let (Some(Value::String(name)), Some(Value::String(url))) =
+                (object.get("visible_name"), object.get("url"))
+            {
+                format!("{} ({})", name, url)
+            } else if let Some(value) = object.get("value") {
+                cell_text(value)
+            } else if let Some(name) = object.get("name") {
+                cell_text(name)
+            } else {
+                value.to_string()
+            }
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl RowRequestBuilder {
+    /// Fetches all pages of the query and writes the rows as CSV
+    ///
+    /// The header holds `id` followed by the names of the fields in table
+    /// order, regardless of the field names mode of the table. The page of
+    /// the query is ignored, its size is used for the requests. Returns the
+    /// number of exported rows.
+    #[instrument(skip(self, writer), err)]
+    pub async fn export_csv<W: AsyncWrite + Unpin + Send>(
+        &self,
+        mut writer: W,
+    ) -> Result<usize, BaserowError> {
+        let (table, baserow) = self.target()?;
+        let table_id = table.id.ok_or(BaserowError::MissingState("Table ID"))?;
+
+        let mut fields = match &table.mapper {
+            Some(mapper) => mapper.fields().to_vec(),
+            None => baserow.table_fields(table_id).await?,
+        };
+        fields.sort_by_key(|field| (field.order, field.id));
+
+        let mut header = csv::Writer::from_writer(Vec::new());
+        header
+            .write_record(std::iter::once("id").chain(fields.iter().map(|f| f.name.as_str())))
+            .map_err(std::io::Error::from)?;
+        let header = header.into_inner().map_err(|e| e.into_error())?;
+        writer.write_all(&header).await?;
+
+        let request = self.request.clone();
+        let count = export_rows(table, baserow, request, &mut writer, |rows, buffer| {
+            let mut records = csv::Writer::from_writer(buffer);
+            for row in rows {
+                let id = row.get("id").map(cell_text).unwrap_or_default();
+                let cells = fields
+                    .iter()
+                    .map(|field| row.get(&field.name).map(cell_text).unwrap_or_default());
+                records
+                    .write_record(std::iter::once(id).chain(cells))
+                    .map_err(std::io::Error::from)?;
+            }
+            records.flush()?;
+            Ok(())
+        })
+        .await?;
+
+        info!(rows = count, "Exported rows to CSV");
+        Ok(count)
+    }
+}
+
+#[cfg(all(test, feature = "csv"))]
+mod tests {
+    use super::*;
+    use crate::{api::table_operations::BaserowTableOperations, Baserow, ConfigBuilder, TableId};
+    use serde_json::json;
+
+    #[test]
+    fn test_cell_text() {
+        assert_eq!(cell_text(&Value::Null), "");
+        assert_eq!(cell_text(&json!("Ada")), "Ada");
+        assert_eq!(cell_text(&json!(true)), "true");
+        assert_eq!(cell_text(&json!("12.50")), "12.50");
+        assert_eq!(
+            cell_text(&json!({"id": 1, "value": "Open", "color": "blue"})),
+            "Open"
+        );
+        assert_eq!(
+            cell_text(&json!([{"id": 1, "value": "Ada"}, {"id": 2, "value": "Grace"}])),
+            "Ada, Grace"
+        );
+        assert_eq!(cell_text(&json!([{"id": 1, "name": "Ada"}])), "Ada");
+        assert_eq!(
+            cell_text(
+                &json!([{"name": "a1b2.pdf", "visible_name": "invoice.pdf", "url": "https://files.example.com/a1b2.pdf"}])
+            ),
+            "invoice.pdf (https://files.example.com/a1b2.pdf)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_csv() {
+        let mut server = mockito::Server::new_async().await;
+
+        let fields_mock = server
+            .mock("GET", "/api/database/fields/table/1234/")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                json!([
+                    {"id": 2, "table_id": 1234, "name": "Status", "order": 1, "type": "single_select", "primary": false, "read_only": false},
+                    {"id": 1, "table_id": 1234, "name": "Name", "order": 0, "type": "text", "primary": true, "read_only": false}
+                ])
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let page_1 = server
+            .mock("GET", "/api/database/rows/table/1234/")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("page".into(), "1".into()),
+                mockito::Matcher::UrlEncoded("user_field_names".into(), "true".into()),
+            ]))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                json!({
+                    "count": 2,
+                    "next": "http://example.com/?page=2",
+                    "previous": null,
+                    "results": [
+                        {"id": 1, "Name": "Ada, Countess", "Status": {"id": 7, "value": "Open", "color": "blue"}}
+                    ]
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let page_2 = server
+            .mock("GET", "/api/database/rows/table/1234/")
+            .match_query(mockito::Matcher::UrlEncoded("page".into(), "2".into()))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                json!({
+                    "count": 2,
+                    "next": null,
+                    "previous": null,
+                    "results": [{"id": 2, "Name": "Grace", "Status": null}]
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let config = ConfigBuilder::new()
+            .base_url(&server.url())
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let table = Baserow::with_configuration(config).table_by_id(TableId(1234));
+
+        let mut csv = Vec::new();
+        let rows = table.query().size(1).export_csv(&mut csv).await.unwrap();
+        assert_eq!(rows, 2);
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "id,Name,Status\n1,\"Ada, Countess\",Open\n2,Grace,\n"
+        );
+
+        fields_mock.assert_async().await;
+        page_1.assert_async().await;
+        page_2.assert_async().await;
+    }
+}
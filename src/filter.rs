@@ -1,3 +1,10 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::ids::RowId;
+
 /// Filter operations available for querying Baserow tables
 ///
 /// This enum provides all the possible filter operations that can be used
@@ -6,7 +13,7 @@
 ///
 /// # Example
 /// ```no_run
-/// use baserow_rs::{ConfigBuilder, Baserow, BaserowTableOperations, filter::Filter, api::client::BaserowClient};
+/// use baserow_rs::{ConfigBuilder, Baserow, BaserowTableOperations, TableId, filter::Filter, api::client::BaserowClient};
 /// use std::collections::HashMap;
 /// use serde_json::Value;
 ///
@@ -15,10 +22,11 @@
 ///     let config = ConfigBuilder::new()
 ///         .base_url("https://api.baserow.io")
 ///         .api_key("your-api-key")
-///         .build();
+///         .try_build()
+///         .expect("invalid configuration");
 ///
 ///     let baserow = Baserow::with_configuration(config);
-///     let table = baserow.table_by_id(1234);
+///     let table = baserow.table_by_id(TableId(1234));
 ///
 ///     // Query with multiple filters
 ///     let results = table.query()
@@ -30,7 +38,10 @@
 ///         .unwrap();
 /// }
 /// ```
-#[derive(Debug, Clone)]
+///
+/// Filters serialize to the names Baserow uses in query parameters, e.g. `"equal"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Filter {
     /// Exact match comparison
     /// Field value must exactly match the provided value
@@ -284,7 +295,7 @@ impl Filter {
 ///
 /// Combines a field name, filter operation, and value into a single filter condition
 /// that can be applied to a table query.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FilterTriple {
     /// The name of the field to filter on
     pub field: String,
@@ -293,3 +304,349 @@ pub struct FilterTriple {
     /// The value to compare against
     pub value: String,
 }
+
+/// How the conditions of a filter group are combined
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum FilterType {
+    /// All conditions must match
+    #[default]
+    And,
+    /// Any of the conditions must match
+    Or,
+}
+
+impl FilterType {
+    /// Converts the filter type to its string representation for API requests
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FilterType::And => "AND",
+            FilterType::Or => "OR",
+        }
+    }
+}
+
+/// Conditions combined with AND and OR, nested to any depth
+///
+/// Trees are built with [`field`] and combined with [`and`](Self::and) and
+/// [`or`](Self::or), which check the type of the argument each operator
+/// expects at compile time:
+///
+/// ```
+/// use baserow_rs::filter::{field, FilterTree};
+///
+/// let adult_and_active: FilterTree = field("Age").gt(18).and(field("Status").eq("Active"));
+/// let urgent = field("Priority").eq("High").or(field("Due").before("2024-06-01"));
+/// let tree = adult_and_active.and(urgent);
+/// ```
+///
+/// Trees are sent in the `filters` query parameter, together with the
+/// conditions added via `filter_by`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterTree {
+    /// A single condition on a field
+    Condition(FilterTriple),
+    /// Conditions and groups combined by the filter type
+    Group {
+        filter_type: FilterType,
+        filters: Vec<FilterTree>,
+    },
+}
+
+impl FilterTree {
+    /// Matches rows matching all of the given trees
+    pub fn all(filters: impl IntoIterator<Item = FilterTree>) -> Self {
+        FilterTree::Group {
+            filter_type: FilterType::And,
+            filters: filters.into_iter().collect(),
+        }
+    }
+
+    /// Matches rows matching any of the given trees
+    pub fn any(filters: impl IntoIterator<Item = FilterTree>) -> Self {
+        FilterTree::Group {
+            filter_type: FilterType::Or,
+            filters: filters.into_iter().collect(),
+        }
+    }
+
+    /// Matches rows matching both this and the other tree
+    pub fn and(self, other: FilterTree) -> Self {
+        self.combine(FilterType::And, other)
+    }
+
+    /// Matches rows matching this or the other tree
+    pub fn or(self, other: FilterTree) -> Self {
+        self.combine(FilterType::Or, other)
+    }
+
+    /// Appends to a group of the same type, so chains stay flat
+    fn combine(self, filter_type: FilterType, other: FilterTree) -> Self {
+        match self {
+            FilterTree::Group {
+                filter_type: current,
+                mut filters,
+            } if current == filter_type => {
+                filters.push(other);
+                FilterTree::Group {
+                    filter_type,
+                    filters,
+                }
+            }
+            tree => FilterTree::Group {
+                filter_type,
+                filters: vec![tree, other],
+            },
+        }
+    }
+
+    /// Converts the tree to the JSON of the `filters` query parameter
+    ///
+    /// Baserow expects a group at the root, a single condition is wrapped
+    /// into one. Fields are keyed as returned by `field_key`.
+    pub(crate) fn to_json(&self, field_key: &dyn Fn(&str) -> Value) -> Value {
+        match self {
+            FilterTree::Condition(_) => {
+                group_json(FilterType::And, std::slice::from_ref(self), field_key)
+            }
+            FilterTree::Group {
+                filter_type,
+                filters,
+            } => group_json(*filter_type, filters, field_key),
+        }
+    }
+}
+
+fn group_json(
+    filter_type: FilterType,
+    filters: &[FilterTree],
+    field_key: &dyn Fn(&str) -> Value,
+) -> Value {
+    let mut conditions = Vec::new();
+    let mut groups = Vec::new();
+    for filter in filters {
+        match filter {
+            FilterTree::Condition(triple) => conditions.push(json!({
+                "field": field_key(&triple.field),
+                "type": triple.filter.as_str(),
+                "value": triple.value,
+            })),
+            FilterTree::Group {
+                filter_type,
+                filters,
+            } => groups.push(group_json(*filter_type, filters, field_key)),
+        }
+    }
+    json!({
+        "filter_type": filter_type.as_str(),
+        "filters": conditions,
+        "groups": groups,
+    })
+}
+
+impl From<FilterTriple> for FilterTree {
+    fn from(triple: FilterTriple) -> Self {
+        FilterTree::Condition(triple)
+    }
+}
+
+/// Numbers that numeric filters compare against
+pub trait FilterNumber: fmt::Display {}
+
+macro_rules! filter_numbers {
+    ($($number:ty),*) => {
+        $(impl FilterNumber for $number {})*
+    };
+}
+
+filter_numbers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// Starts a condition on the field with the given name, see [`FilterTree`]
+pub fn field(name: &str) -> FieldFilter {
+    FieldFilter {
+        field: name.to_string(),
+    }
+}
+
+/// A field to build a condition on, created via [`field`]
+///
+/// Each operator takes the type of value Baserow expects for it, e.g.
+/// numbers for comparisons and select option IDs for select fields.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldFilter {
+    field: String,
+}
+
+impl FieldFilter {
+    /// Applies any filter, with the value as Baserow expects it
+    pub fn matches(self, filter: Filter, value: impl fmt::Display) -> FilterTree {
+        FilterTree::Condition(FilterTriple {
+            field: self.field,
+            filter,
+            value: value.to_string(),
+        })
+    }
+
+    /// The value equals the given text or number
+    pub fn eq(self, value: impl fmt::Display) -> FilterTree {
+        self.matches(Filter::Equal, value)
+    }
+
+    /// The value does not equal the given text or number
+    pub fn ne(self, value: impl fmt::Display) -> FilterTree {
+        self.matches(Filter::NotEqual, value)
+    }
+
+    /// The number is greater than the given one
+    pub fn gt(self, number: impl FilterNumber) -> FilterTree {
+        self.matches(Filter::HigherThan, number)
+    }
+
+    /// The number is greater than or equal to the given one
+    pub fn gte(self, number: impl FilterNumber) -> FilterTree {
+        self.matches(Filter::HigherThanOrEqual, number)
+    }
+
+    /// The number is less than the given one
+    pub fn lt(self, number: impl FilterNumber) -> FilterTree {
+        self.matches(Filter::LowerThan, number)
+    }
+
+    /// The number is less than or equal to the given one
+    pub fn lte(self, number: impl FilterNumber) -> FilterTree {
+        self.matches(Filter::LowerThanOrEqual, number)
+    }
+
+    /// The text contains the given text, ignoring case
+    pub fn contains(self, text: &str) -> FilterTree {
+        self.matches(Filter::Contains, text)
+    }
+
+    /// The text does not contain the given text, ignoring case
+    pub fn not_contains(self, text: &str) -> FilterTree {
+        self.matches(Filter::ContainsNot, text)
+    }
+
+    /// The text contains the given word
+    pub fn contains_word(self, word: &str) -> FilterTree {
+        self.matches(Filter::ContainsWord, word)
+    }
+
+    /// The text is shorter than the given number of characters
+    pub fn shorter_than(self, length: usize) -> FilterTree {
+        self.matches(Filter::LengthIsLowerThan, length)
+    }
+
+    /// The field has no value
+    pub fn empty(self) -> FilterTree {
+        self.matches(Filter::Empty, "")
+    }
+
+    /// The field has a value
+    pub fn not_empty(self) -> FilterTree {
+        self.matches(Filter::NotEmpty, "")
+    }
+
+    /// The boolean field is set to the given value
+    pub fn boolean(self, value: bool) -> FilterTree {
+        self.matches(Filter::Boolean, value)
+    }
+
+    /// The date is before the given UTC date, formatted like `2024-01-31`
+    pub fn before(self, date: &str) -> FilterTree {
+        self.matches(Filter::DateIsBefore, exact_date(date))
+    }
+
+    /// The date is after the given UTC date, formatted like `2024-01-31`
+    pub fn after(self, date: &str) -> FilterTree {
+        self.matches(Filter::DateIsAfter, exact_date(date))
+    }
+
+    /// The date is the given UTC date, formatted like `2024-01-31`
+    pub fn on(self, date: &str) -> FilterTree {
+        self.matches(Filter::DateIs, exact_date(date))
+    }
+
+    /// The single select field is set to the option with the given ID
+    pub fn is_option(self, option_id: u64) -> FilterTree {
+        self.matches(Filter::SingleSelectEqual, option_id)
+    }
+
+    /// The single select field is set to any of the options with the given IDs
+    pub fn is_any_option(self, option_ids: &[u64]) -> FilterTree {
+        let ids: Vec<String> = option_ids.iter().map(u64::to_string).collect();
+        self.matches(Filter::SingleSelectIsAnyOf, ids.join(","))
+    }
+
+    /// The multiple select field includes the option with the given ID
+    pub fn has_option(self, option_id: u64) -> FilterTree {
+        self.matches(Filter::MultipleSelectHas, option_id)
+    }
+
+    /// The link row field links to the row with the given ID
+    pub fn links_to(self, row_id: RowId) -> FilterTree {
+        self.matches(Filter::LinkRowHas, row_id)
+    }
+
+    /// The primary field of a linked row contains the given text
+    pub fn links_containing(self, text: &str) -> FilterTree {
+        self.matches(Filter::LinkRowContains, text)
+    }
+}
+
+/// Formats the value of a `date_is*` filter comparing with an exact UTC date
+pub(crate) fn exact_date(date: &str) -> String {
+    format!("UTC?{}?exact_date", date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_tree() {
+        let tree = field("Age")
+            .gt(18)
+            .and(field("Status").eq("Active"))
+            .and(field("Name").contains("Jo").or(field("Score").lte(2.5)));
+        let key = |field: &str| match field {
+            "Age" => json!(2),
+            name => json!(name),
+        };
+
+        assert_eq!(
+            tree.to_json(&key),
+            json!({
+                "filter_type": "AND",
+                "filters": [
+                    {"field": 2, "type": "higher_than", "value": "18"},
+                    {"field": "Status", "type": "equal", "value": "Active"},
+                ],
+                "groups": [{
+                    "filter_type": "OR",
+                    "filters": [
+                        {"field": "Name", "type": "contains", "value": "Jo"},
+                        {"field": "Score", "type": "lower_than_or_equal", "value": "2.5"},
+                    ],
+                    "groups": [],
+                }],
+            })
+        );
+        assert_eq!(
+            field("Tags").is_any_option(&[1, 3]).to_json(&key)["filters"][0]["value"],
+            "1,3"
+        );
+        assert_eq!(
+            field("Due").before("2024-06-01").to_json(&key)["filters"][0],
+            json!({"field": "Due", "type": "date_is_before", "value": "UTC?2024-06-01?exact_date"})
+        );
+        assert_eq!(
+            field("Due").on("2024-06-01").to_json(&key)["filters"][0]["type"],
+            "date_is"
+        );
+
+        let json = serde_json::to_string(&tree).unwrap();
+        assert_eq!(serde_json::from_str::<FilterTree>(&json).unwrap(), tree);
+    }
+}
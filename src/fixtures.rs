@@ -0,0 +1,307 @@
+//! Fixtures for tests
+//!
+//! Constructors for fields, rows and pages of rows, so tests of code built on
+//! this crate don't have to spell out the JSON of every field type. Combined
+//! with a mock server, they make up the responses of Baserow:
+//! [`fields_json`] for the fields of a table, [`RowsResponse::to_json`] for a
+//! page of rows.
+//!
+//! # Example
+//! ```
+//! use baserow_rs::{api::table_operations::RowsResponse, fixtures::{self, row}, TableField};
+//! use serde_json::json;
+//!
+//! let fields = vec![
+//!     TableField::text("Name", 1).as_primary(),
+//!     TableField::number("Price", 2, 2),
+//!     TableField::single_select("Status", 3, &["Open", "Done"]),
+//! ];
+//! let fields_body = fixtures::fields_json(&fields);
+//!
+//! let status = fields[2].select_options[0].clone();
+//! let rows_body = RowsResponse::from_rows(vec![
+//!     row(1, [("Name", json!("Ada")), ("Price", json!("12.50")), ("Status", json!(status))]),
+//!     row(2, [("Name", json!("Grace"))]),
+//! ])
+//! .to_json();
+//! ```
+
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use crate::{
+    api::table_operations::RowsResponse, ids::FieldId, value::SelectOption, TableField, TableId,
+};
+
+impl TableField {
+    /// Creates a field of the given type in table 1, ordered by its ID
+    pub fn new(name: &str, id: u64, field_type: &str) -> Self {
+        Self {
+            id: FieldId(id),
+            table_id: TableId(1),
+            name: name.to_string(),
+            order: id as u32,
+            r#type: field_type.to_string(),
+            primary: false,
+            read_only: false,
+            description: None,
+            duration_format: None,
+            max_value: None,
+            formula_type: None,
+            array_formula_type: None,
+            select_options: Vec::new(),
+            number_decimal_places: None,
+            number_negative: None,
+            date_format: None,
+            date_include_time: None,
+        }
+    }
+
+    /// Creates a single line text field
+    pub fn text(name: &str, id: u64) -> Self {
+        Self::new(name, id, "text")
+    }
+
+    /// Creates a multi-line text field
+    pub fn long_text(name: &str, id: u64) -> Self {
+        Self::new(name, id, "long_text")
+    }
+
+    /// Creates an email field
+    pub fn email(name: &str, id: u64) -> Self {
+        Self::new(name, id, "email")
+    }
+
+    /// Creates a URL field
+    pub fn url(name: &str, id: u64) -> Self {
+        Self::new(name, id, "url")
+    }
+
+    /// Creates a number field with the given number of decimal places
+    pub fn number(name: &str, id: u64, decimal_places: u32) -> Self {
+        Self {
+            number_decimal_places: Some(decimal_places),
+            number_negative: Some(true),
+            ..Self::new(name, id, "number")
+        }
+    }
+
+    /// Creates a rating field with a maximum of 5
+    pub fn rating(name: &str, id: u64) -> Self {
+        Self {
+            max_value: Some(5),
+            ..Self::new(name, id, "rating")
+        }
+    }
+
+    /// Creates a boolean field
+    pub fn boolean(name: &str, id: u64) -> Self {
+        Self::new(name, id, "boolean")
+    }
+
+    /// Creates a date field in ISO format, with or without a time
+    pub fn date(name: &str, id: u64, include_time: bool) -> Self {
+        Self {
+            date_format: Some("ISO".to_string()),
+            date_include_time: Some(include_time),
+            ..Self::new(name, id, "date")
+        }
+    }
+
+    /// Creates a duration field with the given format, e.g. `h:mm`
+    pub fn duration(name: &str, id: u64, format: &str) -> Self {
+        Self {
+            duration_format: Some(format.to_string()),
+            ..Self::new(name, id, "duration")
+        }
+    }
+
+    /// Creates a single select field with the given options
+    ///
+    /// The IDs of the options are derived from the field ID: the options of
+    /// field 3 get the IDs 301, 302 and so on.
+    pub fn single_select(name: &str, id: u64, options: &[&str]) -> Self {
+        Self {
+            select_options: select_options(id, options),
+            ..Self::new(name, id, "single_select")
+        }
+    }
+
+    /// Creates a multiple select field with the given options, see `single_select`
+    pub fn multiple_select(name: &str, id: u64, options: &[&str]) -> Self {
+        Self {
+            select_options: select_options(id, options),
+            ..Self::new(name, id, "multiple_select")
+        }
+    }
+
+    /// Creates a field linking to rows of another table
+    pub fn link_row(name: &str, id: u64) -> Self {
+        Self::new(name, id, "link_row")
+    }
+
+    /// Creates a file field
+    pub fn file(name: &str, id: u64) -> Self {
+        Self::new(name, id, "file")
+    }
+
+    /// Creates a read-only formula field with the given result type, e.g. `number`
+    pub fn formula(name: &str, id: u64, formula_type: &str) -> Self {
+        Self {
+            read_only: true,
+            formula_type: Some(formula_type.to_string()),
+            ..Self::new(name, id, "formula")
+        }
+    }
+
+    /// Makes the field the primary field of its table
+    pub fn as_primary(mut self) -> Self {
+        self.primary = true;
+        self
+    }
+
+    /// Moves the field to the given table
+    pub fn in_table(mut self, table_id: TableId) -> Self {
+        self.table_id = table_id;
+        self
+    }
+}
+
+fn select_options(field_id: u64, options: &[&str]) -> Vec<SelectOption> {
+    options
+        .iter()
+        .zip(1..)
+        .map(|(value, position)| SelectOption {
+            id: field_id * 100 + position,
+            value: value.to_string(),
+            color: "blue".to_string(),
+        })
+        .collect()
+}
+
+impl RowsResponse {
+    /// Creates the only page of a query, holding the given rows
+    pub fn from_rows(rows: Vec<HashMap<String, Value>>) -> Self {
+        Self {
+            count: Some(rows.len() as i32),
+            next: None,
+            previous: None,
+            results: rows,
+        }
+    }
+
+    /// Splits rows into the pages of a query, linked via their `next` and `previous` URLs
+    ///
+    /// Returns a single empty page if there are no rows.
+    pub fn pages(rows: Vec<HashMap<String, Value>>, page_size: usize) -> Vec<Self> {
+        let count = rows.len() as i32;
+        let chunks: Vec<_> = rows.chunks(page_size.max(1)).map(<[_]>::to_vec).collect();
+        if chunks.is_empty() {
+            return vec![Self::from_rows(Vec::new())];
+        }
+
+        let pages = chunks.len();
+        let url =
+            |page: usize| format!("http://localhost/api/database/rows/table/1/?page={}", page);
+        chunks
+            .into_iter()
+            .enumerate()
+            .map(|(index, results)| Self {
+                count: Some(count),
+                next: (index + 1 < pages).then(|| url(index + 2)),
+                previous: (index > 0).then(|| url(index)),
+                results,
+            })
+            .collect()
+    }
+
+    /// Serializes the page as Baserow returns it
+    pub fn to_json(&self) -> String {
+        json!(self).to_string()
+    }
+}
+
+/// Creates a row with the given ID and field values
+///
+/// The row gets an `order` matching its ID, like rows Baserow returns.
+pub fn row<'a>(
+    id: u64,
+    fields: impl IntoIterator<Item = (&'a str, Value)>,
+) -> HashMap<String, Value> {
+    let mut row: HashMap<String, Value> = fields
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value))
+        .collect();
+    row.insert("id".to_string(), json!(id));
+    row.insert(
+        "order".to_string(),
+        json!(format!("{}.00000000000000000000", id)),
+    );
+    row
+}
+
+/// Serializes fields as Baserow returns them from the fields endpoint of a table
+pub fn fields_json(fields: &[TableField]) -> String {
+    json!(fields).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{api::client::BaserowClient, Baserow, BaserowTableOperations, ConfigBuilder};
+
+    #[tokio::test]
+    async fn test_fixtures() {
+        let mut server = mockito::Server::new_async().await;
+        let fields = vec![
+            TableField::text("Name", 1).as_primary(),
+            TableField::single_select("Status", 2, &["Open", "Done"]),
+            TableField::formula("Total", 3, "number").in_table(TableId(1234)),
+        ];
+        assert_eq!(fields[1].select_options[1].id, 202);
+
+        let fields_mock = server
+            .mock("GET", "/api/database/fields/table/1234/")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(fields_json(&fields))
+            .create_async()
+            .await;
+        let rows = (1..=3)
+            .map(|id| row(id, [("Name", json!(format!("Row {}", id)))]))
+            .collect();
+        let pages = RowsResponse::pages(rows, 2);
+        assert_eq!(pages.len(), 2);
+        let rows_mock = server
+            .mock("GET", "/api/database/rows/table/1234/")
+            .match_query(mockito::Matcher::UrlEncoded("page".into(), "2".into()))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(pages[1].to_json())
+            .create_async()
+            .await;
+
+        let config = ConfigBuilder::new()
+            .base_url(&server.url())
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(config);
+        let table = baserow.table_by_id(TableId(1234)).auto_map().await.unwrap();
+        assert!(table.mapper.as_ref().unwrap().fields()[2].read_only);
+
+        let page = table
+            .query()
+            .page(2)
+            .get::<HashMap<String, Value>>()
+            .await
+            .unwrap();
+        assert_eq!(page.count, Some(3));
+        assert_eq!(page.results[0]["Name"], "Row 3");
+        assert!(page.next.is_none() && page.previous.is_some());
+
+        fields_mock.assert_async().await;
+        rows_mock.assert_async().await;
+    }
+}
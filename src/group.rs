@@ -0,0 +1,213 @@
+//! Rows grouped by the value of a field
+//!
+//! [`RowRequestBuilder::get_grouped`] fetches the rows matching a query and
+//! groups them by a field, e.g. by status for a kanban board or by customer
+//! for a report. Every group holds the value it was formed for, the number of
+//! its rows and the rows themselves, optionally limited per group.
+//!
+//! Groups are listed in the order their first row was returned, so order the
+//! query by the grouped field to get the groups sorted. Rows without a value
+//! form a group with a `null` value.
+//!
+//! The rows are grouped by the client: the rows endpoint of tables cannot
+//! group rows, and the `group_by_metadata` of grid views only holds the
+//! number of rows per value. So every page of rows matching the query is
+//! downloaded, even with a limit per group, which only bounds the rows kept
+//! in memory. Filter the query to the rows of interest on large tables.
+//!
+//! # Example
+//! ```no_run
+//! use baserow_rs::prelude::*;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Task {
+//!     #[serde(rename = "Name")]
+//!     name: String,
+//! }
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let config = ConfigBuilder::new()
+//!         .base_url("https://api.baserow.io")
+//!         .api_key("your-api-key")
+//!         .try_build()
+//!         .expect("invalid configuration");
+//!     let baserow = Baserow::with_configuration(config);
+//!
+//!     let columns = baserow
+//!         .table_by_id(TableId(1234))
+//!         .auto_map()
+//!         .await
+//!         .unwrap()
+//!         .query()
+//!         .order_by("Status", OrderDirection::Asc)
+//!         .get_grouped::<Task>("Status", Some(10))
+//!         .await
+//!         .unwrap();
+//!     for column in columns {
+//!         println!("{} ({} tasks)", column.value, column.count);
+//!         for task in column.rows {
+//!             println!("  {}", task.name);
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use tracing::{debug, instrument};
+
+use crate::{
+    api::table_operations::{row_value, BaserowTableOperations, RowRequestBuilder},
+    error::BaserowError,
+};
+
+/// The rows sharing a value of the grouped field
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Group<T> {
+    /// The value of the grouped field, as returned by Baserow
+    pub value: Value,
+    /// The number of rows in the group, including those beyond the limit
+    pub count: usize,
+    /// The rows of the group, up to the limit per group
+    pub rows: Vec<T>,
+}
+
+impl RowRequestBuilder {
+    /// Fetches all rows matching the query, grouped by the value of a field
+    ///
+    /// Keeps up to `rows_per_group` rows of each group, or all of them if
+    /// `None`, while counting all. The page of the query is ignored, its size
+    /// is used for the requests. All matching rows are downloaded, as they
+    /// are grouped by the client, see the [module documentation](crate::group).
+    #[instrument(skip(self), err)]
+    pub async fn get_grouped<T>(
+        &self,
+        field: &str,
+        rows_per_group: Option<usize>,
+    ) -> Result<Vec<Group<T>>, BaserowError>
+    where
+        T: DeserializeOwned + 'static,
+    {
+        let (table, baserow) = self.target()?;
+        let keys = table.row_keys(field);
+
+        let mut groups: Vec<Group<T>> = Vec::new();
+        // Positions of the groups by their value, serialized
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        let mut request = self.request.clone();
+        for page in 1.. {
+            request.page = Some(page);
+            debug!(page, "Fetching page to group");
+            let response = table.get_rows(baserow.clone(), request.clone()).await?;
+
+            for row in response.results {
+                let value = row_value(&row, &keys).cloned().unwrap_or(Value::Null);
+                let position = *positions.entry(value.to_string()).or_insert_with(|| {
+                    groups.push(Group {
+                        value,
+                        count: 0,
+                        rows: Vec::new(),
+                    });
+                    groups.len() - 1
+                });
+
+                let group = &mut groups[position];
+                group.count += 1;
+                if rows_per_group.is_none_or(|limit| group.rows.len() < limit) {
+                    let row = serde_json::from_value(Value::Object(row.into_iter().collect()))?;
+                    group.rows.push(row);
+                }
+            }
+            if response.next.is_none() {
+                break;
+            }
+        }
+        Ok(groups)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{client::BaserowClient, table_operations::RowsResponse},
+        fixtures::{fields_json, row},
+        Baserow, ConfigBuilder, OrderDirection, TableField, TableId,
+    };
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_get_grouped() {
+        let mut server = mockito::Server::new_async().await;
+        let fields = vec![
+            TableField::text("Name", 1).as_primary(),
+            TableField::single_select("Status", 2, &["Open", "Done"]),
+        ];
+        let open = json!(fields[1].select_options[0]);
+        let done = json!(fields[1].select_options[1]);
+        let fields_mock = server
+            .mock("GET", "/api/database/fields/table/1234/")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(fields_json(&fields))
+            .create_async()
+            .await;
+        let pages = RowsResponse::pages(
+            vec![
+                row(1, [("field_1", json!("Ada")), ("field_2", done.clone())]),
+                row(2, [("field_1", json!("Grace")), ("field_2", open.clone())]),
+                row(3, [("field_1", json!("Linus")), ("field_2", done.clone())]),
+                row(4, [("field_1", json!("Ken")), ("field_2", Value::Null)]),
+            ],
+            2,
+        );
+        let mut page_mocks = Vec::new();
+        for (page, body) in pages.iter().enumerate() {
+            let mock = server
+                .mock("GET", "/api/database/rows/table/1234/")
+                .match_query(mockito::Matcher::AllOf(vec![
+                    mockito::Matcher::UrlEncoded("order_by".into(), "-field_2".into()),
+                    mockito::Matcher::UrlEncoded("page".into(), (page + 1).to_string()),
+                ]))
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(body.to_json())
+                .create_async()
+                .await;
+            page_mocks.push(mock);
+        }
+
+        let config = ConfigBuilder::new()
+            .base_url(&server.url())
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(config);
+        let groups = baserow
+            .table_by_id(TableId(1234))
+            .auto_map()
+            .await
+            .unwrap()
+            .query()
+            .order_by("Status", OrderDirection::Desc)
+            .get_grouped::<HashMap<String, Value>>("Status", Some(1))
+            .await
+            .unwrap();
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!((&groups[0].value, groups[0].count), (&done, 2));
+        assert_eq!(groups[0].rows.len(), 1);
+        assert_eq!(groups[0].rows[0]["Name"], "Ada");
+        assert_eq!((&groups[1].value, groups[1].count), (&open, 1));
+        assert_eq!((&groups[2].value, groups[2].count), (&Value::Null, 1));
+
+        fields_mock.assert_async().await;
+        for mock in page_mocks {
+            mock.assert_async().await;
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! Typed IDs of Baserow objects
+//!
+//! Tables, rows, fields and views are identified by numbers, which are easily
+//! mixed up. Each kind of ID has a type of its own, so passing a field ID
+//! where a row ID is expected does not compile.
+//!
+//! # Example
+//! ```
+//! use baserow_rs::ids::{RowId, TableId};
+//!
+//! let table_id = TableId(1234);
+//! let row_id: RowId = 42.into();
+//! assert_eq!(format!("api/database/rows/table/{}/{}/", table_id, row_id), "api/database/rows/table/1234/42/");
+//! assert_eq!(u64::from(row_id), 42);
+//! ```
+
+use std::{fmt, num::ParseIntError, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+macro_rules! id_type {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(
+            Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+        )]
+        #[serde(transparent)]
+        pub struct $name(pub u64);
+
+        impl $name {
+            /// Returns the ID as number
+            pub const fn get(self) -> u64 {
+                self.0
+            }
+        }
+
+        impl From<u64> for $name {
+            fn from(id: u64) -> Self {
+                Self(id)
+            }
+        }
+
+        impl From<$name> for u64 {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseIntError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.parse().map(Self)
+            }
+        }
+    };
+}
+
+id_type!(
+    /// The ID of a table
+    TableId
+);
+id_type!(
+    /// The ID of a row, unique within its table
+    RowId
+);
+id_type!(
+    /// The ID of a field, used in `field_<id>` keys of rows
+    FieldId
+);
+id_type!(
+    /// The ID of a view
+    ViewId
+);
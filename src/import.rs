@@ -0,0 +1,719 @@
+//! Import of rows
+//!
+//! [`BaserowTable::import_jsonl`] creates a row per line of a JSON Lines
+//! reader, e.g. to restore a backup written by
+//! [`BaserowTable::export_jsonl`]. With the `csv` feature,
+//! [`BaserowTable::import_csv`] creates a row per record of a CSV reader,
+//! converting the text of each cell to the type of its field.
+//!
+//! Rows are created via the batch API as they are read, so large files
+//! don't have to be held in memory.
+//!
+//! # Example
+//! ```no_run
+//! # #[cfg(feature = "csv")]
+//! # async fn example() {
+//! use baserow_rs::{import::CsvImportOptions, prelude::*};
+//! use std::fs::File;
+//!
+//! let config = ConfigBuilder::new()
+//!     .base_url("https://api.baserow.io")
+//!     .api_key("your-api-key")
+//!     .try_build()
+//!     .expect("invalid configuration");
+//! let baserow = Baserow::with_configuration(config);
+//!
+//! let options = CsvImportOptions::new()
+//!     .column("E-Mail", "Email")
+//!     .on_progress(|progress| println!("{} rows created", progress.created));
+//! let file = File::open("customers.csv").unwrap();
+//! let summary = baserow
+//!     .table_by_id(TableId(1234))
+//!     .import_csv(file, options)
+//!     .await
+//!     .unwrap();
+//! for error in &summary.errors {
+//!     println!("Record {} was not imported: {}", error.index, error.error);
+//! }
+//! # }
+//! ```
+
+use std::collections::HashMap;
+#[cfg(feature = "csv")]
+use std::{io::Read, sync::Arc};
+
+#[cfg(feature = "csv")]
+use serde_json::json;
+use serde_json::Value;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt};
+use tracing::{debug, info, instrument};
+
+#[cfg(feature = "csv")]
+use crate::{api::batch::RowError, value::parse_duration};
+use crate::{
+    api::{
+        batch::{BatchResult, BATCH_SIZE},
+        client::BaserowClient,
+        table_operations::BaserowTableOperations,
+    },
+    error::BaserowError,
+    value::BaserowFieldValue,
+    BaserowTable, TableField,
+};
+
+/// Converts a row as read from Baserow into the values to create it with
+///
+/// The `id` and `order` of the row and the values of read-only fields, like
+/// formulas, are left out. Select options, linked rows, files and
+/// collaborators are reduced to the references Baserow accepts when writing.
+/// Keys without a field are kept, so Baserow reports them.
+fn writable_row(row: HashMap<String, Value>, fields: &[TableField]) -> HashMap<String, Value> {
+    row.into_iter()
+        .filter(|(key, _)| key != "id" && key != "order")
+        .filter_map(|(key, value)| match fields.iter().find(|f| f.name == key) {
+            Some(field) if field.read_only => None,
+            Some(field) => {
+                let value = match BaserowFieldValue::from_field(field, value.clone()) {
+                    Ok(converted) => converted.to_value(),
+                    Err(_) => value,
+                };
+                Some((key, value))
+            }
+            None => Some((key, value)),
+        })
+        .collect()
+}
+
+/// How far a CSV import got, reported after each batch
+#[cfg(feature = "csv")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ImportProgress {
+    /// The number of records read
+    pub read: usize,
+    /// The number of rows created
+    pub created: usize,
+    /// The number of records that were not imported
+    pub failed: usize,
+}
+
+/// Callback invoked with the progress of a CSV import
+#[cfg(feature = "csv")]
+pub type ProgressCallback = dyn Fn(&ImportProgress) + Send + Sync;
+
+/// Options of a CSV import
+///
+/// By default, columns are matched to the fields of the same name, an `id`
+/// column is ignored and columns without a field are rejected.
+#[cfg(feature = "csv")]
+#[derive(Clone, Default)]
+pub struct CsvImportOptions {
+    columns: HashMap<String, String>,
+    ignore_unknown_columns: bool,
+    raw_values: bool,
+    delimiter: Option<u8>,
+    progress: Option<Arc<ProgressCallback>>,
+}
+
+#[cfg(feature = "csv")]
+impl CsvImportOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Imports the column with the given header into the given field
+    pub fn column(mut self, header: &str, field: &str) -> Self {
+        self.columns.insert(header.to_string(), field.to_string());
+        self
+    }
+
+    /// Ignores columns without a field instead of failing the import
+    pub fn ignore_unknown_columns(mut self) -> Self {
+        self.ignore_unknown_columns = true;
+        self
+    }
+
+    /// Sends the text of the cells as is, leaving their conversion to Baserow
+    pub fn raw_values(mut self) -> Self {
+        self.raw_values = true;
+        self
+    }
+
+    /// Sets the delimiter of the CSV, `,` by default
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = Some(delimiter);
+        self
+    }
+
+    /// Registers a callback that is invoked after each batch of created rows
+    pub fn on_progress(
+        mut self,
+        callback: impl Fn(&ImportProgress) + Send + Sync + 'static,
+    ) -> Self {
+        self.progress = Some(Arc::new(callback));
+        self
+    }
+}
+
+/// The outcome of a CSV import
+#[cfg(feature = "csv")]
+#[derive(Debug)]
+pub struct CsvImportSummary {
+    /// The created rows, keyed by field names
+    pub rows: Vec<HashMap<String, Value>>,
+    /// The records that were not imported
+    ///
+    /// The index of an error is the position of the record in the CSV,
+    /// not counting the header.
+    pub errors: Vec<RowError>,
+}
+
+#[cfg(feature = "csv")]
+impl CsvImportSummary {
+    /// Returns whether all records were imported
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Returns the ID of the select option with the given value or ID
+#[cfg(feature = "csv")]
+fn select_option_id(field: &TableField, text: &str) -> Result<u64, String> {
+    let options = &field.select_options;
+    options
+        .iter()
+        .find(|option| option.value == text)
+        .or_else(|| {
+            options
+                .iter()
+                .find(|option| option.value.eq_ignore_ascii_case(text))
+        })
+        .or_else(|| {
+            let id = text.parse::<u64>().ok()?;
+            options.iter().find(|option| option.id == id)
+        })
+        .map(|option| option.id)
+        .ok_or_else(|| format!("{} is not an option of {}", text, field.name))
+}
+
+/// Converts the text of a cell to the value of its field
+///
+/// Select options are looked up by value, several values of multiple select
+/// and link row fields are separated by commas. Linked rows are given by ID
+/// or by the value of their primary field.
+#[cfg(feature = "csv")]
+fn coerce(field: &TableField, text: &str) -> Result<Value, String> {
+    let text = text.trim();
+    let list = || {
+        text.split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+    };
+
+    match field.r#type.as_str() {
+        "boolean" => match text.to_lowercase().as_str() {
+            "true" | "yes" | "y" | "1" | "x" | "checked" | "on" => Ok(json!(true)),
+            "" | "false" | "no" | "n" | "0" | "unchecked" | "off" => Ok(json!(false)),
+            _ => Err(format!("{} is not a boolean", text)),
+        },
+        "multiple_select" => list()
+            .map(|item| select_option_id(field, item))
+            .collect::<Result<Vec<_>, _>>()
+            .map(|ids| json!(ids)),
+        "link_row" => Ok(json!(list()
+            .map(|item| match item.parse::<u64>() {
+                Ok(id) => json!(id),
+                Err(_) => json!(item),
+            })
+            .collect::<Vec<_>>())),
+        _ if text.is_empty() => Ok(Value::Null),
+        "number" => match text.parse::<f64>() {
+            Ok(_) => Ok(json!(text)),
+            Err(_) => Err(format!("{} is not a number", text)),
+        },
+        "rating" => text
+            .parse::<u8>()
+            .map(|rating| json!(rating))
+            .map_err(|_| format!("{} is not a rating", text)),
+        "single_select" => select_option_id(field, text).map(|id| json!(id)),
+        "duration" => parse_duration(&json!(text))
+            .map(|duration| json!(duration.as_secs_f64()))
+            .map_err(|_| format!("{} is not a duration", text)),
+        _ => Ok(json!(text)),
+    }
+}
+
+/// The rows of a CSV import waiting to be created, with the positions of their records
+#[cfg(feature = "csv")]
+#[derive(Default)]
+struct PendingRows {
+    indexes: Vec<usize>,
+    rows: Vec<HashMap<String, Value>>,
+}
+
+impl BaserowTable {
+    /// Fetches the fields of the table, from the mapper if there is one
+    pub(crate) async fn fields(&self) -> Result<Vec<TableField>, BaserowError> {
+        match &self.mapper {
+            Some(mapper) => Ok(mapper.fields().to_vec()),
+            None => {
+                let table_id = self.id.ok_or(BaserowError::MissingState("Table ID"))?;
+                let baserow = self
+                    .baserow
+                    .as_ref()
+                    .ok_or(BaserowError::MissingState("Baserow instance"))?;
+                baserow.table_fields(table_id).await
+            }
+        }
+    }
+
+    /// Creates a row per line of a JSON Lines reader
+    ///
+    /// Each line holds a row keyed by field names, as written by
+    /// [`BaserowTable::export_jsonl`]. Rows are prepared for writing, see
+    /// the module documentation, and created in batches of up to
+    /// [`BATCH_SIZE`] rows. Empty lines are skipped. Row indexes in the
+    /// result count the non-empty lines.
+    ///
+    /// Reading stops at the first line that is not a JSON object, after the
+    /// rows before it were created.
+    #[instrument(skip(self, reader), fields(table_id = ?self.id), err)]
+    pub async fn import_jsonl<R: AsyncBufRead + Unpin + Send>(
+        &self,
+        reader: R,
+    ) -> Result<BatchResult<HashMap<String, Value>>, BaserowError> {
+        let fields = self.fields().await?;
+
+        let mut result = BatchResult { chunks: Vec::new() };
+        let mut rows = Vec::new();
+        let mut imported = 0;
+        let mut lines = reader.lines();
+        let mut number = 0;
+        while let Some(line) = lines.next_line().await? {
+            number += 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let row: HashMap<String, Value> = serde_json::from_str(&line).map_err(|e| {
+                BaserowError::InvalidRequest(format!("Line {} is not a row: {}", number, e))
+            })?;
+            rows.push(writable_row(row, &fields));
+
+            if rows.len() == BATCH_SIZE {
+                debug!(offset = imported, "Importing batch");
+                let batch = std::mem::take(&mut rows);
+                let count = batch.len();
+                result.append(self.create_many(batch, Some(true)).await?, imported);
+                imported += count;
+            }
+        }
+        if !rows.is_empty() {
+            debug!(offset = imported, "Importing batch");
+            let count = rows.len();
+            result.append(self.create_many(rows, Some(true)).await?, imported);
+            imported += count;
+        }
+
+        info!(
+            rows = imported,
+            created = result.rows().count(),
+            "Imported rows from JSON Lines"
+        );
+        Ok(result)
+    }
+
+    /// Creates a row per record of a CSV reader
+    ///
+    /// The first record holds the headers, which are matched to fields as
+    /// configured in the options. Columns of read-only fields are ignored.
+    /// Unless disabled via `raw_values()`, cells are converted to the type of
+    /// their field, e.g. numbers are validated, booleans are read from
+    /// values like `yes` or `x` and select options are looked up by value.
+    ///
+    /// Records with cells that cannot be converted are not sent, and records
+    /// Baserow rejects are reported as well, so the errors of the summary
+    /// cover all records that were not imported. Fails without importing
+    /// anything if the headers cannot be matched.
+    #[cfg(feature = "csv")]
+    #[instrument(skip(self, reader, options), fields(table_id = ?self.id), err)]
+    pub async fn import_csv<R: Read + Send>(
+        &self,
+        reader: R,
+        options: CsvImportOptions,
+    ) -> Result<CsvImportSummary, BaserowError> {
+        let fields = self.fields().await?;
+
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(options.delimiter.unwrap_or(b','))
+            .flexible(true)
+            .from_reader(reader);
+        let headers = reader.headers().map_err(std::io::Error::from)?.clone();
+
+        let mut columns = Vec::new();
+        let mut unknown = Vec::new();
+        for (position, header) in headers.iter().enumerate() {
+            let name = options.columns.get(header).map_or(header, String::as_str);
+            match fields.iter().find(|field| field.name == name) {
+                Some(field) if field.read_only => {}
+                Some(field) => columns.push((position, field)),
+                None if name == "id" || options.ignore_unknown_columns => {}
+                None => unknown.push(header.to_string()),
+            }
+        }
+        if !unknown.is_empty() {
+            return Err(BaserowError::InvalidRequest(format!(
+                "No field for the columns {}",
+                unknown.join(", ")
+            )));
+        }
+
+        let mut summary = CsvImportSummary {
+            rows: Vec::new(),
+            errors: Vec::new(),
+        };
+        let mut pending = PendingRows::default();
+        let mut read = 0;
+        for (index, record) in reader.records().enumerate() {
+            read += 1;
+            let record = match record {
+                Ok(record) => record,
+                Err(e) => {
+                    let error = BaserowError::IoError(e.into());
+                    summary.errors.push(RowError { index, error });
+                    continue;
+                }
+            };
+
+            let mut row = HashMap::new();
+            let mut invalid: HashMap<String, Vec<String>> = HashMap::new();
+            for (position, field) in &columns {
+                let text = record.get(*position).unwrap_or_default();
+                let value = match options.raw_values {
+                    true => Ok(Value::String(text.to_string())),
+                    false => coerce(field, text),
+                };
+                match value {
+                    Ok(value) => {
+                        row.insert(field.name.clone(), value);
+                    }
+                    Err(reason) => invalid.entry(field.name.clone()).or_default().push(reason),
+                }
+            }
+            if !invalid.is_empty() {
+                let error = BaserowError::InvalidFieldValues(invalid);
+                summary.errors.push(RowError { index, error });
+                continue;
+            }
+
+            pending.indexes.push(index);
+            pending.rows.push(row);
+            if pending.rows.len() == BATCH_SIZE {
+                self.create_pending(std::mem::take(&mut pending), &mut summary)
+                    .await?;
+                report_progress(&options, read, &summary);
+            }
+        }
+        if !pending.rows.is_empty() {
+            self.create_pending(pending, &mut summary).await?;
+        }
+        report_progress(&options, read, &summary);
+
+        info!(
+            records = read,
+            created = summary.rows.len(),
+            failed = summary.errors.len(),
+            "Imported rows from CSV"
+        );
+        Ok(summary)
+    }
+
+    /// Creates the pending rows of a CSV import, adding the outcome to the summary
+    #[cfg(feature = "csv")]
+    async fn create_pending(
+        &self,
+        pending: PendingRows,
+        summary: &mut CsvImportSummary,
+    ) -> Result<(), BaserowError> {
+        debug!(rows = pending.rows.len(), "Importing batch");
+        let result = self.create_many(pending.rows, Some(true)).await?;
+
+        for chunk in result.chunks {
+            summary.rows.extend(chunk.rows);
+            if let Some(error) = &chunk.error {
+                // The chunk failed as a whole, so its remaining records are reported as well
+                let rejected: Vec<usize> = chunk.row_errors.iter().map(|e| e.index).collect();
+                summary.errors.extend(
+                    chunk
+                        .range
+                        .clone()
+                        .filter(|position| !rejected.contains(position))
+                        .map(|position| RowError {
+                            index: pending.indexes[position],
+                            error: BaserowError::InvalidRequest(format!(
+                                "The batch failed - {}",
+                                error
+                            )),
+                        }),
+                );
+            }
+            summary
+                .errors
+                .extend(chunk.row_errors.into_iter().map(|error| RowError {
+                    index: pending.indexes[error.index],
+                    error: error.error,
+                }));
+        }
+        summary.errors.sort_by_key(|error| error.index);
+        Ok(())
+    }
+}
+
+/// Invokes the progress callback of a CSV import, if any
+#[cfg(feature = "csv")]
+fn report_progress(options: &CsvImportOptions, read: usize, summary: &CsvImportSummary) {
+    if let Some(callback) = &options.progress {
+        callback(&ImportProgress {
+            read,
+            created: summary.rows.len(),
+            failed: summary.errors.len(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Baserow, ConfigBuilder, TableId};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_export_and_import_jsonl() {
+        let mut server = mockito::Server::new_async().await;
+
+        let rows_mock = server
+            .mock("GET", "/api/database/rows/table/1234/")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "user_field_names".into(),
+                "true".into(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                json!({
+                    "count": 2,
+                    "next": null,
+                    "previous": null,
+                    "results": [
+                        {"id": 1, "order": "1.00000000000000000000", "Name": "Ada", "Status": {"id": 7, "value": "Open", "color": "blue"}, "Total": "3"},
+                        {"id": 2, "order": "2.00000000000000000000", "Name": "Grace", "Status": null, "Total": "5"}
+                    ]
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let fields_mock = server
+            .mock("GET", "/api/database/fields/table/5678/")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                json!([
+                    {"id": 1, "table_id": 5678, "name": "Name", "order": 0, "type": "text", "primary": true, "read_only": false},
+                    {"id": 2, "table_id": 5678, "name": "Status", "order": 1, "type": "single_select", "primary": false, "read_only": false},
+                    {"id": 3, "table_id": 5678, "name": "Total", "order": 2, "type": "formula", "primary": false, "read_only": true, "formula_type": "number"}
+                ])
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let create_mock = server
+            .mock("POST", "/api/database/rows/table/5678/batch/")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "user_field_names".into(),
+                "true".into(),
+            ))
+            .match_body(mockito::Matcher::Json(json!({"items": [
+                {"Name": "Ada", "Status": 7},
+                {"Name": "Grace", "Status": null}
+            ]})))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                json!({"items": [
+                    {"id": 11, "Name": "Ada", "Status": {"id": 7, "value": "Open", "color": "blue"}},
+                    {"id": 12, "Name": "Grace", "Status": null}
+                ]})
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let config = ConfigBuilder::new()
+            .base_url(&server.url())
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let baserow = Baserow::with_configuration(config);
+
+        let mut backup = Vec::new();
+        let rows = baserow
+            .table_by_id(TableId(1234))
+            .export_jsonl(&mut backup)
+            .await
+            .unwrap();
+        assert_eq!(rows, 2);
+        assert_eq!(backup.iter().filter(|&&b| b == b'\n').count(), 2);
+
+        let result = baserow
+            .table_by_id(TableId(5678))
+            .import_jsonl(backup.as_slice())
+            .await
+            .unwrap();
+        assert!(result.is_complete());
+        assert_eq!(result.rows().count(), 2);
+
+        rows_mock.assert_async().await;
+        fields_mock.assert_async().await;
+        create_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_import_jsonl_invalid_line() {
+        let mut server = mockito::Server::new_async().await;
+        let _fields_mock = server
+            .mock("GET", "/api/database/fields/table/1234/")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let config = ConfigBuilder::new()
+            .base_url(&server.url())
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let table = Baserow::with_configuration(config).table_by_id(TableId(1234));
+
+        let error = table
+            .import_jsonl("\n[1, 2]\n".as_bytes())
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(error, BaserowError::InvalidRequest(ref reason) if reason.starts_with("Line 2"))
+        );
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_coerce() {
+        let field = |r#type: &str| -> TableField {
+            serde_json::from_value(json!({
+                "id": 1, "table_id": 1, "name": "Field", "order": 0, "type": r#type,
+                "primary": false, "read_only": false,
+                "select_options": [{"id": 7, "value": "Open", "color": "blue"}, {"id": 8, "value": "Done", "color": "green"}]
+            }))
+            .unwrap()
+        };
+
+        assert_eq!(coerce(&field("text"), " Ada "), Ok(json!("Ada")));
+        assert_eq!(coerce(&field("text"), ""), Ok(Value::Null));
+        assert_eq!(coerce(&field("number"), "12.50"), Ok(json!("12.50")));
+        assert!(coerce(&field("number"), "twelve").is_err());
+        assert_eq!(coerce(&field("rating"), "4"), Ok(json!(4)));
+        assert_eq!(coerce(&field("boolean"), "Yes"), Ok(json!(true)));
+        assert_eq!(coerce(&field("boolean"), ""), Ok(json!(false)));
+        assert!(coerce(&field("boolean"), "maybe").is_err());
+        assert_eq!(coerce(&field("single_select"), "open"), Ok(json!(7)));
+        assert!(coerce(&field("single_select"), "Closed").is_err());
+        assert_eq!(
+            coerce(&field("multiple_select"), "Open, Done"),
+            Ok(json!([7, 8]))
+        );
+        assert_eq!(coerce(&field("multiple_select"), ""), Ok(json!([])));
+        assert_eq!(coerce(&field("link_row"), "3, Ada"), Ok(json!([3, "Ada"])));
+        assert_eq!(coerce(&field("duration"), "1:30"), Ok(json!(5400.0)));
+    }
+
+    #[cfg(feature = "csv")]
+    #[tokio::test]
+    async fn test_import_csv() {
+        use std::sync::Mutex;
+
+        let mut server = mockito::Server::new_async().await;
+        let fields_mock = server
+            .mock("GET", "/api/database/fields/table/1234/")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                json!([
+                    {"id": 1, "table_id": 1234, "name": "Name", "order": 0, "type": "text", "primary": true, "read_only": false},
+                    {"id": 2, "table_id": 1234, "name": "Price", "order": 1, "type": "number", "primary": false, "read_only": false},
+                    {"id": 3, "table_id": 1234, "name": "Total", "order": 2, "type": "formula", "primary": false, "read_only": true}
+                ])
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let create_mock = server
+            .mock("POST", "/api/database/rows/table/1234/batch/")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "user_field_names".into(),
+                "true".into(),
+            ))
+            .match_body(mockito::Matcher::Json(json!({"items": [
+                {"Name": "Ada", "Price": "12.50"},
+                {"Name": "Grace", "Price": null}
+            ]})))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                json!({"items": [
+                    {"id": 11, "Name": "Ada", "Price": "12.50"},
+                    {"id": 12, "Name": "Grace", "Price": null}
+                ]})
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let config = ConfigBuilder::new()
+            .base_url(&server.url())
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let table = Baserow::with_configuration(config).table_by_id(TableId(1234));
+
+        let progress = Arc::new(Mutex::new(Vec::new()));
+        let reported = progress.clone();
+        let options = CsvImportOptions::new()
+            .column("Cost", "Price")
+            .on_progress(move |progress| reported.lock().unwrap().push(*progress));
+        let csv = "id,Name,Cost,Total\n1,Ada,12.50,x\n2,Alan,twelve,x\n3,Grace,,x\n";
+        let summary = table.import_csv(csv.as_bytes(), options).await.unwrap();
+
+        assert_eq!(summary.rows.len(), 2);
+        assert_eq!(summary.errors.len(), 1);
+        assert_eq!(summary.errors[0].index, 1);
+        assert!(matches!(
+            summary.errors[0].error,
+            BaserowError::InvalidFieldValues(ref fields) if fields.contains_key("Price")
+        ));
+        assert_eq!(
+            *progress.lock().unwrap(),
+            vec![ImportProgress {
+                read: 3,
+                created: 2,
+                failed: 1
+            }]
+        );
+
+        let error = table
+            .import_csv("Name,Unknown\n".as_bytes(), CsvImportOptions::new())
+            .await
+            .unwrap_err();
+        assert!(matches!(error, BaserowError::InvalidRequest(_)));
+
+        fields_mock.expect(2).assert_async().await;
+        create_mock.assert_async().await;
+    }
+}
@@ -3,7 +3,48 @@ use serde_json::Value;
 use std::collections::HashMap;
 use tracing::{debug, instrument, warn};
 
-use crate::TableField;
+use crate::{
+    error::ValidationError, ids::FieldId, validation::validate_value, value::BaserowFieldValue,
+    TableField,
+};
+
+/// The type of fields holding uploaded files
+const FILE_FIELD_TYPE: &str = "file";
+/// The types of fields holding select options
+const SINGLE_SELECT_FIELD_TYPE: &str = "single_select";
+const MULTIPLE_SELECT_FIELD_TYPE: &str = "multiple_select";
+/// The type of fields linking to rows of another table
+const LINK_ROW_FIELD_TYPE: &str = "link_row";
+/// The type of fields referencing users
+const MULTIPLE_COLLABORATORS_FIELD_TYPE: &str = "multiple_collaborators";
+/// The types of fields whose values are computed by Baserow
+const FORMULA_FIELD_TYPES: &[&str] = &["formula", "lookup", "rollup", "count"];
+
+/// Removes all but the given keys from the objects in a list
+fn retain_keys(items: Vec<Value>, keys: &[&str]) -> Value {
+    Value::Array(
+        items
+            .into_iter()
+            .map(|item| match item {
+                Value::Object(mut item) => {
+                    item.retain(|key, _| keys.contains(&key.as_str()));
+                    Value::Object(item)
+                }
+                item => item,
+            })
+            .collect(),
+    )
+}
+
+/// Reduces a select option or linked row object, as returned when reading a row, to its ID
+fn id_ref(item: Value) -> Value {
+    match item {
+        Value::Object(mut item) if item.contains_key("id") => {
+            item.remove("id").unwrap_or(Value::Null)
+        }
+        item => item,
+    }
+}
 
 /// A trait for mapping between Baserow field IDs and their human-readable names
 ///
@@ -23,8 +64,8 @@ pub trait FieldMapper: Clone {
     /// * `name` - The human-readable field name
     ///
     /// # Returns
-    /// * `Option<u64>` - The field ID if found, None otherwise
-    fn get_field_id(&self, name: &str) -> Option<u64>;
+    /// * `Option<FieldId>` - The field ID if found, None otherwise
+    fn get_field_id(&self, name: &str) -> Option<FieldId>;
 
     /// Gets the field name corresponding to a field ID
     ///
@@ -33,7 +74,7 @@ pub trait FieldMapper: Clone {
     ///
     /// # Returns
     /// * `Option<String>` - The field name if found, None otherwise
-    fn get_field_name(&self, id: u64) -> Option<String>;
+    fn get_field_name(&self, id: FieldId) -> Option<String>;
 
     /// Gets all mapped fields
     ///
@@ -49,8 +90,8 @@ pub trait FieldMapper: Clone {
 #[derive(Clone, Default)]
 pub struct TableMapper {
     fields: Vec<TableField>,
-    ids_to_names: HashMap<u64, String>,
-    names_to_ids: HashMap<String, u64>,
+    ids_to_names: HashMap<FieldId, String>,
+    names_to_ids: HashMap<String, FieldId>,
 }
 
 impl TableMapper {
@@ -76,8 +117,67 @@ impl TableMapper {
     {
         // First convert field IDs to names
         let converted = self.convert_to_field_names(row);
-        // Then deserialize
-        serde_json::from_value(serde_json::to_value(converted)?)
+        // Then deserialize, moving the values instead of copying them into a new tree
+        serde_json::from_value(Value::Object(converted.into_iter().collect()))
+    }
+
+    /// Returns the mapped fields
+    pub fn fields(&self) -> &[TableField] {
+        &self.fields
+    }
+
+    /// Returns the type of a mapped field, e.g. `"text"` or `"file"`
+    pub fn get_field_type(&self, id: FieldId) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|field| field.id == id)
+            .map(|field| field.r#type.as_str())
+    }
+
+    /// Returns whether a mapped field can not be written to
+    ///
+    /// This is the case for fields Baserow reports as read-only and for fields
+    /// computed by Baserow: formula, lookup, rollup and count fields.
+    pub fn is_read_only(&self, id: FieldId) -> bool {
+        self.fields
+            .iter()
+            .find(|field| field.id == id)
+            .is_some_and(|field| {
+                field.read_only || FORMULA_FIELD_TYPES.contains(&field.r#type.as_str())
+            })
+    }
+
+    /// Normalizes a value read from a field so it deserializes into the field's Rust type
+    ///
+    /// Empty file fields may be returned as `null`, they are turned into an empty
+    /// list so they can be read as a `FileFieldValue`.
+    fn normalize_value(&self, field_id: FieldId, value: Value) -> Value {
+        match (self.get_field_type(field_id), value) {
+            (Some(FILE_FIELD_TYPE), Value::Null) => Value::Array(Vec::new()),
+            (_, value) => value,
+        }
+    }
+
+    /// Prepares a value to be written to a field
+    ///
+    /// Files in file fields are reduced to the `name` and `visible_name` Baserow
+    /// expects, select options and linked rows to their ID and collaborators to
+    /// an object with their ID, so values read from a row can be written back as
+    /// they are.
+    fn prepare_value(&self, field_id: FieldId, value: Value) -> Value {
+        match (self.get_field_type(field_id), value) {
+            (Some(SINGLE_SELECT_FIELD_TYPE), option) => id_ref(option),
+            (Some(MULTIPLE_SELECT_FIELD_TYPE | LINK_ROW_FIELD_TYPE), Value::Array(items)) => {
+                Value::Array(items.into_iter().map(id_ref).collect())
+            }
+            (Some(FILE_FIELD_TYPE), Value::Array(files)) => {
+                retain_keys(files, &["name", "visible_name"])
+            }
+            (Some(MULTIPLE_COLLABORATORS_FIELD_TYPE), Value::Array(collaborators)) => {
+                retain_keys(collaborators, &["id"])
+            }
+            (_, value) => value,
+        }
     }
 
     /// Converts field IDs to field names in a row
@@ -92,24 +192,24 @@ impl TableMapper {
         let mut converted = HashMap::new();
         for (key, value) in row {
             // Try to parse as a raw field ID first
-            if let Ok(field_id) = key.parse::<u64>() {
+            if let Ok(field_id) = key.parse::<FieldId>() {
                 if let Some(name) = self.get_field_name(field_id) {
-                    debug!(field_id = field_id, field_name = ?name, "Converted raw field ID to name");
-                    converted.insert(name, value);
+                    debug!(field_id = %field_id, field_name = ?name, "Converted raw field ID to name");
+                    converted.insert(name, self.normalize_value(field_id, value));
                     continue;
                 }
             }
             // Then try with field_ prefix
             if let Some(field_id) = key
                 .strip_prefix("field_")
-                .and_then(|id| id.parse::<u64>().ok())
+                .and_then(|id| id.parse::<FieldId>().ok())
             {
                 if let Some(name) = self.get_field_name(field_id) {
-                    debug!(field_id = field_id, field_name = ?name, "Converted prefixed field ID to name");
-                    converted.insert(name, value);
+                    debug!(field_id = %field_id, field_name = ?name, "Converted prefixed field ID to name");
+                    converted.insert(name, self.normalize_value(field_id, value));
                     continue;
                 }
-                warn!(field_id = field_id, "No name mapping found for field ID");
+                warn!(field_id = %field_id, "No name mapping found for field ID");
             }
             debug!(key = ?key, "Keeping original key");
             converted.insert(key, value);
@@ -121,8 +221,84 @@ impl TableMapper {
         converted
     }
 
+    /// Converts a row into typed values keyed by field name
+    ///
+    /// The field types of the mapped fields determine the typed representation.
+    /// Columns without a mapped field, like `id` and `order`, are inferred from
+    /// their value.
+    ///
+    /// # Arguments
+    /// * `row` - The row data with field IDs or names as keys
+    ///
+    /// # Returns
+    /// * HashMap with field names as keys and typed values
+    #[instrument(skip(self, row), fields(row_keys = ?row.keys().collect::<Vec<_>>()), err)]
+    pub fn typed_values(
+        &self,
+        row: HashMap<String, Value>,
+    ) -> Result<HashMap<String, BaserowFieldValue>, serde_json::Error> {
+        self.convert_to_field_names(row)
+            .into_iter()
+            .map(|(name, value)| {
+                let field = self
+                    .names_to_ids
+                    .get(&name)
+                    .and_then(|id| self.fields.iter().find(|field| field.id == *id));
+                let value = match field {
+                    Some(field) => BaserowFieldValue::from_field(field, value)?,
+                    None => BaserowFieldValue::from_field_type("", value)?,
+                };
+                Ok((name, value))
+            })
+            .collect()
+    }
+
+    /// Validates the values of a row before it is written to the table
+    ///
+    /// Each value keyed by the name or ID of a mapped field is checked against
+    /// the field's schema: select options must exist, numbers must respect
+    /// the allowed sign and decimal places, dates must parse, and so on.
+    /// Read-only fields are skipped, as they are left out when writing.
+    ///
+    /// # Arguments
+    /// * `row` - The row data with field names or IDs as keys
+    ///
+    /// # Returns
+    /// * `Result<(), ValidationError>` - All invalid fields, if any
+    #[instrument(skip(self, row), fields(row_keys = ?row.keys().collect::<Vec<_>>()), err)]
+    pub fn validate_row(&self, row: &HashMap<String, Value>) -> Result<(), ValidationError> {
+        let errors: Vec<_> = row
+            .iter()
+            .filter_map(|(key, value)| {
+                let id = self.names_to_ids.get(key).copied().or_else(|| {
+                    key.strip_prefix("field_")
+                        .unwrap_or(key)
+                        .parse::<FieldId>()
+                        .ok()
+                })?;
+                let field = self.fields.iter().find(|field| field.id == id)?;
+                if self.is_read_only(id) {
+                    return None;
+                }
+                validate_value(field, value).err()
+            })
+            .collect();
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => {
+                let error = ValidationError { errors };
+                error.log();
+                Err(error)
+            }
+        }
+    }
+
     /// Converts field names to field IDs in a row
     ///
+    /// Read-only fields are left out, so rows read from a table can be written
+    /// back even if they contain formula or lookup values.
+    ///
     /// # Arguments
     /// * `row` - The row data with field names as keys
     ///
@@ -133,9 +309,13 @@ impl TableMapper {
         let mut converted = HashMap::new();
         for (key, value) in row {
             if let Some(id) = self.get_field_id(&key) {
+                if self.is_read_only(id) {
+                    debug!(field_name = ?key, field_id = %id, "Skipping read-only field");
+                    continue;
+                }
                 let field_key = format!("field_{}", id);
-                debug!(field_name = ?key, field_id = id, "Converted field name to ID");
-                converted.insert(field_key, value);
+                debug!(field_name = ?key, field_id = %id, "Converted field name to ID");
+                converted.insert(field_key, self.prepare_value(id, value));
                 continue;
             }
             debug!(key = ?key, "Keeping original key");
@@ -158,7 +338,7 @@ impl FieldMapper for TableMapper {
 
         // Add new mappings
         fields.iter().for_each(|field| {
-            debug!(field_id = field.id, field_name = ?field.name, "Mapping field");
+            debug!(field_id = %field.id, field_name = ?field.name, "Mapping field");
             self.ids_to_names.insert(field.id, field.name.clone());
             self.names_to_ids.insert(field.name.clone(), field.id);
         });
@@ -167,7 +347,7 @@ impl FieldMapper for TableMapper {
     }
 
     #[instrument(skip(self))]
-    fn get_field_id(&self, name: &str) -> Option<u64> {
+    fn get_field_id(&self, name: &str) -> Option<FieldId> {
         let id = self.names_to_ids.get(name).copied();
         if id.is_none() {
             warn!(field_name = ?name, "Field name not found in mapping");
@@ -176,10 +356,10 @@ impl FieldMapper for TableMapper {
     }
 
     #[instrument(skip(self))]
-    fn get_field_name(&self, id: u64) -> Option<String> {
+    fn get_field_name(&self, id: FieldId) -> Option<String> {
         let name = self.ids_to_names.get(&id).cloned();
         if name.is_none() {
-            warn!(field_id = id, "Field ID not found in mapping");
+            warn!(field_id = %id, "Field ID not found in mapping");
         }
         name
     }
@@ -194,16 +374,11 @@ mod tests {
     use super::*;
 
     fn create_test_field(id: u64, name: &str) -> TableField {
-        TableField {
-            id,
-            table_id: 1,
-            name: name.to_string(),
-            order: 0,
-            r#type: "text".to_string(),
-            primary: false,
-            read_only: false,
-            description: None,
-        }
+        create_typed_test_field(id, name, "text")
+    }
+
+    fn create_typed_test_field(id: u64, name: &str, r#type: &str) -> TableField {
+        TableField::new(name, id, r#type)
     }
 
     #[test]
@@ -221,15 +396,15 @@ mod tests {
         assert_eq!(mapper.get_fields().len(), 3);
 
         // Test ID to name mapping
-        assert_eq!(mapper.get_field_name(1), Some("Name".to_string()));
-        assert_eq!(mapper.get_field_name(2), Some("Email".to_string()));
-        assert_eq!(mapper.get_field_name(3), Some("Age".to_string()));
-        assert_eq!(mapper.get_field_name(4), None);
+        assert_eq!(mapper.get_field_name(FieldId(1)), Some("Name".to_string()));
+        assert_eq!(mapper.get_field_name(FieldId(2)), Some("Email".to_string()));
+        assert_eq!(mapper.get_field_name(FieldId(3)), Some("Age".to_string()));
+        assert_eq!(mapper.get_field_name(FieldId(4)), None);
 
         // Test name to ID mapping
-        assert_eq!(mapper.get_field_id("Name"), Some(1));
-        assert_eq!(mapper.get_field_id("Email"), Some(2));
-        assert_eq!(mapper.get_field_id("Age"), Some(3));
+        assert_eq!(mapper.get_field_id("Name"), Some(FieldId(1)));
+        assert_eq!(mapper.get_field_id("Email"), Some(FieldId(2)));
+        assert_eq!(mapper.get_field_id("Age"), Some(FieldId(3)));
         assert_eq!(mapper.get_field_id("Unknown"), None);
     }
 
@@ -250,12 +425,157 @@ mod tests {
         mapper.map_fields(updated_fields);
 
         // Verify updated mappings
-        assert_eq!(mapper.get_field_name(1), Some("FullName".to_string()));
-        assert_eq!(mapper.get_field_id("FullName"), Some(1));
-        assert_eq!(mapper.get_field_name(3), Some("Phone".to_string()));
-        assert_eq!(mapper.get_field_id("Phone"), Some(3));
+        assert_eq!(
+            mapper.get_field_name(FieldId(1)),
+            Some("FullName".to_string())
+        );
+        assert_eq!(mapper.get_field_id("FullName"), Some(FieldId(1)));
+        assert_eq!(mapper.get_field_name(FieldId(3)), Some("Phone".to_string()));
+        assert_eq!(mapper.get_field_id("Phone"), Some(FieldId(3)));
 
         // Old name should no longer exist
         assert_eq!(mapper.get_field_id("Name"), None);
     }
+
+    #[test]
+    fn test_file_fields() {
+        use crate::api::file::FileFieldValue;
+        use serde::Deserialize;
+        use serde_json::json;
+
+        #[derive(Deserialize)]
+        struct Document {
+            #[serde(rename = "Attachments")]
+            attachments: FileFieldValue,
+        }
+
+        let mut mapper = TableMapper::new();
+        mapper.map_fields(vec![create_typed_test_field(1, "Attachments", "file")]);
+        assert_eq!(mapper.get_field_type(FieldId(1)), Some("file"));
+
+        let row = HashMap::from([("field_1".to_string(), Value::Null)]);
+        let document: Document = mapper.deserialize_row(row).unwrap();
+        assert!(document.attachments.is_empty());
+
+        let row = HashMap::from([(
+            "Attachments".to_string(),
+            json!([{
+                "url": "https://example.com/media/user_files/abc_report.pdf",
+                "name": "abc_report.pdf",
+                "visible_name": "report.pdf",
+                "size": 2048
+            }]),
+        )]);
+        let converted = mapper.convert_to_field_ids(row);
+        assert_eq!(
+            converted["field_1"],
+            json!([{"name": "abc_report.pdf", "visible_name": "report.pdf"}])
+        );
+    }
+
+    #[test]
+    fn test_typed_values() {
+        use serde_json::json;
+
+        let mut mapper = TableMapper::new();
+        mapper.map_fields(vec![
+            create_typed_test_field(1, "Name", "text"),
+            create_typed_test_field(2, "Price", "number"),
+        ]);
+
+        let row = HashMap::from([
+            ("id".to_string(), json!(1)),
+            ("field_1".to_string(), json!("Widget")),
+            ("field_2".to_string(), json!("9.99")),
+        ]);
+        let values = mapper.typed_values(row).unwrap();
+
+        assert_eq!(values["id"], BaserowFieldValue::Number(1.0));
+        assert_eq!(
+            values["Name"],
+            BaserowFieldValue::Text("Widget".to_string())
+        );
+        assert_eq!(values["Price"], BaserowFieldValue::Number(9.99));
+    }
+
+    #[test]
+    fn test_formula_fields() {
+        use serde_json::json;
+
+        let mut total = create_typed_test_field(2, "Total", "formula");
+        total.formula_type = Some("number".to_string());
+        let mut names = create_typed_test_field(3, "Project names", "lookup");
+        names.formula_type = Some("array".to_string());
+        names.array_formula_type = Some("text".to_string());
+
+        let mut mapper = TableMapper::new();
+        mapper.map_fields(vec![
+            create_typed_test_field(1, "Name", "text"),
+            total,
+            names,
+        ]);
+
+        assert!(!mapper.is_read_only(FieldId(1)));
+        assert!(mapper.is_read_only(FieldId(2)));
+        assert!(mapper.is_read_only(FieldId(3)));
+
+        let row = HashMap::from([
+            ("field_1".to_string(), json!("Widget")),
+            ("field_2".to_string(), json!("19.90")),
+            (
+                "field_3".to_string(),
+                json!([{"id": 1, "value": "Apollo"}, {"id": 2, "value": "Gemini"}]),
+            ),
+        ]);
+        let values = mapper.typed_values(row.clone()).unwrap();
+        assert_eq!(values["Total"], BaserowFieldValue::Number(19.9));
+        assert_eq!(
+            values["Project names"],
+            BaserowFieldValue::Array(vec![
+                BaserowFieldValue::Text("Apollo".to_string()),
+                BaserowFieldValue::Text("Gemini".to_string()),
+            ])
+        );
+
+        let written = mapper.convert_to_field_ids(mapper.convert_to_field_names(row));
+        assert_eq!(
+            written,
+            HashMap::from([("field_1".to_string(), json!("Widget"))])
+        );
+    }
+
+    #[test]
+    fn test_reference_fields() {
+        use serde_json::json;
+
+        let mut mapper = TableMapper::new();
+        mapper.map_fields(vec![
+            create_typed_test_field(1, "Status", "single_select"),
+            create_typed_test_field(2, "Tags", "multiple_select"),
+            create_typed_test_field(3, "Projects", "link_row"),
+            create_typed_test_field(4, "Assignees", "multiple_collaborators"),
+        ]);
+
+        let row = HashMap::from([
+            (
+                "Status".to_string(),
+                json!({"id": 10, "value": "Open", "color": "blue"}),
+            ),
+            (
+                "Tags".to_string(),
+                json!([{"id": 20, "value": "A", "color": "red"}, "B", 22]),
+            ),
+            (
+                "Projects".to_string(),
+                json!([{"id": 30, "value": "Apollo"}, "Gemini"]),
+            ),
+            ("Assignees".to_string(), json!([{"id": 40, "name": "Ada"}])),
+        ]);
+        let converted = mapper.convert_to_field_ids(row);
+
+        assert_eq!(converted["field_1"], json!(10));
+        assert_eq!(converted["field_2"], json!([20, "B", 22]));
+        assert_eq!(converted["field_3"], json!([30, "Gemini"]));
+        assert_eq!(converted["field_4"], json!([{"id": 40}]));
+    }
 }
@@ -0,0 +1,54 @@
+//! The types and traits needed for most uses of the client
+//!
+//! # Example
+//! ```no_run
+//! use baserow_rs::prelude::*;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Task {
+//!     #[serde(rename = "Name")]
+//!     name: String,
+//!     #[serde(rename = "Status")]
+//!     status: Option<SelectOption>,
+//! }
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let config = ConfigBuilder::new()
+//!         .base_url("https://api.baserow.io")
+//!         .api_key("your-api-key")
+//!         .try_build()?;
+//!     let baserow = Baserow::with_configuration(config);
+//!
+//!     let tasks = baserow
+//!         .table_by_id(TableId(1234))
+//!         .auto_map()
+//!         .await?
+//!         .query()
+//!         .filter_by("Status", Filter::SingleSelectEqual, "Open")
+//!         .order_by("Name", OrderDirection::Asc)
+//!         .get::<Task>()
+//!         .await?;
+//!     for task in tasks.results {
+//!         println!("{}", task.name);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+pub use crate::{
+    api::{
+        client::BaserowClient,
+        file::{BaserowFileRef, FileFieldValue},
+        view::ViewSource,
+    },
+    error::BaserowError,
+    filter::{field, Filter, FilterTree, FilterType},
+    ids::{FieldId, RowId, TableId, ViewId},
+    value::{
+        BaserowFieldValue, Collaborator, LinkRowRef, LinkRowValue, SelectOption, SelectOptionRef,
+    },
+    Baserow, BaserowTableOperations, ConfigBuilder, FieldNamesMode, OrderDirection,
+    TypedTableOperations,
+};
@@ -0,0 +1,171 @@
+//! Named configuration profiles read from a TOML file
+//!
+//! Each top-level table of the file is a profile:
+//!
+//! ```toml
+//! [default]
+//! endpoint = "https://api.baserow.io"
+//! api_key = "your-api-key"
+//!
+//! [staging]
+//! endpoint = "https://baserow.staging.example.com"
+//! email = "user@example.com"
+//! password = "password"
+//! reauthenticate = true
+//! root_certificates = ["internal-ca.pem"]
+//! ```
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{error::ConfigError, secret::SecretString, ConfigBuilder};
+
+/// The profile used if none is named
+pub(crate) const DEFAULT_PROFILE: &str = "default";
+
+/// The settings of a profile, named like the `ConfigBuilder` methods
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Profile {
+    endpoint: String,
+    api_key: Option<SecretString>,
+    email: Option<String>,
+    password: Option<SecretString>,
+    access_token: Option<SecretString>,
+    refresh_token: Option<SecretString>,
+    #[serde(default)]
+    reauthenticate: bool,
+    #[serde(default)]
+    rate_limit_retries: u32,
+    proxy: Option<SecretString>,
+    no_proxy: Option<String>,
+    /// PEM files with certificates to trust, relative to the configuration file
+    #[serde(default)]
+    root_certificates: Vec<PathBuf>,
+}
+
+/// Reads a profile from a configuration file into a builder
+pub(crate) fn load(path: &Path, name: &str) -> Result<ConfigBuilder, ConfigError> {
+    let read_error = |source| ConfigError::ReadFile {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    let content = std::fs::read_to_string(path).map_err(read_error)?;
+    let mut profiles: HashMap<String, Profile> =
+        toml::from_str(&content).map_err(|e| ConfigError::ParseFile {
+            path: path.to_path_buf(),
+            reason: e.message().to_string(),
+        })?;
+    let profile = profiles
+        .remove(name)
+        .ok_or_else(|| ConfigError::MissingProfile(name.to_string()))?;
+
+    let mut builder = ConfigBuilder::new()
+        .base_url(&profile.endpoint)
+        .reauthenticate(profile.reauthenticate)
+        .retry_rate_limited(profile.rate_limit_retries);
+    if let Some(api_key) = &profile.api_key {
+        builder = builder.api_key(api_key.expose_secret());
+    }
+    if let Some(email) = &profile.email {
+        builder = builder.email(email);
+    }
+    if let Some(password) = &profile.password {
+        builder = builder.password(password.expose_secret());
+    }
+    if let Some(access_token) = &profile.access_token {
+        builder = builder.access_token(access_token.expose_secret());
+    }
+    if let Some(refresh_token) = &profile.refresh_token {
+        builder = builder.refresh_token(refresh_token.expose_secret());
+    }
+    if let Some(proxy) = &profile.proxy {
+        builder = builder.proxy(proxy.expose_secret());
+    }
+    if let Some(no_proxy) = &profile.no_proxy {
+        builder = builder.no_proxy(no_proxy);
+    }
+    for certificate in &profile.root_certificates {
+        let certificate = path.parent().unwrap_or(Path::new(".")).join(certificate);
+        let pem = std::fs::read(&certificate).map_err(|source| ConfigError::ReadFile {
+            path: certificate,
+            source,
+        })?;
+        builder = builder.add_root_certificate(&pem);
+    }
+
+    Ok(builder)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_profiles() {
+        let dir = std::env::temp_dir().join(format!("baserow-rs-profile-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("baserow.toml");
+        std::fs::write(
+            &path,
+            r#"
+[default]
+endpoint = "https://api.baserow.io"
+api_key = "123"
+
+[staging]
+endpoint = "https://baserow.staging.example.com"
+email = "user@example.com"
+password = "password"
+reauthenticate = true
+rate_limit_retries = 2
+"#,
+        )
+        .unwrap();
+
+        let configuration = load(&path, DEFAULT_PROFILE).unwrap().try_build().unwrap();
+        assert_eq!(configuration.base_url, "https://api.baserow.io");
+        assert_eq!(
+            configuration
+                .database_token
+                .as_ref()
+                .map(SecretString::expose_secret),
+            Some("123")
+        );
+
+        let configuration = load(&path, "staging").unwrap().try_build().unwrap();
+        assert_eq!(
+            configuration.base_url,
+            "https://baserow.staging.example.com"
+        );
+        assert_eq!(configuration.email.as_deref(), Some("user@example.com"));
+        assert!(configuration.reauthenticate);
+        assert_eq!(configuration.rate_limit_retries, 2);
+
+        assert!(matches!(
+            load(&path, "production"),
+            Err(ConfigError::MissingProfile(name)) if name == "production"
+        ));
+        assert!(matches!(
+            load(&dir.join("missing.toml"), DEFAULT_PROFILE),
+            Err(ConfigError::ReadFile { .. })
+        ));
+
+        std::fs::write(
+            &path,
+            "[default]\nendpoint = \"https://api.baserow.io\"\ntoken = \"123\"\n",
+        )
+        .unwrap();
+        assert!(matches!(
+            load(&path, DEFAULT_PROFILE),
+            Err(ConfigError::ParseFile { .. })
+        ));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,186 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::Method;
+
+/// The failures a [`RetryPolicy`] can retry
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RetryOn {
+    /// Responses with a 5xx status code
+    ServerErrors,
+    /// Responses with a 429 status code
+    RateLimited,
+    /// Requests that failed to connect or timed out
+    ConnectionErrors,
+}
+
+/// Retry behaviour for failed requests
+///
+/// Only idempotent requests (`GET`, `HEAD`, `OPTIONS`, `PUT` and `DELETE`)
+/// are retried, so rows are never created twice. The delay doubles with
+/// every attempt, starting at the base delay and capped at the maximum
+/// delay. Rate limited requests wait as long as the `Retry-After` header
+/// asks for instead, unless that exceeds the maximum delay: then they are not
+/// retried and fail with `BaserowError::RateLimited`.
+///
+/// # Example
+/// ```
+/// use baserow_rs::{retry::{RetryOn, RetryPolicy}, ConfigBuilder};
+/// use std::time::Duration;
+///
+/// let config = ConfigBuilder::new()
+///     .base_url("https://api.baserow.io")
+///     .api_key("your-api-key")
+///     .retry_policy(
+///         RetryPolicy::new()
+///             .max_attempts(5)
+///             .base_delay(Duration::from_millis(200))
+///             .retry_on(&[RetryOn::ServerErrors, RetryOn::ConnectionErrors]),
+///     )
+///     .try_build()
+///     .expect("invalid configuration");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    retry_on: Vec<RetryOn>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            retry_on: vec![
+                RetryOn::ServerErrors,
+                RetryOn::RateLimited,
+                RetryOn::ConnectionErrors,
+            ],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a policy making up to 3 attempts, retrying server errors, rate
+    /// limiting and connection errors with a base delay of 500ms
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the total number of attempts, including the first one
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Set the delay before the first retry
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Set the upper bound of the delay between attempts
+    ///
+    /// Rate limited requests asking for a longer `Retry-After` are not retried.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Set whether delays are randomized, so concurrent clients do not retry in lockstep
+    pub fn jitter(mut self, enabled: bool) -> Self {
+        self.jitter = enabled;
+        self
+    }
+
+    /// Set the failures that are retried
+    pub fn retry_on(mut self, retry_on: &[RetryOn]) -> Self {
+        self.retry_on = retry_on.to_vec();
+        self
+    }
+
+    /// Returns whether another attempt is allowed after the given number of retries
+    pub(crate) fn allows_retry(&self, retries: u32) -> bool {
+        retries + 1 < self.max_attempts
+    }
+
+    /// Returns the longest delay waited for before a retry
+    pub(crate) fn delay_limit(&self) -> Duration {
+        self.max_delay
+    }
+
+    /// Returns whether the given failure is retried
+    pub(crate) fn retries(&self, failure: RetryOn) -> bool {
+        self.retry_on.contains(&failure)
+    }
+
+    /// Returns the delay before the retry following the given number of retries
+    pub(crate) fn delay(&self, retries: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(retries))
+            .min(self.max_delay);
+        match self.jitter {
+            // Wait between half and the full delay
+            true => delay / 2 + delay.mul_f64(random_fraction() / 2.0),
+            false => delay,
+        }
+    }
+}
+
+/// Returns whether repeating a request with this method has no additional effect
+pub(crate) fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE
+    )
+}
+
+/// A number between 0 and 1 that is random enough to spread out retries
+fn random_fraction() -> f64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .subsec_nanos();
+    f64::from(nanos % 1_000) / 1_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay() {
+        let policy = RetryPolicy::new()
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(300))
+            .jitter(false);
+        assert_eq!(policy.delay(0), Duration::from_millis(100));
+        assert_eq!(policy.delay(1), Duration::from_millis(200));
+        assert_eq!(policy.delay(2), Duration::from_millis(300));
+        assert_eq!(policy.delay(40), Duration::from_millis(300));
+
+        let policy = policy.jitter(true);
+        for retries in 0..3 {
+            let delay = policy.delay(retries);
+            let full = policy.clone().jitter(false).delay(retries);
+            assert!(delay >= full / 2 && delay <= full);
+        }
+    }
+
+    #[test]
+    fn test_attempts() {
+        let policy = RetryPolicy::new().max_attempts(2);
+        assert!(policy.allows_retry(0));
+        assert!(!policy.allows_retry(1));
+        assert!(!RetryPolicy::new().max_attempts(0).allows_retry(0));
+
+        assert!(is_idempotent(&Method::GET));
+        assert!(!is_idempotent(&Method::POST));
+        assert!(!is_idempotent(&Method::PATCH));
+    }
+}
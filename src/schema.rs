@@ -0,0 +1,270 @@
+//! Idempotent provisioning of table schemas
+//!
+//! [`BaserowTable::ensure_schema`] compares the desired fields of a table to
+//! its live fields, creating the missing ones and updating the differing
+//! ones. Running it again without changes to the schema sends no writes, so
+//! it can run on every deployment.
+//!
+//! Requires the `schema` feature.
+//!
+//! # Example
+//! ```no_run
+//! use baserow_rs::{api::{client::BaserowClient, field::FieldSpec}, Baserow, ConfigBuilder, TableId};
+//! use serde_json::json;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let config = ConfigBuilder::new()
+//!         .base_url("https://api.baserow.io")
+//!         .email("user@example.com")
+//!         .password("password")
+//!         .try_build()
+//!         .expect("invalid configuration");
+//!     let client = Baserow::with_configuration(config).token_auth().await.unwrap();
+//!
+//!     let schema = vec![
+//!         FieldSpec::new("Email", "email"),
+//!         FieldSpec::new("Price", "number").option("number_decimal_places", 2),
+//!         FieldSpec::new("Status", "single_select").option(
+//!             "select_options",
+//!             json!([{"value": "Open", "color": "blue"}, {"value": "Done", "color": "green"}]),
+//!         ),
+//!     ];
+//!     let changes = client.table_by_id(TableId(1234)).ensure_schema(&schema).await.unwrap();
+//!     println!("{} created, {} updated", changes.created.len(), changes.updated.len());
+//! }
+//! ```
+
+use reqwest::Method;
+use serde_json::Value;
+use tracing::{debug, info, instrument};
+
+use crate::{
+    api::{
+        client::BaserowClient,
+        field::{BaserowFieldOperations, FieldSpec},
+    },
+    error::BaserowError,
+    ids::FieldId,
+    BaserowTable, TableField,
+};
+
+/// The changes `ensure_schema` made to a table
+#[derive(Clone, Debug)]
+pub struct SchemaChanges {
+    /// The fields that were missing, as created
+    pub created: Vec<TableField>,
+    /// The fields that differed from their definition, as updated
+    pub updated: Vec<TableField>,
+    /// The number of fields that already matched their definition
+    pub unchanged: usize,
+}
+
+impl SchemaChanges {
+    /// Returns whether the table already matched the schema
+    pub fn is_empty(&self) -> bool {
+        self.created.is_empty() && self.updated.is_empty()
+    }
+}
+
+/// Returns whether a live value matches the desired one
+///
+/// Objects match if all desired keys match, so values Baserow adds, like
+/// the IDs of select options, are ignored.
+fn matches(desired: &Value, live: &Value) -> bool {
+    match (desired, live) {
+        (Value::Object(desired), Value::Object(live)) => desired
+            .iter()
+            .all(|(key, value)| live.get(key).is_some_and(|live| matches(value, live))),
+        (Value::Array(desired), Value::Array(live)) => {
+            desired.len() == live.len()
+                && desired
+                    .iter()
+                    .zip(live)
+                    .all(|(desired, live)| matches(desired, live))
+        }
+        (Value::Number(desired), Value::Number(live)) => desired.as_f64() == live.as_f64(),
+        _ => desired == live,
+    }
+}
+
+/// Adds the IDs of live select options to the desired options with the same value
+///
+/// Select options sent without ID are created anew, which would clear the
+/// cells referring to the existing ones.
+fn with_option_ids(mut field: FieldSpec, live: &Value) -> FieldSpec {
+    let live_options = live["select_options"].as_array();
+    if let (Some(Value::Array(options)), Some(live_options)) =
+        (field.options.get_mut("select_options"), live_options)
+    {
+        for option in options.iter_mut().filter_map(Value::as_object_mut) {
+            if option.contains_key("id") {
+                continue;
+            }
+            let id = live_options
+                .iter()
+                .find(|live| live.get("value") == option.get("value"))
+                .and_then(|live| live.get("id"));
+            if let Some(id) = id {
+                option.insert("id".to_string(), id.clone());
+            }
+        }
+    }
+    field
+}
+
+impl BaserowTable {
+    /// Creates the missing fields of the schema and updates the differing ones
+    ///
+    /// Fields are matched by name. A field differs if its type or any of the
+    /// given options differ, options not given are left as they are. Fields
+    /// of the table missing from the schema are kept.
+    ///
+    /// Changing fields requires a client authenticated via `token_auth()`.
+    /// Mapped tables don't know the changed fields until `auto_map()` is
+    /// called again.
+    #[instrument(skip(self, fields), fields(table_id = ?self.id, field_count = fields.len()), err)]
+    pub async fn ensure_schema(&self, fields: &[FieldSpec]) -> Result<SchemaChanges, BaserowError> {
+        let table_id = self.id.ok_or(BaserowError::MissingState("Table ID"))?;
+        let baserow = self
+            .baserow
+            .as_ref()
+            .ok_or(BaserowError::MissingState("Baserow instance"))?;
+
+        // The fields are compared as returned, including the options not
+        // modeled by `TableField`
+        debug!("Fetching live fields");
+        let request = baserow
+            .request(
+                Method::GET,
+                &format!("api/database/fields/table/{}/", table_id),
+            )
+            .await;
+        let live: Vec<Value> = baserow.send_request(request).await?.json().await?;
+
+        let mut changes = SchemaChanges {
+            created: Vec::new(),
+            updated: Vec::new(),
+            unchanged: 0,
+        };
+        for field in fields {
+            let desired = serde_json::to_value(field)?;
+            match live.iter().find(|live| live["name"] == field.name.as_str()) {
+                None => {
+                    let created = baserow.create_field(table_id, field).await?;
+                    changes.created.push(created);
+                }
+                Some(live) if !matches(&desired, live) => {
+                    let id: FieldId = serde_json::from_value(live["id"].clone())?;
+                    let field = with_option_ids(field.clone(), live);
+                    let updated = baserow.update_field(id, &field).await?;
+                    changes.updated.push(updated);
+                }
+                Some(_) => changes.unchanged += 1,
+            }
+        }
+
+        info!(
+            created = changes.created.len(),
+            updated = changes.updated.len(),
+            unchanged = changes.unchanged,
+            "Ensured table schema"
+        );
+        Ok(changes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Baserow, ConfigBuilder, TableId};
+    use serde_json::json;
+
+    #[test]
+    fn test_matches() {
+        let live = json!({
+            "id": 3,
+            "name": "Status",
+            "type": "single_select",
+            "select_options": [{"id": 10, "value": "Open", "color": "blue"}]
+        });
+        let desired = FieldSpec::new("Status", "single_select").option(
+            "select_options",
+            json!([{"value": "Open", "color": "blue"}]),
+        );
+        assert!(matches(&serde_json::to_value(&desired).unwrap(), &live));
+
+        let desired = FieldSpec::new("Status", "single_select").option(
+            "select_options",
+            json!([{"value": "Open", "color": "blue"}, {"value": "Done", "color": "green"}]),
+        );
+        assert!(!matches(&serde_json::to_value(&desired).unwrap(), &live));
+        assert_eq!(
+            with_option_ids(desired, &live).options["select_options"],
+            json!([{"id": 10, "value": "Open", "color": "blue"}, {"value": "Done", "color": "green"}])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ensure_schema() {
+        let mut server = mockito::Server::new_async().await;
+
+        let fields_mock = server
+            .mock("GET", "/api/database/fields/table/1234/")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                json!([
+                    {"id": 1, "table_id": 1234, "name": "Email", "order": 0, "type": "email", "primary": true, "read_only": false},
+                    {"id": 2, "table_id": 1234, "name": "Price", "order": 1, "type": "number", "primary": false, "read_only": false, "number_decimal_places": 0}
+                ])
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let update_mock = server
+            .mock("PATCH", "/api/database/fields/2/")
+            .match_body(mockito::Matcher::Json(json!({
+                "name": "Price",
+                "type": "number",
+                "number_decimal_places": 2
+            })))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": 2, "table_id": 1234, "name": "Price", "order": 1, "type": "number", "primary": false, "read_only": false, "number_decimal_places": 2}"#)
+            .create_async()
+            .await;
+        let create_mock = server
+            .mock("POST", "/api/database/fields/table/1234/")
+            .match_body(mockito::Matcher::Json(
+                json!({"name": "Notes", "type": "long_text"}),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": 3, "table_id": 1234, "name": "Notes", "order": 2, "type": "long_text", "primary": false, "read_only": false}"#)
+            .create_async()
+            .await;
+
+        let config = ConfigBuilder::new()
+            .base_url(&server.url())
+            .access_token("jwt-token")
+            .try_build()
+            .unwrap();
+        let table = Baserow::with_configuration(config).table_by_id(TableId(1234));
+
+        let schema = vec![
+            FieldSpec::new("Email", "email"),
+            FieldSpec::new("Price", "number").option("number_decimal_places", 2),
+            FieldSpec::new("Notes", "long_text"),
+        ];
+        let changes = table.ensure_schema(&schema).await.unwrap();
+        assert_eq!(changes.created[0].id, FieldId(3));
+        assert_eq!(changes.updated[0].number_decimal_places, Some(2));
+        assert_eq!(changes.unchanged, 1);
+        assert!(!changes.is_empty());
+
+        fields_mock.assert_async().await;
+        update_mock.assert_async().await;
+        create_mock.assert_async().await;
+    }
+}
@@ -0,0 +1,173 @@
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use zeroize::Zeroize;
+
+/// Replaces the values of credentials in captured or recorded requests
+pub(crate) const REDACTED: &str = "[redacted]";
+
+/// Parts of the names of JSON fields whose values are never captured, e.g.
+/// `password`, `new_password` or `refresh_token`
+const SENSITIVE_FIELDS: &[&str] = &["password", "token", "secret"];
+
+/// A string holding a credential such as a password or a token
+///
+/// The value is redacted in `Debug` output, so secrets do not end up in logs
+/// or traces, and its memory is zeroed when it is dropped.
+///
+/// # Example
+/// ```
+/// use baserow_rs::secret::SecretString;
+///
+/// let token = SecretString::from("my-database-token");
+/// assert_eq!(format!("{:?}", token), "[REDACTED]");
+/// assert_eq!(token.expose_secret(), "my-database-token");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SecretString(String);
+
+impl SecretString {
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self(secret.into())
+    }
+
+    /// Returns the secret value
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+impl Drop for SecretString {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(secret: String) -> Self {
+        Self(secret)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(secret: &str) -> Self {
+        Self(secret.to_string())
+    }
+}
+
+impl Serialize for SecretString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for SecretString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self)
+    }
+}
+
+/// Bytes holding a credential such as a private key
+///
+/// Like [`SecretString`], redacted in `Debug` output and zeroed when dropped.
+#[derive(Clone)]
+pub(crate) struct SecretBytes(Vec<u8>);
+
+impl SecretBytes {
+    /// Returns the secret bytes
+    pub(crate) fn expose_secret(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl fmt::Debug for SecretBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+impl Drop for SecretBytes {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl From<&[u8]> for SecretBytes {
+    fn from(secret: &[u8]) -> Self {
+        Self(secret.to_vec())
+    }
+}
+
+/// Whether the value of a JSON field is a credential, judged by its name
+fn is_sensitive(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    SENSITIVE_FIELDS.iter().any(|part| key.contains(part))
+}
+
+/// Replaces the values of sensitive fields anywhere in a JSON document
+pub(crate) fn redact(value: &mut Value) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                match is_sensitive(key) {
+                    true => *value = Value::String(REDACTED.to_string()),
+                    false => redact(value),
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_redact() {
+        let mut body = json!({
+            "email": "a@example.com",
+            "password": "secret",
+            "user": {"token": "x", "refresh_token": "y", "Client_Secret": "z"},
+        });
+        redact(&mut body);
+        assert_eq!(
+            body,
+            json!({
+                "email": "a@example.com",
+                "password": REDACTED,
+                "user": {"token": REDACTED, "refresh_token": REDACTED, "Client_Secret": REDACTED},
+            })
+        );
+    }
+
+    #[test]
+    fn test_secret_is_redacted() {
+        let secret = SecretString::from("hunter2");
+        assert_eq!(format!("{:?}", secret), "[REDACTED]");
+        assert_eq!(format!("{:?}", Some(secret.clone())), "Some([REDACTED])");
+        assert_eq!(secret.expose_secret(), "hunter2");
+    }
+
+    #[test]
+    fn test_secret_bytes_are_redacted() {
+        let secret = SecretBytes::from(&b"private key"[..]);
+        assert_eq!(format!("{:?}", secret), "[REDACTED]");
+        assert_eq!(secret.expose_secret(), b"private key");
+    }
+
+    #[test]
+    fn test_secret_serializes_as_plain_string() {
+        let secret: SecretString = serde_json::from_str(r#""hunter2""#).unwrap();
+        assert_eq!(serde_json::to_string(&secret).unwrap(), r#""hunter2""#);
+    }
+}
@@ -0,0 +1,430 @@
+//! Synchronization of a local collection to a table
+//!
+//! [`BaserowTable::sync`] matches local items to the rows of a table by a key
+//! field, then creates the missing rows, updates the changed ones and deletes
+//! the rows without a local counterpart, all via the batch APIs.
+//!
+//! Requires the `sync` feature.
+//!
+//! # Example
+//! ```no_run
+//! use baserow_rs::{api::client::BaserowClient, Baserow, ConfigBuilder, TableId};
+//! use serde::Serialize;
+//!
+//! #[derive(Serialize)]
+//! struct Customer {
+//!     #[serde(rename = "Email")]
+//!     email: String,
+//!     #[serde(rename = "Name")]
+//!     name: String,
+//! }
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let config = ConfigBuilder::new()
+//!         .base_url("https://api.baserow.io")
+//!         .api_key("your-api-key")
+//!         .try_build()
+//!         .expect("invalid configuration");
+//!     let baserow = Baserow::with_configuration(config);
+//!
+//!     let customers = vec![Customer {
+//!         email: "jane@example.com".to_string(),
+//!         name: "Jane".to_string(),
+//!     }];
+//!     let summary = baserow
+//!         .table_by_id(TableId(1234))
+//!         .with_user_field_names(true)
+//!         .sync(&customers, "Email")
+//!         .await
+//!         .unwrap();
+//!     println!(
+//!         "{} created, {} updated, {} deleted, {} unchanged",
+//!         summary.created.rows().count(),
+//!         summary.updated.rows().count(),
+//!         summary.deleted.rows().count(),
+//!         summary.unchanged
+//!     );
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::Value;
+use tracing::{debug, info, instrument};
+
+use crate::{
+    api::{
+        batch::BatchResult,
+        table_operations::{row_id, RowRequest},
+    },
+    error::BaserowError,
+    ids::RowId,
+    BaserowTable, BaserowTableOperations,
+};
+
+/// Options of [`BaserowTable::sync_with_options`]
+///
+/// # Example
+/// ```
+/// use baserow_rs::sync::SyncOptions;
+///
+/// let options = SyncOptions::new().keep_missing();
+/// ```
+#[derive(Clone, Debug)]
+pub struct SyncOptions {
+    delete_missing: bool,
+}
+
+impl Default for SyncOptions {
+    fn default() -> Self {
+        Self {
+            delete_missing: true,
+        }
+    }
+}
+
+impl SyncOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep rows whose key has no local counterpart instead of deleting them
+    ///
+    /// Syncs then fail with `InvalidRequest` if rows share a key, as the
+    /// duplicates are not deleted either.
+    pub fn keep_missing(mut self) -> Self {
+        self.delete_missing = false;
+        self
+    }
+}
+
+/// The outcome of a sync, per kind of change
+#[derive(Debug)]
+pub struct SyncSummary {
+    /// The rows created for items without a matching row
+    pub created: BatchResult<HashMap<String, Value>>,
+    /// The rows updated because their items differed
+    pub updated: BatchResult<HashMap<String, Value>>,
+    /// The rows deleted because no item matched them
+    pub deleted: BatchResult<RowId>,
+    /// The number of rows that already matched their items
+    pub unchanged: usize,
+}
+
+impl SyncSummary {
+    /// Returns whether all changes were applied
+    pub fn is_complete(&self) -> bool {
+        self.created.is_complete() && self.updated.is_complete() && self.deleted.is_complete()
+    }
+}
+
+/// The changes needed to bring a table in line with the local items
+#[derive(Debug, Default, PartialEq)]
+struct SyncPlan {
+    creates: Vec<HashMap<String, Value>>,
+    updates: Vec<(RowId, HashMap<String, Value>)>,
+    deletes: Vec<RowId>,
+    unchanged: usize,
+}
+
+impl SyncPlan {
+    /// Matches the items to the rows by the value of their key field
+    ///
+    /// Items are only compared by the fields they contain, other fields of
+    /// the rows are left as they are. Rows without a key are never deleted.
+    /// Of rows sharing a key, the one with the lowest ID is matched and the
+    /// others are deleted, or rejected if missing rows are kept.
+    fn new(
+        items: Vec<HashMap<String, Value>>,
+        mut rows: Vec<HashMap<String, Value>>,
+        key: &str,
+        options: &SyncOptions,
+    ) -> Result<Self, BaserowError> {
+        let mut plan = SyncPlan::default();
+        let mut existing: HashMap<String, (RowId, HashMap<String, Value>)> = HashMap::new();
+        rows.sort_by_key(row_id);
+        for row in rows {
+            let (Some(id), Some(value)) = (row_id(&row), key_value(&row, key)) else {
+                continue;
+            };
+            if let Some((previous, _)) = existing.get(&value) {
+                if !options.delete_missing {
+                    return Err(BaserowError::InvalidRequest(format!(
+                        "Rows {} and {} have the same value for {}",
+                        previous, id, key
+                    )));
+                }
+                plan.deletes.push(id);
+                continue;
+            }
+            existing.insert(value, (id, row));
+        }
+
+        let mut seen = HashMap::with_capacity(items.len());
+        for (index, item) in items.into_iter().enumerate() {
+            let value = key_value(&item, key).ok_or_else(|| {
+                BaserowError::InvalidRequest(format!("Item {} has no value for {}", index, key))
+            })?;
+            if let Some(previous) = seen.insert(value.clone(), index) {
+                return Err(BaserowError::InvalidRequest(format!(
+                    "Items {} and {} have the same value for {}",
+                    previous, index, key
+                )));
+            }
+
+            match existing.remove(&value) {
+                None => plan.creates.push(item),
+                Some((id, row)) if differs(&item, &row) => plan.updates.push((id, item)),
+                Some(_) => plan.unchanged += 1,
+            }
+        }
+
+        if options.delete_missing {
+            plan.deletes
+                .extend(existing.into_values().map(|(id, _)| id));
+            plan.deletes.sort_unstable();
+        }
+        Ok(plan)
+    }
+}
+
+/// Returns the value of the key field, if set, in a form usable as map key
+fn key_value(row: &HashMap<String, Value>, key: &str) -> Option<String> {
+    match row.get(key)? {
+        Value::Null => None,
+        Value::String(value) => Some(value.clone()),
+        value => Some(value.to_string()),
+    }
+}
+
+/// Returns whether any field of an item differs from the row
+fn differs(item: &HashMap<String, Value>, row: &HashMap<String, Value>) -> bool {
+    item.iter()
+        .any(|(field, value)| row.get(field).unwrap_or(&Value::Null) != value)
+}
+
+/// Serializes an item into the fields of a row
+fn to_fields<T: Serialize>(item: &T) -> Result<HashMap<String, Value>, BaserowError> {
+    match serde_json::to_value(item)? {
+        Value::Object(fields) => Ok(fields.into_iter().collect()),
+        _ => Err(BaserowError::InvalidRequest(
+            "Items must serialize to objects".to_string(),
+        )),
+    }
+}
+
+impl BaserowTable {
+    /// Brings the rows of the table in line with the given items
+    ///
+    /// Items are matched to rows by the value of the `key` field, which has
+    /// to be unique among the items. Items without a matching row are
+    /// created, items differing from their row update it, and rows without a
+    /// matching item are deleted. Of rows sharing a key, the one with the
+    /// lowest ID is matched and the others are deleted as duplicates. Fields
+    /// are named as set via `with_field_names`, so items and key use the
+    /// same names as the rows.
+    ///
+    /// Values are compared as read from Baserow. Items should therefore use
+    /// the representation Baserow returns, e.g. objects for select options,
+    /// or their rows are updated on every sync.
+    pub async fn sync<T: Serialize>(
+        &self,
+        items: &[T],
+        key: &str,
+    ) -> Result<SyncSummary, BaserowError> {
+        self.sync_with_options(items, key, SyncOptions::default())
+            .await
+    }
+
+    /// Brings the rows of the table in line with the given items, see `sync`
+    #[instrument(skip(self, items, options), fields(table_id = ?self.id, item_count = items.len()), err)]
+    pub async fn sync_with_options<T: Serialize>(
+        &self,
+        items: &[T],
+        key: &str,
+        options: SyncOptions,
+    ) -> Result<SyncSummary, BaserowError> {
+        let items = items.iter().map(to_fields).collect::<Result<Vec<_>, _>>()?;
+
+        debug!("Fetching rows to sync");
+        let request = RowRequest {
+            page_size: Some(200),
+            ..Default::default()
+        };
+        let rows = self.all_rows(request).await?;
+        let plan = SyncPlan::new(items, rows, key, &options)?;
+        info!(
+            creates = plan.creates.len(),
+            updates = plan.updates.len(),
+            deletes = plan.deletes.len(),
+            unchanged = plan.unchanged,
+            "Planned sync"
+        );
+
+        Ok(SyncSummary {
+            created: self.create_many(plan.creates, None).await?,
+            updated: self.update_many(plan.updates, None).await?,
+            deleted: self.delete_many(plan.deletes).await?,
+            unchanged: plan.unchanged,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{api::client::BaserowClient, Baserow, ConfigBuilder, TableId};
+    use serde_json::json;
+
+    fn fields(value: Value) -> HashMap<String, Value> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_sync_plan() {
+        let rows = vec![
+            fields(json!({"id": 1, "Email": "a@example.com", "Name": "A", "Notes": "kept"})),
+            fields(json!({"id": 2, "Email": "b@example.com", "Name": "B"})),
+            fields(json!({"id": 3, "Email": "c@example.com", "Name": "C"})),
+            fields(json!({"id": 4, "Email": null, "Name": "Unkeyed"})),
+        ];
+        let items = vec![
+            fields(json!({"Email": "a@example.com", "Name": "A"})),
+            fields(json!({"Email": "b@example.com", "Name": "Bee"})),
+            fields(json!({"Email": "d@example.com", "Name": "D"})),
+        ];
+
+        let plan = SyncPlan::new(
+            items.clone(),
+            rows.clone(),
+            "Email",
+            &SyncOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(plan.creates, vec![items[2].clone()]);
+        assert_eq!(plan.updates, vec![(RowId(2), items[1].clone())]);
+        assert_eq!(plan.deletes, vec![RowId(3)]);
+        assert_eq!(plan.unchanged, 1);
+
+        let plan = SyncPlan::new(
+            items.clone(),
+            rows.clone(),
+            "Email",
+            &SyncOptions::new().keep_missing(),
+        )
+        .unwrap();
+        assert!(plan.deletes.is_empty());
+
+        let duplicates = vec![items[0].clone(), items[0].clone()];
+        let result = SyncPlan::new(duplicates, rows.clone(), "Email", &SyncOptions::default());
+        assert!(matches!(result, Err(BaserowError::InvalidRequest(_))));
+
+        // Rows sharing a key are matched by the lowest ID, the others deleted
+        let mut duplicated_rows = rows;
+        duplicated_rows.insert(
+            0,
+            fields(json!({"id": 5, "Email": "a@example.com", "Name": "Copy"})),
+        );
+        let plan = SyncPlan::new(
+            items.clone(),
+            duplicated_rows.clone(),
+            "Email",
+            &SyncOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(plan.deletes, vec![RowId(3), RowId(5)]);
+        assert_eq!(plan.unchanged, 1);
+
+        let result = SyncPlan::new(
+            items,
+            duplicated_rows,
+            "Email",
+            &SyncOptions::new().keep_missing(),
+        );
+        assert!(matches!(result, Err(BaserowError::InvalidRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_sync() {
+        let mut server = mockito::Server::new_async().await;
+
+        let rows_mock = server
+            .mock("GET", "/api/database/rows/table/1234/")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("user_field_names".into(), "true".into()),
+                mockito::Matcher::UrlEncoded("page".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                json!({
+                    "count": 2,
+                    "next": null,
+                    "previous": null,
+                    "results": [
+                        {"id": 1, "Email": "a@example.com", "Name": "A"},
+                        {"id": 2, "Email": "b@example.com", "Name": "B"}
+                    ]
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let create_mock = server
+            .mock("POST", "/api/database/rows/table/1234/batch/")
+            .match_query(mockito::Matcher::Any)
+            .match_body(mockito::Matcher::Json(json!({
+                "items": [{"Email": "c@example.com", "Name": "C"}]
+            })))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"items": [{"id": 3, "Email": "c@example.com", "Name": "C"}]}"#)
+            .create_async()
+            .await;
+        let update_mock = server
+            .mock("PATCH", "/api/database/rows/table/1234/batch/")
+            .match_query(mockito::Matcher::Any)
+            .match_body(mockito::Matcher::Json(json!({
+                "items": [{"id": 1, "Email": "a@example.com", "Name": "Ay"}]
+            })))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"items": [{"id": 1, "Email": "a@example.com", "Name": "Ay"}]}"#)
+            .create_async()
+            .await;
+        let delete_mock = server
+            .mock("POST", "/api/database/rows/table/1234/batch-delete/")
+            .match_body(mockito::Matcher::Json(json!({"items": [2]})))
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let configuration = ConfigBuilder::new()
+            .base_url(&server.url())
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let table = Baserow::with_configuration(configuration)
+            .table_by_id(TableId(1234))
+            .with_user_field_names(true);
+
+        let items = vec![
+            json!({"Email": "a@example.com", "Name": "Ay"}),
+            json!({"Email": "c@example.com", "Name": "C"}),
+        ];
+        let summary = table.sync(&items, "Email").await.unwrap();
+
+        assert!(summary.is_complete());
+        assert_eq!(summary.created.rows().count(), 1);
+        assert_eq!(summary.updated.rows().count(), 1);
+        assert_eq!(summary.deleted.into_rows(), vec![RowId(2)]);
+        assert_eq!(summary.unchanged, 0);
+
+        rows_mock.assert_async().await;
+        create_mock.assert_async().await;
+        update_mock.assert_async().await;
+        delete_mock.assert_async().await;
+    }
+}
@@ -0,0 +1,357 @@
+//! Client-side validation of values before they are written to Baserow
+//!
+//! Baserow validates every value it receives. Checking values against the
+//! table schema on the client reports all invalid fields at once, before a
+//! request that is bound to fail is sent.
+
+use serde_json::Value;
+
+use crate::{error::FieldValueError, TableField};
+
+/// The maximum of rating fields if the schema does not report one
+const DEFAULT_RATING_MAX: u8 = 5;
+
+/// Checks whether a JSON value, as it would be sent to Baserow, is valid for a field
+///
+/// Values are checked against the constraints reported in the schema: select
+/// options must exist, numbers must respect the allowed sign and decimal
+/// places, ratings must not exceed the maximum, dates must parse, URLs and
+/// email addresses must be well-formed. Empty values are accepted for every
+/// field, as they are by Baserow. Constraints the schema does not report are
+/// not checked.
+pub(crate) fn validate_value(field: &TableField, value: &Value) -> Result<(), FieldValueError> {
+    let invalid_number = || FieldValueError::InvalidNumber {
+        field: field.name.clone(),
+        value: value.to_string(),
+    };
+
+    if is_empty(value) {
+        return Ok(());
+    }
+
+    match field.r#type.as_str() {
+        "single_select" => validate_select_option(field, value),
+        "multiple_select" => match value {
+            Value::Array(options) => options
+                .iter()
+                .try_for_each(|option| validate_select_option(field, option)),
+            _ => validate_select_option(field, value),
+        },
+        "number" => {
+            let text = match value {
+                Value::String(text) => text.trim().to_string(),
+                Value::Number(number) => number.to_string(),
+                _ => return Err(invalid_number()),
+            };
+            let number: f64 = text.parse().map_err(|_| invalid_number())?;
+            if number < 0.0 && field.number_negative == Some(false) {
+                return Err(FieldValueError::NegativeNumber {
+                    field: field.name.clone(),
+                    value: text,
+                });
+            }
+            match field.number_decimal_places {
+                Some(decimal_places) if count_decimal_places(&text) > decimal_places => {
+                    Err(FieldValueError::TooManyDecimalPlaces {
+                        field: field.name.clone(),
+                        value: text,
+                        decimal_places,
+                    })
+                }
+                _ => Ok(()),
+            }
+        }
+        "rating" => {
+            let rating = value.as_u64().ok_or_else(invalid_number)?;
+            let max = field.max_value.unwrap_or(DEFAULT_RATING_MAX);
+            match u8::try_from(rating) {
+                Ok(rating) if rating <= max => Ok(()),
+                _ => Err(FieldValueError::RatingOutOfRange {
+                    field: field.name.clone(),
+                    value: rating,
+                    max,
+                }),
+            }
+        }
+        "date" => match value.as_str() {
+            Some(date)
+                if is_valid_date(
+                    date,
+                    field.date_format.as_deref(),
+                    field.date_include_time.unwrap_or_default(),
+                ) =>
+            {
+                Ok(())
+            }
+            _ => Err(FieldValueError::InvalidDate {
+                field: field.name.clone(),
+                value: value.to_string(),
+            }),
+        },
+        "url" => match value.as_str() {
+            Some(url) if is_valid_url(url) => Ok(()),
+            _ => Err(FieldValueError::InvalidURL {
+                field: field.name.clone(),
+                value: value.to_string(),
+            }),
+        },
+        "email" => match value.as_str() {
+            Some(email) if is_valid_email(email) => Ok(()),
+            _ => Err(FieldValueError::InvalidEmail {
+                field: field.name.clone(),
+                value: value.to_string(),
+            }),
+        },
+        _ => Ok(()),
+    }
+}
+
+/// Returns whether a value leaves the cell empty
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(text) => text.trim().is_empty(),
+        _ => false,
+    }
+}
+
+/// Checks that a select option, referenced by ID, value or object with an ID, exists
+fn validate_select_option(field: &TableField, option: &Value) -> Result<(), FieldValueError> {
+    if field.select_options.is_empty() {
+        return Ok(());
+    }
+
+    let exists = match option {
+        Value::Number(id) => id
+            .as_u64()
+            .is_some_and(|id| field.select_options.iter().any(|option| option.id == id)),
+        Value::String(value) => field.select_options.iter().any(|option| {
+            option.value == *value || value.parse::<u64>().is_ok_and(|id| option.id == id)
+        }),
+        Value::Object(object) => object
+            .get("id")
+            .and_then(Value::as_u64)
+            .is_some_and(|id| field.select_options.iter().any(|option| option.id == id)),
+        _ => false,
+    };
+
+    match exists {
+        true => Ok(()),
+        false => Err(FieldValueError::UnknownSelectOption {
+            field: field.name.clone(),
+            value: match option {
+                Value::String(value) => value.clone(),
+                option => option.to_string(),
+            },
+        }),
+    }
+}
+
+/// Counts the significant decimal places of a number, ignoring trailing zeros
+fn count_decimal_places(number: &str) -> u32 {
+    match number.split_once('.') {
+        Some((_, decimals)) => decimals.trim_end_matches('0').len() as u32,
+        None => 0,
+    }
+}
+
+/// Checks a date in ISO 8601 or the field's date format, with a time if the field has one
+///
+/// The date format is `EU` (`31/01/2024`), `US` (`01/31/2024`) or `ISO`
+/// (`2024-01-31`). ISO 8601 dates are always accepted.
+fn is_valid_date(value: &str, format: Option<&str>, include_time: bool) -> bool {
+    let value = value.trim();
+    let (date, time) = match value.split_once(['T', ' ']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (value, None),
+    };
+
+    let numbers = |separator: char| -> Option<Vec<u32>> {
+        date.split(separator)
+            .map(|part| part.parse().ok())
+            .collect::<Option<Vec<u32>>>()
+            .filter(|parts| parts.len() == 3)
+    };
+    let (year, month, day) = match (numbers('-'), numbers('/'), format) {
+        (Some(parts), _, _) => (parts[0], parts[1], parts[2]),
+        (_, Some(parts), Some("EU")) => (parts[2], parts[1], parts[0]),
+        (_, Some(parts), Some("US")) => (parts[2], parts[0], parts[1]),
+        _ => return false,
+    };
+
+    let valid_date = (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month);
+    match time {
+        None => valid_date,
+        Some(time) => valid_date && include_time && is_valid_time(time),
+    }
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Checks a time like `13:45`, `13:45:30.5` or `13:45:30+02:00`
+fn is_valid_time(time: &str) -> bool {
+    let time = time.strip_suffix('Z').unwrap_or(time);
+    let (time, offset) = match time.rfind(['+', '-']) {
+        Some(index) => (&time[..index], Some(&time[index + 1..])),
+        None => (time, None),
+    };
+
+    let clock = |text: &str, with_seconds: bool| -> bool {
+        let parts: Vec<&str> = text.split(':').collect();
+        let number =
+            |part: &str, max: f64| part.parse::<f64>().is_ok_and(|n| (0.0..max).contains(&n));
+        match parts[..] {
+            [hours, minutes] => number(hours, 24.0) && number(minutes, 60.0),
+            [hours, minutes, seconds] if with_seconds => {
+                number(hours, 24.0) && number(minutes, 60.0) && number(seconds, 60.0)
+            }
+            _ => false,
+        }
+    };
+
+    clock(time, true) && offset.is_none_or(|offset| clock(offset, false))
+}
+
+/// Checks for an absolute URL with a host, which may omit the scheme like Baserow allows
+fn is_valid_url(url: &str) -> bool {
+    let parsed = match url.contains("://") {
+        true => reqwest::Url::parse(url),
+        false => reqwest::Url::parse(&format!("http://{}", url)),
+    };
+    !url.chars().any(char::is_whitespace)
+        && parsed.is_ok_and(|parsed| parsed.host_str().is_some_and(|host| !host.is_empty()))
+}
+
+/// Checks for a single `@` between a non-empty local part and a dotted domain
+fn is_valid_email(email: &str) -> bool {
+    match email.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.contains('@')
+                && !email.chars().any(char::is_whitespace)
+                && domain.contains('.')
+                && domain.split('.').all(|label| !label.is_empty())
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value::SelectOption;
+    use serde_json::json;
+
+    fn field(r#type: &str) -> TableField {
+        TableField::new("Field", 1, r#type)
+    }
+
+    #[test]
+    fn test_select_options() {
+        let mut status = field("single_select");
+        status.select_options = vec![SelectOption {
+            id: 1,
+            value: "Open".to_string(),
+            color: "blue".to_string(),
+        }];
+
+        for value in [
+            json!(1),
+            json!("Open"),
+            json!("1"),
+            json!({"id": 1}),
+            Value::Null,
+        ] {
+            assert!(validate_value(&status, &value).is_ok(), "{}", value);
+        }
+        assert!(matches!(
+            validate_value(&status, &json!("Closed")),
+            Err(FieldValueError::UnknownSelectOption { value, .. }) if value == "Closed"
+        ));
+
+        status.r#type = "multiple_select".to_string();
+        assert!(validate_value(&status, &json!([1, "Open"])).is_ok());
+        assert!(validate_value(&status, &json!([1, 2])).is_err());
+    }
+
+    #[test]
+    fn test_numbers() {
+        let mut price = field("number");
+        price.number_decimal_places = Some(2);
+        price.number_negative = Some(false);
+
+        for value in [json!(9.99), json!("10.50"), json!(10), json!("10.100")] {
+            assert!(validate_value(&price, &value).is_ok(), "{}", value);
+        }
+        assert!(matches!(
+            validate_value(&price, &json!(1.234)),
+            Err(FieldValueError::TooManyDecimalPlaces {
+                decimal_places: 2,
+                ..
+            })
+        ));
+        assert!(matches!(
+            validate_value(&price, &json!("-1")),
+            Err(FieldValueError::NegativeNumber { .. })
+        ));
+        assert!(matches!(
+            validate_value(&price, &json!("ten")),
+            Err(FieldValueError::InvalidNumber { .. })
+        ));
+    }
+
+    #[test]
+    fn test_dates() {
+        let mut due = field("date");
+        due.date_format = Some("EU".to_string());
+
+        for value in ["2024-02-29", "29/02/2024"] {
+            assert!(validate_value(&due, &json!(value)).is_ok(), "{}", value);
+        }
+        for value in [
+            "2023-02-29",
+            "02/29/2024",
+            "2024-01-31T10:00:00Z",
+            "tomorrow",
+        ] {
+            assert!(
+                matches!(
+                    validate_value(&due, &json!(value)),
+                    Err(FieldValueError::InvalidDate { .. })
+                ),
+                "{}",
+                value
+            );
+        }
+
+        due.date_include_time = Some(true);
+        for value in [
+            "2024-01-31T10:00:00Z",
+            "2024-01-31T10:00:00.123+02:00",
+            "31/01/2024 10:00",
+        ] {
+            assert!(validate_value(&due, &json!(value)).is_ok(), "{}", value);
+        }
+        assert!(validate_value(&due, &json!("2024-01-31T25:00")).is_err());
+    }
+
+    #[test]
+    fn test_empty_values() {
+        let mut name = field("text");
+        assert!(validate_value(&name, &Value::Null).is_ok());
+
+        // Baserow accepts empty primary fields
+        name.primary = true;
+        assert!(validate_value(&name, &json!(" ")).is_ok());
+        assert!(validate_value(&field("number"), &Value::Null).is_ok());
+    }
+}
@@ -0,0 +1,1145 @@
+use std::{ops::Deref, time::Duration};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{json, Map, Value};
+
+use crate::{
+    api::file::{BaserowFileRef, FileFieldValue},
+    error::FieldValueError,
+    ids::RowId,
+    validation::validate_value,
+    TableField,
+};
+
+/// An option of a single or multiple select field, as returned when reading a row
+///
+/// # Example
+/// ```
+/// use baserow_rs::value::{SelectOption, SelectOptionRef};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize)]
+/// struct Ticket {
+///     status: Option<SelectOption>,
+/// }
+///
+/// #[derive(Serialize)]
+/// struct NewTicket {
+///     status: SelectOptionRef,
+/// }
+///
+/// let ticket = NewTicket { status: "Open".into() };
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SelectOption {
+    pub id: u64,
+    pub value: String,
+    #[serde(default)]
+    pub color: String,
+}
+
+/// A select option to write to a single or multiple select field
+///
+/// Baserow accepts either the ID or the value of an existing option.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SelectOptionRef {
+    Id(u64),
+    Value(String),
+}
+
+impl From<u64> for SelectOptionRef {
+    fn from(id: u64) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl From<&str> for SelectOptionRef {
+    fn from(value: &str) -> Self {
+        Self::Value(value.to_string())
+    }
+}
+
+impl From<String> for SelectOptionRef {
+    fn from(value: String) -> Self {
+        Self::Value(value)
+    }
+}
+
+impl From<&SelectOption> for SelectOptionRef {
+    fn from(option: &SelectOption) -> Self {
+        Self::Id(option.id)
+    }
+}
+
+/// A row referenced by a link row field
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LinkedRow {
+    pub id: RowId,
+    /// The primary field value of the linked row
+    pub value: Value,
+}
+
+/// The rows referenced by a link row field, as returned when reading a row
+///
+/// # Example
+/// ```
+/// use baserow_rs::{value::{LinkRowRef, LinkRowValue}, RowId};
+/// use serde_json::json;
+///
+/// let projects: LinkRowValue =
+///     serde_json::from_value(json!([{"id": 1, "value": "Apollo"}, {"id": 2, "value": "Gemini"}]))
+///         .unwrap();
+/// assert_eq!(projects.ids(), vec![RowId(1), RowId(2)]);
+///
+/// // Link rows by ID or by their primary field value when writing
+/// let links: Vec<LinkRowRef> = vec![RowId(1).into(), "Gemini".into()];
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct LinkRowValue(pub Vec<LinkedRow>);
+
+impl LinkRowValue {
+    /// Returns the IDs of the linked rows
+    pub fn ids(&self) -> Vec<RowId> {
+        self.0.iter().map(|row| row.id).collect()
+    }
+
+    /// Returns the primary field values of the linked rows as text
+    pub fn values(&self) -> Vec<String> {
+        self.0
+            .iter()
+            .map(|row| match &row.value {
+                Value::String(value) => value.clone(),
+                value => value.to_string(),
+            })
+            .collect()
+    }
+
+    /// Returns whether the row with the given ID is linked
+    pub fn contains(&self, id: RowId) -> bool {
+        self.0.iter().any(|row| row.id == id)
+    }
+
+    /// Returns the references needed to write these links back
+    pub fn to_refs(&self) -> Vec<LinkRowRef> {
+        self.0.iter().map(LinkRowRef::from).collect()
+    }
+}
+
+impl Deref for LinkRowValue {
+    type Target = Vec<LinkedRow>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Vec<LinkedRow>> for LinkRowValue {
+    fn from(rows: Vec<LinkedRow>) -> Self {
+        Self(rows)
+    }
+}
+
+/// A row to link in a link row field when writing a row
+///
+/// Baserow accepts either the ID or the primary field value of the row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LinkRowRef {
+    Id(RowId),
+    Value(String),
+}
+
+impl From<RowId> for LinkRowRef {
+    fn from(id: RowId) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl From<&str> for LinkRowRef {
+    fn from(value: &str) -> Self {
+        Self::Value(value.to_string())
+    }
+}
+
+impl From<String> for LinkRowRef {
+    fn from(value: String) -> Self {
+        Self::Value(value)
+    }
+}
+
+impl From<&LinkedRow> for LinkRowRef {
+    fn from(row: &LinkedRow) -> Self {
+        Self::Id(row.id)
+    }
+}
+
+/// A user referenced by a collaborator field
+///
+/// Used for multiple collaborators fields as well as the read-only created by
+/// and last modified by fields. Only the ID is needed when writing.
+///
+/// # Example
+/// ```
+/// use baserow_rs::value::Collaborator;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize)]
+/// struct Task {
+///     assignees: Vec<Collaborator>,
+///     created_by: Option<Collaborator>,
+/// }
+///
+/// #[derive(Serialize)]
+/// struct NewTask {
+///     assignees: Vec<Collaborator>,
+/// }
+///
+/// let task = NewTask { assignees: vec![Collaborator::new(1)] };
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Collaborator {
+    pub id: u64,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+}
+
+impl Collaborator {
+    /// References the user with the given ID
+    pub fn new(id: u64) -> Self {
+        Self {
+            id,
+            name: String::new(),
+        }
+    }
+}
+
+/// The display format of a duration field, as reported in `TableField::duration_format`
+///
+/// The format determines the precision Baserow stores durations with and how
+/// formatted strings such as `1:30` are interpreted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DurationFormat {
+    /// `h:mm`
+    HoursMinutes,
+    /// `h:mm:ss`
+    HoursMinutesSeconds,
+    /// `h:mm:ss.s`
+    HoursMinutesSecondsDeciseconds,
+    /// `h:mm:ss.ss`
+    HoursMinutesSecondsCentiseconds,
+    /// `h:mm:ss.sss`
+    HoursMinutesSecondsMilliseconds,
+    /// `d h`
+    DaysHours,
+    /// `d h:mm`
+    DaysHoursMinutes,
+    /// `d h:mm:ss`
+    DaysHoursMinutesSeconds,
+    /// `d h mm`
+    DaysHoursMinutesUnits,
+    /// `d h mm ss`
+    DaysHoursMinutesSecondsUnits,
+}
+
+impl DurationFormat {
+    /// Parses the format string used by the Baserow API
+    pub fn parse(format: &str) -> Option<Self> {
+        Some(match format {
+            "h:mm" => Self::HoursMinutes,
+            "h:mm:ss" => Self::HoursMinutesSeconds,
+            "h:mm:ss.s" => Self::HoursMinutesSecondsDeciseconds,
+            "h:mm:ss.ss" => Self::HoursMinutesSecondsCentiseconds,
+            "h:mm:ss.sss" => Self::HoursMinutesSecondsMilliseconds,
+            "d h" => Self::DaysHours,
+            "d h:mm" => Self::DaysHoursMinutes,
+            "d h:mm:ss" => Self::DaysHoursMinutesSeconds,
+            "d h mm" => Self::DaysHoursMinutesUnits,
+            "d h mm ss" => Self::DaysHoursMinutesSecondsUnits,
+            _ => return None,
+        })
+    }
+
+    /// Converts the format to its string representation for API requests
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::HoursMinutes => "h:mm",
+            Self::HoursMinutesSeconds => "h:mm:ss",
+            Self::HoursMinutesSecondsDeciseconds => "h:mm:ss.s",
+            Self::HoursMinutesSecondsCentiseconds => "h:mm:ss.ss",
+            Self::HoursMinutesSecondsMilliseconds => "h:mm:ss.sss",
+            Self::DaysHours => "d h",
+            Self::DaysHoursMinutes => "d h:mm",
+            Self::DaysHoursMinutesSeconds => "d h:mm:ss",
+            Self::DaysHoursMinutesUnits => "d h mm",
+            Self::DaysHoursMinutesSecondsUnits => "d h mm ss",
+        }
+    }
+
+    /// The smallest unit in seconds a duration in this format is stored with
+    fn precision(&self) -> f64 {
+        match self {
+            Self::HoursMinutes | Self::DaysHoursMinutes | Self::DaysHoursMinutesUnits => 60.0,
+            Self::HoursMinutesSeconds
+            | Self::DaysHoursMinutesSeconds
+            | Self::DaysHoursMinutesSecondsUnits => 1.0,
+            Self::HoursMinutesSecondsDeciseconds => 0.1,
+            Self::HoursMinutesSecondsCentiseconds => 0.01,
+            Self::HoursMinutesSecondsMilliseconds => 0.001,
+            Self::DaysHours => 3600.0,
+        }
+    }
+
+    /// Rounds a duration to the precision Baserow stores it with in this format
+    pub fn round(&self, duration: Duration) -> Duration {
+        let precision = self.precision();
+        Duration::from_secs_f64((duration.as_secs_f64() / precision).round() * precision)
+    }
+
+    /// Formats a duration the way Baserow displays it in this format
+    ///
+    /// # Example
+    /// ```
+    /// use baserow_rs::value::DurationFormat;
+    /// use std::time::Duration;
+    ///
+    /// let format = DurationFormat::parse("d h:mm").unwrap();
+    /// assert_eq!(format.format(Duration::from_secs(95_400)), "1d 2:30");
+    /// ```
+    pub fn format(&self, duration: Duration) -> String {
+        let decimals = match self {
+            Self::HoursMinutesSecondsDeciseconds => 1,
+            Self::HoursMinutesSecondsCentiseconds => 2,
+            Self::HoursMinutesSecondsMilliseconds => 3,
+            _ => 0,
+        };
+        let scale = 10u64.pow(decimals);
+        let total = (self.round(duration).as_secs_f64() * scale as f64).round() as u64;
+        let (fraction, seconds) = (total % scale, total / scale);
+
+        let (days, hours) = match self {
+            Self::DaysHours
+            | Self::DaysHoursMinutes
+            | Self::DaysHoursMinutesSeconds
+            | Self::DaysHoursMinutesUnits
+            | Self::DaysHoursMinutesSecondsUnits => (seconds / 86_400, seconds % 86_400 / 3600),
+            _ => (0, seconds / 3600),
+        };
+        let minutes = seconds % 3600 / 60;
+        let secs = seconds % 60;
+
+        match self {
+            Self::HoursMinutes => format!("{}:{:02}", hours, minutes),
+            Self::HoursMinutesSeconds => format!("{}:{:02}:{:02}", hours, minutes, secs),
+            Self::HoursMinutesSecondsDeciseconds
+            | Self::HoursMinutesSecondsCentiseconds
+            | Self::HoursMinutesSecondsMilliseconds => format!(
+                "{}:{:02}:{:02}.{:0width$}",
+                hours,
+                minutes,
+                secs,
+                fraction,
+                width = decimals as usize
+            ),
+            Self::DaysHours => format!("{}d {}h", days, hours),
+            Self::DaysHoursMinutes => format!("{}d {}:{:02}", days, hours, minutes),
+            Self::DaysHoursMinutesSeconds => {
+                format!("{}d {}:{:02}:{:02}", days, hours, minutes, secs)
+            }
+            Self::DaysHoursMinutesUnits => format!("{}d {}h {}m", days, hours, minutes),
+            Self::DaysHoursMinutesSecondsUnits => {
+                format!("{}d {}h {}m {}s", days, hours, minutes, secs)
+            }
+        }
+    }
+}
+
+/// Parses a duration from the number of seconds or a formatted string
+///
+/// Strings may use colons (`1:30`, `1:30:15.5`), unit suffixes
+/// (`1d 2h 30m 15s`) or a mix of both (`1d 2:30`). Two colon-separated
+/// parts are read as hours and minutes, like Baserow does. Negative
+/// durations are rejected as they cannot be represented by [`Duration`].
+pub fn parse_duration(value: &Value) -> Result<Duration, serde_json::Error> {
+    let seconds = match value {
+        Value::String(text) => text
+            .parse::<f64>()
+            .ok()
+            .or_else(|| parse_duration_text(text))
+            .ok_or_else(|| de::Error::custom(format!("invalid duration: {}", text)))?,
+        value => parse_number(value.clone())?,
+    };
+    Duration::try_from_secs_f64(seconds)
+        .map_err(|_| de::Error::custom(format!("invalid duration: {} seconds", seconds)))
+}
+
+fn parse_duration_text(text: &str) -> Option<f64> {
+    let mut seconds = 0.0;
+    for part in text.split_whitespace() {
+        if part.contains(':') {
+            let numbers = part
+                .split(':')
+                .map(|number| number.parse::<f64>().ok())
+                .collect::<Option<Vec<_>>>()?;
+            seconds += match numbers[..] {
+                [hours, minutes] => hours * 3600.0 + minutes * 60.0,
+                [hours, minutes, secs] => hours * 3600.0 + minutes * 60.0 + secs,
+                _ => return None,
+            };
+            continue;
+        }
+
+        let unit_index = part.find(|c: char| c.is_ascii_alphabetic())?;
+        let number: f64 = part[..unit_index].parse().ok()?;
+        seconds += number
+            * match &part[unit_index..] {
+                "d" => 86_400.0,
+                "h" => 3600.0,
+                "m" => 60.0,
+                "s" => 1.0,
+                _ => return None,
+            };
+    }
+    (!text.trim().is_empty()).then_some(seconds)
+}
+
+/// Serde helpers for `Duration` fields of typed rows
+///
+/// Durations are read from the number of seconds or a formatted string and
+/// written as the number of seconds.
+///
+/// # Example
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use std::time::Duration;
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Task {
+///     #[serde(with = "baserow_rs::value::duration_seconds")]
+///     estimate: Duration,
+///     #[serde(with = "baserow_rs::value::duration_seconds::option")]
+///     spent: Option<Duration>,
+/// }
+/// ```
+pub mod duration_seconds {
+    use std::time::Duration;
+
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use serde_json::Value;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(duration.as_secs_f64())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        super::parse_duration(&value).map_err(de::Error::custom)
+    }
+
+    /// Serde helpers for `Option<Duration>` fields, mapping empty cells to `None`
+    pub mod option {
+        use std::time::Duration;
+
+        use serde::{de, Deserialize, Deserializer, Serializer};
+        use serde_json::Value;
+
+        pub fn serialize<S: Serializer>(
+            duration: &Option<Duration>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match duration {
+                Some(duration) => super::serialize(duration, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Duration>, D::Error> {
+            match Value::deserialize(deserializer)? {
+                Value::Null => Ok(None),
+                value => super::super::parse_duration(&value)
+                    .map(Some)
+                    .map_err(de::Error::custom),
+            }
+        }
+    }
+}
+
+/// Serde helpers for number fields of typed rows
+///
+/// Baserow returns numbers as strings to preserve their decimal places.
+/// Numbers are read from strings or plain numbers and written as numbers.
+///
+/// # Example
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Product {
+///     #[serde(with = "baserow_rs::value::number")]
+///     price: f64,
+///     #[serde(with = "baserow_rs::value::number::option")]
+///     discount: Option<f64>,
+/// }
+///
+/// let product: Product = serde_json::from_str(r#"{"price": "9.90", "discount": null}"#).unwrap();
+/// assert_eq!(product.price, 9.9);
+/// ```
+pub mod number {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use serde_json::Value;
+
+    pub fn serialize<S: Serializer>(number: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(*number)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        super::parse_number(value).map_err(de::Error::custom)
+    }
+
+    /// Serde helpers for `Option<f64>` fields, mapping empty cells to `None`
+    pub mod option {
+        use serde::{de, Deserialize, Deserializer, Serializer};
+        use serde_json::Value;
+
+        pub fn serialize<S: Serializer>(
+            number: &Option<f64>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match number {
+                Some(number) => super::serialize(number, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<f64>, D::Error> {
+            match Value::deserialize(deserializer)? {
+                Value::Null => Ok(None),
+                value => super::super::parse_number(value)
+                    .map(Some)
+                    .map_err(de::Error::custom),
+            }
+        }
+    }
+}
+
+/// The value of a single cell of a row
+///
+/// Rows returned by the API contain plain JSON values whose shape depends on
+/// the type of the field. `BaserowFieldValue` represents them as typed values
+/// that can be pattern-matched.
+///
+/// Use [`BaserowFieldValue::from_field`] or [`BaserowFieldValue::from_field_type`]
+/// when the field is known, e.g. via `TableMapper::typed_values`. Deserializing without a field type infers
+/// the variant from the shape of the JSON value, which cannot tell apart
+/// texts and dates for example.
+///
+/// Serializing produces the representation Baserow expects when writing the
+/// value: select options, linked rows and collaborators are written by ID,
+/// files by name.
+///
+/// # Example
+/// ```
+/// use baserow_rs::value::BaserowFieldValue;
+/// use serde_json::json;
+///
+/// let value = BaserowFieldValue::from_field_type("number", json!("42.50")).unwrap();
+/// match value {
+///     BaserowFieldValue::Number(number) => assert_eq!(number, 42.5),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum BaserowFieldValue {
+    /// An empty cell
+    Null,
+    /// Text, long text and phone number fields
+    Text(String),
+    /// Number and count fields
+    Number(f64),
+    /// Rating fields, from zero up to the field's `max_value`
+    Rating(u8),
+    /// Autonumber fields, which are read-only
+    Autonumber(u64),
+    /// UUID fields, which are read-only
+    Uuid(String),
+    /// URL fields
+    Url(String),
+    /// Email fields
+    Email(String),
+    /// Boolean fields
+    Boolean(bool),
+    /// Date, created on and last modified fields as ISO 8601 string
+    Date(String),
+    /// Single select fields
+    SingleSelect(SelectOption),
+    /// Multiple select fields
+    MultipleSelect(Vec<SelectOption>),
+    /// Link row fields
+    LinkRow(LinkRowValue),
+    /// File fields
+    File(FileFieldValue),
+    /// Created by and last modified by fields
+    Collaborator(Collaborator),
+    /// Multiple collaborators fields
+    Collaborators(Vec<Collaborator>),
+    /// Duration fields
+    Duration(Duration),
+    /// Results of formula, lookup and rollup fields returning an array, which are read-only
+    Array(Vec<BaserowFieldValue>),
+    /// Values of field types without a typed representation
+    Other(Value),
+}
+
+impl BaserowFieldValue {
+    /// Converts the value of a field of the given type, as reported in `TableField::type`
+    ///
+    /// Unknown field types are inferred from the shape of the value.
+    pub fn from_field_type(field_type: &str, value: Value) -> Result<Self, serde_json::Error> {
+        if value.is_null() {
+            return Ok(Self::Null);
+        }
+
+        Ok(match field_type {
+            "text" | "long_text" | "phone_number" => Self::Text(serde_json::from_value(value)?),
+            "number" | "count" => Self::Number(parse_number(value)?),
+            "rating" => Self::Rating(serde_json::from_value(value)?),
+            "autonumber" => Self::Autonumber(serde_json::from_value(value)?),
+            "uuid" => Self::Uuid(serde_json::from_value(value)?),
+            "url" => Self::Url(serde_json::from_value(value)?),
+            "email" => Self::Email(serde_json::from_value(value)?),
+            "boolean" => Self::Boolean(serde_json::from_value(value)?),
+            "date" | "created_on" | "last_modified" => Self::Date(serde_json::from_value(value)?),
+            "single_select" => Self::SingleSelect(serde_json::from_value(value)?),
+            "multiple_select" => Self::MultipleSelect(serde_json::from_value(value)?),
+            "link_row" => Self::LinkRow(serde_json::from_value(value)?),
+            "file" => Self::File(serde_json::from_value(value)?),
+            "created_by" | "last_modified_by" => Self::Collaborator(serde_json::from_value(value)?),
+            "multiple_collaborators" => Self::Collaborators(serde_json::from_value(value)?),
+            "duration" => Self::Duration(parse_duration(&value)?),
+            _ => Self::infer(value),
+        })
+    }
+
+    /// Converts the value of the given field
+    ///
+    /// Unlike [`BaserowFieldValue::from_field_type`], this takes field options
+    /// into account, e.g. durations are rounded to the precision of the
+    /// configured duration format.
+    ///
+    /// Values of formula, lookup, rollup and count fields are converted
+    /// according to the result type reported in `TableField::formula_type`.
+    /// Array results become [`BaserowFieldValue::Array`], with each item
+    /// converted according to `TableField::array_formula_type`.
+    pub fn from_field(field: &TableField, value: Value) -> Result<Self, serde_json::Error> {
+        let value = match (field.formula_type.as_deref(), value) {
+            (_, Value::Null) => Self::Null,
+            (Some("array"), Value::Array(items)) => {
+                let item_type = formula_result_field_type(
+                    field.array_formula_type.as_deref().unwrap_or_default(),
+                );
+                Self::Array(
+                    items
+                        .into_iter()
+                        .map(|item| match item {
+                            Value::Object(mut item) if item.contains_key("value") => {
+                                let value = item.remove("value").unwrap_or_default();
+                                Self::from_field_type(item_type, value)
+                            }
+                            item => Self::from_field_type(item_type, item),
+                        })
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            (Some(formula_type), value) => {
+                Self::from_field_type(formula_result_field_type(formula_type), value)?
+            }
+            (None, value) => Self::from_field_type(&field.r#type, value)?,
+        };
+
+        let format = field
+            .duration_format
+            .as_deref()
+            .and_then(DurationFormat::parse);
+        Ok(match (value, format) {
+            (Self::Duration(duration), Some(format)) => Self::Duration(format.round(duration)),
+            (value, _) => value,
+        })
+    }
+
+    /// Infers the variant from the shape of a JSON value
+    fn infer(value: Value) -> Self {
+        match value {
+            Value::Null => Self::Null,
+            Value::Bool(value) => Self::Boolean(value),
+            Value::Number(ref number) => match number.as_f64() {
+                Some(number) => Self::Number(number),
+                None => Self::Other(value),
+            },
+            Value::String(value) => Self::Text(value),
+            Value::Object(ref object) if is_select_option(object) => {
+                serde_json::from_value(value.clone())
+                    .map(Self::SingleSelect)
+                    .unwrap_or(Self::Other(value))
+            }
+            Value::Object(ref object) if is_collaborator(object) => {
+                serde_json::from_value(value.clone())
+                    .map(Self::Collaborator)
+                    .unwrap_or(Self::Other(value))
+            }
+            Value::Array(ref items) => {
+                let objects: Vec<&Map<String, Value>> =
+                    items.iter().filter_map(Value::as_object).collect();
+                let converted = if items.is_empty() || objects.len() != items.len() {
+                    None
+                } else if objects.iter().all(|object| is_file(object)) {
+                    serde_json::from_value(value.clone()).ok().map(Self::File)
+                } else if objects.iter().all(|object| is_select_option(object)) {
+                    serde_json::from_value(value.clone())
+                        .ok()
+                        .map(Self::MultipleSelect)
+                } else if objects.iter().all(|object| is_collaborator(object)) {
+                    serde_json::from_value(value.clone())
+                        .ok()
+                        .map(Self::Collaborators)
+                } else if objects.iter().all(|object| is_linked_row(object)) {
+                    serde_json::from_value(value.clone())
+                        .ok()
+                        .map(Self::LinkRow)
+                } else {
+                    None
+                };
+                converted.unwrap_or(Self::Other(value))
+            }
+            value => Self::Other(value),
+        }
+    }
+
+    /// Returns whether the cell is empty
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    /// Returns whether the value belongs to a field Baserow does not allow writing to
+    pub fn is_read_only(&self) -> bool {
+        matches!(self, Self::Autonumber(_) | Self::Uuid(_) | Self::Array(_))
+    }
+
+    /// Checks whether the value can be written to the given field
+    ///
+    /// Validation is optional: Baserow validates all values as well, but
+    /// checking them on the client avoids a request that is bound to fail.
+    /// Read-only values are rejected, and the value is checked against the
+    /// constraints of the field, e.g. ratings must not exceed the field's
+    /// maximum and URLs and email addresses must be well-formed.
+    pub fn validate(&self, field: &TableField) -> Result<(), FieldValueError> {
+        let result = match self.is_read_only() || field.read_only {
+            true => Err(FieldValueError::ReadOnly(field.name.clone())),
+            false => validate_value(field, &self.to_value()),
+        };
+        if let Err(e) = &result {
+            e.log();
+        }
+        result
+    }
+
+    /// Returns the JSON representation used when writing the value to Baserow
+    pub fn to_value(&self) -> Value {
+        match self {
+            Self::Null => Value::Null,
+            Self::Text(text)
+            | Self::Date(text)
+            | Self::Uuid(text)
+            | Self::Url(text)
+            | Self::Email(text) => json!(text),
+            Self::Number(number) => json!(number),
+            Self::Rating(rating) => json!(rating),
+            Self::Autonumber(number) => json!(number),
+            Self::Boolean(value) => json!(value),
+            Self::SingleSelect(option) => json!(option.id),
+            Self::MultipleSelect(options) => {
+                json!(options.iter().map(|option| option.id).collect::<Vec<_>>())
+            }
+            Self::LinkRow(rows) => json!(rows.ids()),
+            Self::File(files) => json!(files
+                .iter()
+                .map(|file| BaserowFileRef {
+                    name: file.name.clone(),
+                    visible_name: file.visible_name.clone(),
+                    ..Default::default()
+                })
+                .collect::<Vec<_>>()),
+            Self::Collaborator(collaborator) => json!(Collaborator::new(collaborator.id)),
+            Self::Collaborators(collaborators) => json!(collaborators
+                .iter()
+                .map(|collaborator| Collaborator::new(collaborator.id))
+                .collect::<Vec<_>>()),
+            Self::Duration(duration) => json!(duration.as_secs_f64()),
+            Self::Array(items) => json!(items.iter().map(Self::to_value).collect::<Vec<_>>()),
+            Self::Other(value) => value.clone(),
+        }
+    }
+}
+
+impl Serialize for BaserowFieldValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_value().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for BaserowFieldValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Value::deserialize(deserializer).map(Self::infer)
+    }
+}
+
+/// Maps the result type of a formula to the field type holding values of that type
+///
+/// Result types without a typed representation map to an empty string, so
+/// their values are inferred.
+pub(crate) fn formula_result_field_type(formula_type: &str) -> &str {
+    match formula_type {
+        "char" => "text",
+        "invalid" | "link" | "button" | "date_interval" => "",
+        formula_type => formula_type,
+    }
+}
+
+/// Parses numbers, which Baserow returns as strings to preserve decimal places
+fn parse_number(value: Value) -> Result<f64, serde_json::Error> {
+    match value {
+        Value::String(number) => number
+            .parse()
+            .map_err(|_| de::Error::custom(format!("invalid number: {}", number))),
+        value => serde_json::from_value(value),
+    }
+}
+
+fn is_select_option(object: &Map<String, Value>) -> bool {
+    object.contains_key("id") && object.contains_key("value") && object.contains_key("color")
+}
+
+fn is_linked_row(object: &Map<String, Value>) -> bool {
+    object.contains_key("id") && object.contains_key("value")
+}
+
+fn is_collaborator(object: &Map<String, Value>) -> bool {
+    object.contains_key("id") && object.contains_key("name") && object.len() == 2
+}
+
+fn is_file(object: &Map<String, Value>) -> bool {
+    object.contains_key("name") && object.contains_key("visible_name")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_field_type() {
+        assert_eq!(
+            BaserowFieldValue::from_field_type("text", json!("Hello")).unwrap(),
+            BaserowFieldValue::Text("Hello".to_string())
+        );
+        assert_eq!(
+            BaserowFieldValue::from_field_type("number", json!("12.50")).unwrap(),
+            BaserowFieldValue::Number(12.5)
+        );
+        assert_eq!(
+            BaserowFieldValue::from_field_type("date", json!("2024-01-31")).unwrap(),
+            BaserowFieldValue::Date("2024-01-31".to_string())
+        );
+        assert_eq!(
+            BaserowFieldValue::from_field_type("duration", json!(90)).unwrap(),
+            BaserowFieldValue::Duration(Duration::from_secs(90))
+        );
+        assert_eq!(
+            BaserowFieldValue::from_field_type("single_select", Value::Null).unwrap(),
+            BaserowFieldValue::Null
+        );
+        assert_eq!(
+            BaserowFieldValue::from_field_type(
+                "single_select",
+                json!({"id": 1, "value": "Open", "color": "blue"})
+            )
+            .unwrap(),
+            BaserowFieldValue::SingleSelect(SelectOption {
+                id: 1,
+                value: "Open".to_string(),
+                color: "blue".to_string(),
+            })
+        );
+        assert!(BaserowFieldValue::from_field_type("boolean", json!("yes")).is_err());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(
+            parse_duration(&json!(5400.5)).unwrap(),
+            Duration::from_secs_f64(5400.5)
+        );
+        assert_eq!(
+            parse_duration(&json!("1:30")).unwrap(),
+            Duration::from_secs(5400)
+        );
+        assert_eq!(
+            parse_duration(&json!("1:30:15.5")).unwrap(),
+            Duration::from_secs_f64(5415.5)
+        );
+        assert_eq!(
+            parse_duration(&json!("1d 2h 30m")).unwrap(),
+            Duration::from_secs(95_400)
+        );
+        assert_eq!(
+            parse_duration(&json!("1d 2:30")).unwrap(),
+            Duration::from_secs(95_400)
+        );
+        assert!(parse_duration(&json!("soon")).is_err());
+        assert!(parse_duration(&json!(-1)).is_err());
+    }
+
+    #[test]
+    fn test_duration_format() {
+        let duration = Duration::from_secs_f64(95_415.256);
+        let formats = [
+            ("h:mm", "26:30"),
+            ("h:mm:ss", "26:30:15"),
+            ("h:mm:ss.s", "26:30:15.3"),
+            ("h:mm:ss.ss", "26:30:15.26"),
+            ("h:mm:ss.sss", "26:30:15.256"),
+            ("d h", "1d 3h"),
+            ("d h:mm", "1d 2:30"),
+            ("d h:mm:ss", "1d 2:30:15"),
+            ("d h mm", "1d 2h 30m"),
+            ("d h mm ss", "1d 2h 30m 15s"),
+        ];
+        for (format, expected) in formats {
+            let format = DurationFormat::parse(format).unwrap();
+            assert_eq!(format.format(duration), expected, "{}", format.as_str());
+        }
+
+        let field: TableField = serde_json::from_value(json!({
+            "id": 1, "table_id": 1, "name": "Estimate", "order": 0, "type": "duration",
+            "primary": false, "read_only": false, "description": null,
+            "duration_format": "h:mm"
+        }))
+        .unwrap();
+        let value = BaserowFieldValue::from_field(&field, json!(5415)).unwrap();
+        assert_eq!(
+            value,
+            BaserowFieldValue::Duration(Duration::from_secs(5400))
+        );
+        assert_eq!(serde_json::to_value(&value).unwrap(), json!(5400.0));
+    }
+
+    #[test]
+    fn test_duration_seconds() {
+        #[derive(Deserialize, Serialize)]
+        struct Task {
+            #[serde(with = "duration_seconds")]
+            estimate: Duration,
+            #[serde(with = "duration_seconds::option")]
+            spent: Option<Duration>,
+        }
+
+        let task: Task =
+            serde_json::from_value(json!({"estimate": "1:30", "spent": null})).unwrap();
+        assert_eq!(task.estimate, Duration::from_secs(5400));
+        assert_eq!(task.spent, None);
+        assert_eq!(
+            serde_json::to_value(&task).unwrap(),
+            json!({"estimate": 5400.0, "spent": null})
+        );
+    }
+
+    #[test]
+    fn test_scalar_field_types() {
+        assert_eq!(
+            BaserowFieldValue::from_field_type("rating", json!(3)).unwrap(),
+            BaserowFieldValue::Rating(3)
+        );
+        assert_eq!(
+            BaserowFieldValue::from_field_type("autonumber", json!(42)).unwrap(),
+            BaserowFieldValue::Autonumber(42)
+        );
+        assert_eq!(
+            BaserowFieldValue::from_field_type("url", json!("https://baserow.io")).unwrap(),
+            BaserowFieldValue::Url("https://baserow.io".to_string())
+        );
+        assert!(BaserowFieldValue::from_field_type("rating", json!(300)).is_err());
+
+        let uuid = BaserowFieldValue::from_field_type(
+            "uuid",
+            json!("2b5c6a1e-7f0f-4a4b-9a33-3c0a7bd0b8f1"),
+        )
+        .unwrap();
+        assert!(uuid.is_read_only());
+        assert!(!BaserowFieldValue::Rating(1).is_read_only());
+    }
+
+    #[test]
+    fn test_validate() {
+        let field = |r#type: &str, max_value: Option<u8>| TableField {
+            max_value,
+            ..TableField::new("Field", 1, r#type)
+        };
+
+        assert!(BaserowFieldValue::Rating(5)
+            .validate(&field("rating", None))
+            .is_ok());
+        assert!(matches!(
+            BaserowFieldValue::Rating(6).validate(&field("rating", None)),
+            Err(FieldValueError::RatingOutOfRange {
+                value: 6,
+                max: 5,
+                ..
+            })
+        ));
+        assert!(BaserowFieldValue::Rating(10)
+            .validate(&field("rating", Some(10)))
+            .is_ok());
+
+        for url in ["https://baserow.io/docs", "baserow.io"] {
+            assert!(BaserowFieldValue::Url(url.to_string())
+                .validate(&field("url", None))
+                .is_ok());
+        }
+        for url in ["not a url", "https://"] {
+            assert!(matches!(
+                BaserowFieldValue::Url(url.to_string()).validate(&field("url", None)),
+                Err(FieldValueError::InvalidURL { .. })
+            ));
+        }
+
+        assert!(BaserowFieldValue::Email("ada@example.com".to_string())
+            .validate(&field("email", None))
+            .is_ok());
+        for email in ["ada", "ada@example", "@example.com", "ada@@example.com"] {
+            assert!(matches!(
+                BaserowFieldValue::Email(email.to_string()).validate(&field("email", None)),
+                Err(FieldValueError::InvalidEmail { .. })
+            ));
+        }
+
+        assert!(matches!(
+            BaserowFieldValue::Autonumber(1).validate(&field("autonumber", None)),
+            Err(FieldValueError::ReadOnly(_))
+        ));
+    }
+
+    #[test]
+    fn test_deserialize_infers_variant() {
+        let value: BaserowFieldValue =
+            serde_json::from_value(json!([{"id": 3, "value": "Row 3"}])).unwrap();
+        assert_eq!(
+            value,
+            BaserowFieldValue::LinkRow(
+                vec![LinkedRow {
+                    id: RowId(3),
+                    value: json!("Row 3"),
+                }]
+                .into()
+            )
+        );
+
+        let value: BaserowFieldValue =
+            serde_json::from_value(json!([{"id": 1, "name": "Ada"}])).unwrap();
+        assert_eq!(
+            value,
+            BaserowFieldValue::Collaborators(vec![Collaborator {
+                id: 1,
+                name: "Ada".to_string(),
+            }])
+        );
+
+        let value: BaserowFieldValue = serde_json::from_value(json!(true)).unwrap();
+        assert_eq!(value, BaserowFieldValue::Boolean(true));
+    }
+
+    #[test]
+    fn test_select_option_ref() {
+        let option: SelectOption =
+            serde_json::from_value(json!({"id": 4, "value": "Done"})).unwrap();
+        assert_eq!(option.color, "");
+
+        let refs: Vec<SelectOptionRef> = vec![(&option).into(), "Open".into()];
+        assert_eq!(serde_json::to_value(&refs).unwrap(), json!([4, "Open"]));
+
+        let parsed: Vec<SelectOptionRef> = serde_json::from_value(json!([4, "Open"])).unwrap();
+        assert_eq!(parsed, refs);
+    }
+
+    #[test]
+    fn test_link_row_value() {
+        let value: LinkRowValue = serde_json::from_value(json!([
+            {"id": 1, "value": "Apollo"},
+            {"id": 2, "value": 42}
+        ]))
+        .unwrap();
+
+        assert_eq!(value.ids(), vec![RowId(1), RowId(2)]);
+        assert_eq!(value.values(), vec!["Apollo".to_string(), "42".to_string()]);
+        assert!(value.contains(RowId(2)));
+        assert!(!value.contains(RowId(3)));
+        assert_eq!(value.len(), 2);
+
+        let mut refs = value.to_refs();
+        refs.push("Gemini".into());
+        assert_eq!(
+            serde_json::to_value(&refs).unwrap(),
+            json!([1, 2, "Gemini"])
+        );
+    }
+
+    #[test]
+    fn test_collaborators() {
+        let value = BaserowFieldValue::from_field_type(
+            "multiple_collaborators",
+            json!([{"id": 1, "name": "Ada"}, {"id": 2, "name": "Grace"}]),
+        )
+        .unwrap();
+        assert_eq!(
+            serde_json::to_value(&value).unwrap(),
+            json!([{"id": 1}, {"id": 2}])
+        );
+
+        let value =
+            BaserowFieldValue::from_field_type("created_by", json!({"id": 1, "name": "Ada"}))
+                .unwrap();
+        assert_eq!(
+            value,
+            BaserowFieldValue::Collaborator(Collaborator {
+                id: 1,
+                name: "Ada".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_serialize_write_representation() {
+        let value = BaserowFieldValue::MultipleSelect(vec![
+            SelectOption {
+                id: 1,
+                value: "A".to_string(),
+                color: "red".to_string(),
+            },
+            SelectOption {
+                id: 2,
+                value: "B".to_string(),
+                color: "blue".to_string(),
+            },
+        ]);
+        assert_eq!(serde_json::to_value(&value).unwrap(), json!([1, 2]));
+
+        let value = BaserowFieldValue::Collaborator(Collaborator {
+            id: 7,
+            name: "Ada".to_string(),
+        });
+        assert_eq!(serde_json::to_value(&value).unwrap(), json!({"id": 7}));
+    }
+}
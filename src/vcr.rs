@@ -0,0 +1,488 @@
+//! Recording and replaying of HTTP interactions
+//!
+//! A [`Cassette`] installed via `Baserow::with_cassette` records the
+//! responses of a real Baserow instance to a JSON file on the first run and
+//! replays them on later runs, without a network connection. Tests can so
+//! exercise the behavior of a real server deterministically, e.g. in CI.
+//!
+//! Requests are matched by method, path, query parameters in any order and
+//! body. Each recorded interaction is replayed once, in the order they were
+//! recorded, so repeated requests can yield different responses. Request
+//! headers, including credentials, are not recorded. Credentials in JSON
+//! bodies, i.e. fields whose names contain `password`, `token` or `secret`,
+//! are recorded as `[redacted]`, so cassettes of logins can be committed.
+//! Replayed logins yield redacted tokens, which the replayed requests don't
+//! need.
+//!
+//! The mode is chosen by [`Cassette::open`]: an existing cassette is
+//! replayed, a missing one is recorded. Set `BASEROW_VCR` to `record` or
+//! `replay` to force a mode, e.g. to re-record after the API changed.
+//!
+//! Requires the `vcr` feature.
+//!
+//! # Example
+//! ```no_run
+//! use baserow_rs::{api::client::BaserowClient, vcr::Cassette, Baserow, ConfigBuilder, TableId};
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let config = ConfigBuilder::new()
+//!         .base_url("https://api.baserow.io")
+//!         .api_key(&env::var("BASEROW_TOKEN").unwrap_or_default())
+//!         .try_build()
+//!         .expect("invalid configuration");
+//!     let cassette = Cassette::open("tests/fixtures/table_fields.json").unwrap();
+//!     let baserow = Baserow::with_configuration(config).with_cassette(cassette);
+//!
+//!     let fields = baserow.table_fields(TableId(1234)).await.unwrap();
+//!     assert!(!fields.is_empty());
+//! }
+//! ```
+
+use std::{
+    env, fmt, fs,
+    path::{Path, PathBuf},
+    sync::{Mutex, PoisonError},
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use reqwest::{
+    header::{HeaderName, CONTENT_LENGTH, CONTENT_TYPE, SET_COOKIE, TRANSFER_ENCODING},
+    Client, Request, Response, ResponseBuilderExt, StatusCode, Url,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tracing::{debug, error, info};
+
+use crate::{error::BaserowError, secret::redact};
+
+/// Environment variable forcing the mode of cassettes opened via `Cassette::open`
+pub const VCR_MODE_VARIABLE: &str = "BASEROW_VCR";
+
+/// Response headers that are not recorded, as they may hold session cookies or
+/// no longer fit the body once it was re-encoded
+const UNRECORDED_HEADERS: [HeaderName; 3] = [CONTENT_LENGTH, SET_COOKIE, TRANSFER_ENCODING];
+
+/// Whether a cassette records or replays interactions
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VcrMode {
+    /// Sends requests to the server and records the responses
+    Record,
+    /// Answers requests with the recorded responses, without sending them
+    Replay,
+}
+
+/// A request or response body
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum RecordedBody {
+    Json(Value),
+    Text(String),
+    Base64(String),
+}
+
+impl RecordedBody {
+    fn new(bytes: &[u8]) -> Option<Self> {
+        if bytes.is_empty() {
+            return None;
+        }
+        if let Ok(value) = serde_json::from_slice(bytes) {
+            return Some(Self::Json(value));
+        }
+        Some(match std::str::from_utf8(bytes) {
+            Ok(text) => Self::Text(text.to_string()),
+            Err(_) => Self::Base64(STANDARD.encode(bytes)),
+        })
+    }
+
+    /// Replaces the credentials in JSON bodies
+    fn redacted(self) -> Self {
+        match self {
+            Self::Json(mut value) => {
+                redact(&mut value);
+                Self::Json(value)
+            }
+            body => body,
+        }
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        match self {
+            Self::Json(value) => value.to_string().into_bytes(),
+            Self::Text(text) => text.into_bytes(),
+            Self::Base64(data) => STANDARD.decode(data).unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct RecordedRequest {
+    method: String,
+    path: String,
+    /// The query parameters, sorted to match them regardless of their order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    query: Vec<(String, String)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<RecordedBody>,
+}
+
+impl RecordedRequest {
+    fn new(request: &Request) -> Self {
+        let url = request.url();
+        let mut query: Vec<_> = url.query_pairs().into_owned().collect();
+        query.sort();
+        Self {
+            method: request.method().to_string(),
+            path: url.path().to_string(),
+            query,
+            // Streamed bodies, like uploads, are not matched
+            body: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .and_then(RecordedBody::new)
+                .map(RecordedBody::redacted),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    headers: Vec<(String, String)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<RecordedBody>,
+}
+
+impl RecordedResponse {
+    /// Builds a response to the given URL from the recording
+    fn to_response(&self, url: Url) -> Response {
+        let mut builder = http::Response::builder()
+            .status(self.status)
+            .url(url.clone());
+        for (name, value) in &self.headers {
+            builder = builder.header(name, value);
+        }
+        let body = self
+            .body
+            .clone()
+            .map(RecordedBody::into_bytes)
+            .unwrap_or_default();
+        match builder.body(body) {
+            Ok(response) => response.into(),
+            Err(e) => {
+                error!(error = %e, "Recorded response is invalid");
+                no_interaction(url, &format!("the recorded response is invalid - {}", e))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct Interaction {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct Recording {
+    interactions: Vec<Interaction>,
+}
+
+struct State {
+    recording: Recording,
+    /// Whether each interaction was replayed already
+    replayed: Vec<bool>,
+}
+
+/// A file of recorded HTTP interactions, see the [module documentation](self)
+pub struct Cassette {
+    path: PathBuf,
+    mode: VcrMode,
+    state: Mutex<State>,
+}
+
+impl fmt::Debug for Cassette {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cassette")
+            .field("path", &self.path)
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
+
+impl Cassette {
+    /// Opens a cassette, replaying it if the file exists and recording it otherwise
+    ///
+    /// The mode can be forced by setting `BASEROW_VCR` to `record` or `replay`.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, BaserowError> {
+        let path = path.into();
+        let mode = match env::var(VCR_MODE_VARIABLE).as_deref() {
+            Ok("record") => VcrMode::Record,
+            Ok("replay") => VcrMode::Replay,
+            _ if path.exists() => VcrMode::Replay,
+            _ => VcrMode::Record,
+        };
+        Self::with_mode(path, mode)
+    }
+
+    /// Opens a cassette in the given mode
+    ///
+    /// Recording replaces the interactions recorded before. Replaying fails
+    /// if the file cannot be read.
+    pub fn with_mode(path: impl Into<PathBuf>, mode: VcrMode) -> Result<Self, BaserowError> {
+        let path = path.into();
+        let recording = match mode {
+            VcrMode::Record => Recording::default(),
+            VcrMode::Replay => serde_json::from_slice(&fs::read(&path)?)?,
+        };
+        info!(path = %path.display(), ?mode, "Opened cassette");
+
+        Ok(Self {
+            path,
+            mode,
+            state: Mutex::new(State {
+                replayed: vec![false; recording.interactions.len()],
+                recording,
+            }),
+        })
+    }
+
+    /// Returns the file of the cassette
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns whether the cassette records or replays interactions
+    pub fn mode(&self) -> VcrMode {
+        self.mode
+    }
+
+    /// Sends a request or replays its recorded response, depending on the mode
+    pub(crate) async fn execute(
+        &self,
+        client: &Client,
+        request: Request,
+    ) -> reqwest::Result<Response> {
+        let recorded = RecordedRequest::new(&request);
+        match self.mode {
+            VcrMode::Replay => Ok(self.replay(&recorded, request.url().clone())),
+            VcrMode::Record => {
+                let response = client.execute(request).await?;
+                self.record(recorded, response).await
+            }
+        }
+    }
+
+    fn replay(&self, request: &RecordedRequest, url: Url) -> Response {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let State {
+            recording,
+            replayed,
+        } = &mut *state;
+
+        let position = recording
+            .interactions
+            .iter()
+            .zip(replayed.iter())
+            .position(|(interaction, replayed)| !replayed && interaction.request == *request);
+        match position {
+            Some(position) => {
+                debug!(method = %request.method, path = %request.path, "Replaying interaction");
+                replayed[position] = true;
+                recording.interactions[position].response.to_response(url)
+            }
+            None => {
+                error!(method = %request.method, path = %request.path, "No recorded interaction");
+                no_interaction(url, "no interaction matches the request")
+            }
+        }
+    }
+
+    /// Reads the body of a response, records it and returns the response again
+    async fn record(
+        &self,
+        request: RecordedRequest,
+        response: Response,
+    ) -> reqwest::Result<Response> {
+        let url = response.url().clone();
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .filter(|(name, _)| !UNRECORDED_HEADERS.contains(name))
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        let body = response.bytes().await?;
+
+        let mut recorded = RecordedResponse {
+            status,
+            headers,
+            body: RecordedBody::new(&body),
+        };
+        // The caller gets the credentials, the cassette doesn't
+        let response = recorded.to_response(url);
+        recorded.body = recorded.body.map(RecordedBody::redacted);
+        let interaction = Interaction {
+            request,
+            response: recorded,
+        };
+
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.recording.interactions.push(interaction);
+        state.replayed.push(true);
+        debug!(path = %self.path.display(), "Recorded interaction");
+        // Saved after each interaction, as clients don't know when a test ends
+        if let Err(e) = self.save(&state.recording) {
+            error!(error = %e, path = %self.path.display(), "Failed to save cassette");
+        }
+
+        Ok(response)
+    }
+
+    fn save(&self, recording: &Recording) -> Result<(), BaserowError> {
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, serde_json::to_vec_pretty(recording)?)?;
+        Ok(())
+    }
+}
+
+/// The response to a request that cannot be replayed
+fn no_interaction(url: Url, reason: &str) -> Response {
+    let body = json!({
+        "error": "ERROR_NO_RECORDED_INTERACTION",
+        "detail": format!("Cannot replay the request, {}", reason),
+    });
+    http::Response::builder()
+        .status(StatusCode::NOT_IMPLEMENTED)
+        .header(CONTENT_TYPE, "application/json")
+        .url(url)
+        .body(body.to_string())
+        .map(Response::from)
+        .unwrap_or_else(|_| http::Response::new(String::new()).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{api::client::BaserowClient, Baserow, ConfigBuilder, TableId};
+
+    #[tokio::test]
+    async fn test_record_and_replay() {
+        let path = env::temp_dir().join(format!("baserow-vcr-{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut server = mockito::Server::new_async().await;
+        let fields_mock = server
+            .mock("GET", "/api/database/fields/table/1234/")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                json!([
+                    {"id": 1, "table_id": 1234, "name": "Name", "order": 0, "type": "text", "primary": true, "read_only": false}
+                ])
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let config = ConfigBuilder::new()
+            .base_url(&server.url())
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let cassette = Cassette::with_mode(&path, VcrMode::Record).unwrap();
+        let baserow = Baserow::with_configuration(config).with_cassette(cassette);
+        let recorded = baserow.table_fields(TableId(1234)).await.unwrap();
+        assert_eq!(recorded[0].name, "Name");
+        fields_mock.assert_async().await;
+
+        // Replayed without a server
+        let config = ConfigBuilder::new()
+            .base_url("http://127.0.0.1:9")
+            .api_key("test-token")
+            .try_build()
+            .unwrap();
+        let cassette = Cassette::with_mode(&path, VcrMode::Replay).unwrap();
+        let baserow = Baserow::with_configuration(config).with_cassette(cassette);
+        let replayed = baserow.table_fields(TableId(1234)).await.unwrap();
+        assert_eq!(replayed[0].name, "Name");
+
+        // Each interaction is replayed once
+        let error = baserow
+            .clone()
+            .with_client_session_id("other")
+            .table_fields(TableId(1234))
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("no interaction matches"));
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_record_redacts_credentials() {
+        let path = env::temp_dir().join(format!("baserow-vcr-auth-{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut server = mockito::Server::new_async().await;
+        let auth_mock = server
+            .mock("POST", "/api/user/token-auth/")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                json!({
+                    "user": {"first_name": "Ada", "username": "ada@example.com", "language": "en"},
+                    "token": "live-access-token",
+                    "access_token": "live-access-token",
+                    "refresh_token": "live-refresh-token",
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let config = ConfigBuilder::new()
+            .base_url(&server.url())
+            .email("ada@example.com")
+            .password("live-password")
+            .try_build()
+            .unwrap();
+        let cassette = Cassette::with_mode(&path, VcrMode::Record).unwrap();
+        let baserow = Baserow::with_configuration(config)
+            .with_cassette(cassette)
+            .token_auth()
+            .await
+            .unwrap();
+        // The live session keeps the real tokens
+        assert_eq!(
+            baserow.configuration().jwt_token().unwrap().expose_secret(),
+            "live-access-token"
+        );
+        auth_mock.assert_async().await;
+
+        let recorded = fs::read_to_string(&path).unwrap();
+        assert!(!recorded.contains("live-"));
+        assert!(recorded.contains("[redacted]"));
+
+        // Replayed logins match on the redacted password
+        let config = ConfigBuilder::new()
+            .base_url("http://127.0.0.1:9")
+            .email("ada@example.com")
+            .password("other-password")
+            .try_build()
+            .unwrap();
+        let cassette = Cassette::with_mode(&path, VcrMode::Replay).unwrap();
+        let replayed = Baserow::with_configuration(config)
+            .with_cassette(cassette)
+            .token_auth()
+            .await;
+        assert!(replayed.is_ok());
+
+        fs::remove_file(&path).unwrap();
+    }
+}